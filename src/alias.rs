@@ -0,0 +1,221 @@
+// src/alias.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Known folder IDs that name the same folder.
+
+use std::path::PathBuf;
+
+use crate::{FolderId, KnownFolder, Resolver};
+
+// Groups of equivalent known folders. The first folder of each group is the
+// canonical one. Libraries like `SavedPicturesLibrary` are `.library-ms`
+// files, not directories, so they are never aliases of a folder.
+const ONE_DRIVE: &[KnownFolder] = &[KnownFolder::OneDrive, KnownFolder::SkyDrive];
+const DOCUMENTS: &[KnownFolder] = &[
+    KnownFolder::Documents,
+    KnownFolder::LocalDocuments,
+    KnownFolder::SkyDriveDocuments,
+];
+const DOWNLOADS: &[KnownFolder] = &[KnownFolder::Downloads, KnownFolder::LocalDownloads];
+const MUSIC: &[KnownFolder] = &[
+    KnownFolder::Music,
+    KnownFolder::LocalMusic,
+    KnownFolder::SkyDriveMusic,
+];
+const PICTURES: &[KnownFolder] = &[
+    KnownFolder::Pictures,
+    KnownFolder::LocalPictures,
+    KnownFolder::SkyDrivePictures,
+];
+const VIDEOS: &[KnownFolder] = &[KnownFolder::Videos, KnownFolder::LocalVideos];
+const CAMERA_ROLL: &[KnownFolder] = &[KnownFolder::CameraRoll, KnownFolder::SkyDriveCameraRoll];
+
+impl KnownFolder {
+    /// Return the group of known folders equivalent to this one, canonical
+    /// folder first, or an empty slice if this folder has no aliases.
+    const fn alias_group(self) -> &'static [Self] {
+        match self {
+            Self::OneDrive | Self::SkyDrive => ONE_DRIVE,
+            Self::Documents | Self::LocalDocuments | Self::SkyDriveDocuments => DOCUMENTS,
+            Self::Downloads | Self::LocalDownloads => DOWNLOADS,
+            Self::Music | Self::LocalMusic | Self::SkyDriveMusic => MUSIC,
+            Self::Pictures | Self::LocalPictures | Self::SkyDrivePictures => PICTURES,
+            Self::Videos | Self::LocalVideos => VIDEOS,
+            Self::CameraRoll | Self::SkyDriveCameraRoll => CAMERA_ROLL,
+            _ => &[],
+        }
+    }
+
+    /// Return the preferred known folder among the folders equivalent to
+    /// this one.
+    ///
+    /// Legacy IDs like [`SkyDrive`] and the "this device" variants like
+    /// [`LocalDocuments`] name the same folder as a more common ID. Folders
+    /// without aliases are their own canonical folder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::SkyDrive.canonical(), KnownFolder::OneDrive);
+    /// assert_eq!(KnownFolder::LocalDocuments.canonical(), KnownFolder::Documents);
+    /// assert_eq!(KnownFolder::Fonts.canonical(), KnownFolder::Fonts);
+    /// ```
+    ///
+    /// [`SkyDrive`]: Self::SkyDrive
+    /// [`LocalDocuments`]: Self::LocalDocuments
+    #[must_use]
+    pub const fn canonical(self) -> Self {
+        match self.alias_group() {
+            [canonical, ..] => *canonical,
+            [] => self,
+        }
+    }
+
+    /// Iterate over the other known folders equivalent to this one, canonical
+    /// folder first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// let aliases = KnownFolder::SkyDrivePictures.aliases().collect::<Vec<_>>();
+    /// assert_eq!(aliases, [KnownFolder::Pictures, KnownFolder::LocalPictures]);
+    /// assert_eq!(KnownFolder::Fonts.aliases().count(), 0);
+    /// ```
+    pub fn aliases(self) -> impl Iterator<Item = Self> {
+        self.alias_group()
+            .iter()
+            .copied()
+            .filter(move |&alias| alias != self)
+    }
+}
+
+/// A [`Resolver`] that retries through a folder's aliases when the wrapped
+/// resolver cannot locate the requested folder.
+///
+/// Resolution tries the requested folder first and then each of its
+/// [aliases](KnownFolder::aliases), canonical folder first.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{AliasResolver, KnownFolder, Resolver, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::OneDrive, r"C:\Users\jsmith\OneDrive");
+///
+/// assert_eq!(snapshot.resolve(KnownFolder::SkyDrive), None);
+///
+/// let resolver = AliasResolver::new(snapshot);
+/// assert_eq!(
+///     resolver.resolve(KnownFolder::SkyDrive),
+///     Some(r"C:\Users\jsmith\OneDrive".into()),
+/// );
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct AliasResolver<R> {
+    inner: R,
+}
+
+impl<R> AliasResolver<R> {
+    /// Wrap `inner` so that resolution retries through aliases.
+    #[must_use]
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Return a reference to the wrapped resolver.
+    #[must_use]
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Consume this resolver and return the wrapped resolver.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Resolver for AliasResolver<R>
+where
+    R: Resolver,
+{
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.inner.resolve(known_folder).or_else(|| {
+            known_folder
+                .aliases()
+                .find_map(|alias| self.inner.resolve(alias))
+        })
+    }
+
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        match id {
+            FolderId::Known(known_folder) => self.resolve(known_folder),
+            FolderId::Custom(_) => self.inner.resolve_id(id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Snapshot;
+
+    #[test]
+    fn groups_are_consistent() {
+        for &folder in KnownFolder::ALL {
+            let canonical = folder.canonical();
+            assert_eq!(canonical.canonical(), canonical, "{folder:?}");
+            for alias in folder.aliases() {
+                assert_ne!(alias, folder);
+                assert_eq!(alias.canonical(), canonical, "{folder:?}");
+                assert!(alias.aliases().any(|other| other == folder), "{folder:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn resolver_prefers_requested_folder() {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(
+            KnownFolder::Documents,
+            r"C:\Users\jsmith\OneDrive\Documents",
+        );
+        snapshot.insert(KnownFolder::LocalDocuments, r"C:\Users\jsmith\Documents");
+        let resolver = AliasResolver::new(&snapshot);
+        assert_eq!(
+            resolver.resolve(KnownFolder::LocalDocuments),
+            Some(r"C:\Users\jsmith\Documents".into())
+        );
+        assert_eq!(
+            resolver.resolve(KnownFolder::SkyDriveDocuments),
+            Some(r"C:\Users\jsmith\OneDrive\Documents".into())
+        );
+        assert_eq!(resolver.resolve(KnownFolder::Music), None);
+    }
+
+    #[test]
+    fn resolver_keeps_libraries_and_folders_apart() {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(
+            KnownFolder::SavedPicturesLibrary,
+            r"C:\Users\jsmith\AppData\Roaming\Microsoft\Windows\Libraries\SavedPictures.library-ms",
+        );
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        let resolver = AliasResolver::new(&snapshot);
+        assert_eq!(resolver.resolve(KnownFolder::SavedPictures), None);
+        assert_eq!(resolver.resolve(KnownFolder::DocumentsLibrary), None);
+        assert_eq!(KnownFolder::SavedPictures.aliases().count(), 0);
+    }
+}
@@ -0,0 +1,329 @@
+// src/arch.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Architecture-dependent program and system folders.
+//!
+//! The [`ProgramFiles`], [`ProgramFilesCommon`], and [`System`] known folders
+//! name different directories depending on the architecture of the operating
+//! system and of the process that resolves them. A 32-bit x86 process on
+//! 64-bit Windows sees `Program Files (x86)` as its `ProgramFiles` folder,
+//! and its accesses to `System32` are redirected to `SysWOW64` by the WOW64
+//! file system redirector.
+//!
+//! [`directory`] computes which on-disk directory a folder refers to for a
+//! given OS and process architecture, without calling into Windows.
+//!
+//! [`ProgramFiles`]: KnownFolder::ProgramFiles
+//! [`ProgramFilesCommon`]: KnownFolder::ProgramFilesCommon
+//! [`System`]: KnownFolder::System
+
+use std::path::{Path, PathBuf};
+
+use crate::{path, KnownFolder};
+
+/// A processor architecture of Windows or of a Windows process.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Architecture {
+    /// 32-bit x86.
+    X86,
+    /// 64-bit x86, also known as AMD64.
+    X64,
+    /// 32-bit ARM.
+    Arm,
+    /// 64-bit ARM.
+    Arm64,
+}
+
+impl Architecture {
+    /// Return `true` if this is a 64-bit architecture.
+    #[must_use]
+    pub const fn is_64_bit(self) -> bool {
+        matches!(self, Self::X64 | Self::Arm64)
+    }
+
+    /// Return `true` if Windows on this architecture can run processes of
+    /// the `process` architecture, natively or under emulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::arch::Architecture;
+    ///
+    /// assert!(Architecture::X64.can_run(Architecture::X86));
+    /// assert!(Architecture::Arm64.can_run(Architecture::X64));
+    /// assert!(!Architecture::X86.can_run(Architecture::X64));
+    /// ```
+    #[must_use]
+    pub const fn can_run(self, process: Self) -> bool {
+        matches!(
+            (self, process),
+            (Self::X86, Self::X86)
+                | (Self::X64, Self::X64 | Self::X86)
+                | (Self::Arm, Self::Arm)
+                | (Self::Arm64, _)
+        )
+    }
+}
+
+/// The folder an architecture-dependent [`Directory`] is relative to.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Base {
+    /// The root of the system drive, `%SystemDrive%\`.
+    SystemDrive,
+    /// The Windows directory, `%SystemRoot%`.
+    SystemRoot,
+}
+
+/// The on-disk directory an architecture-dependent known folder refers to.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Directory {
+    base: Base,
+    path: &'static str,
+    redirected: bool,
+}
+
+impl Directory {
+    /// The folder [`path`](Self::path) is relative to.
+    #[must_use]
+    pub const fn base(&self) -> Base {
+        self.base
+    }
+
+    /// The path of the directory relative to its [`base`](Self::base), e.g.
+    /// `Program Files (x86)\Common Files` or `SysWOW64`.
+    #[must_use]
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Return `true` if the process accesses this directory through WOW64
+    /// file system redirection.
+    ///
+    /// For redirected directories, the Known Folders API returns the path of
+    /// the native directory, e.g. `System32`, and the file system redirector
+    /// maps accesses to the directory returned by [`path`](Self::path).
+    #[must_use]
+    pub const fn is_redirected(&self) -> bool {
+        self.redirected
+    }
+
+    /// Join this directory to the path of its [`base`](Self::base).
+    #[must_use]
+    pub fn join(&self, base: &Path) -> PathBuf {
+        path::join(base, self.path)
+    }
+}
+
+/// Return the directory that the program files folder or system folder
+/// `known_folder` refers to for a process of architecture `process` running
+/// on Windows of architecture `os`.
+///
+/// Returns [`None`] if `known_folder` does not depend on the architecture,
+/// if `os` cannot run `process`, or if the folder is not supported for the
+/// process, like [`ProgramFilesX64`] for 32-bit processes.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::arch::{directory, Architecture, Base};
+/// use known_folders::KnownFolder;
+///
+/// let dir = directory(Architecture::X64, Architecture::X86, KnownFolder::ProgramFiles).unwrap();
+/// assert_eq!(dir.base(), Base::SystemDrive);
+/// assert_eq!(dir.path(), "Program Files (x86)");
+/// assert!(!dir.is_redirected());
+///
+/// let dir = directory(Architecture::Arm64, Architecture::Arm, KnownFolder::System).unwrap();
+/// assert_eq!(dir.path(), "SysArm32");
+/// assert!(dir.is_redirected());
+///
+/// assert!(directory(Architecture::X64, Architecture::X86, KnownFolder::ProgramFilesX64).is_none());
+/// ```
+///
+/// [`ProgramFilesX64`]: KnownFolder::ProgramFilesX64
+#[must_use]
+pub fn directory(
+    os: Architecture,
+    process: Architecture,
+    known_folder: KnownFolder,
+) -> Option<Directory> {
+    if !os.can_run(process) {
+        return None;
+    }
+    // 32-bit processes on 64-bit Windows run under WOW64.
+    let wow64 = os.is_64_bit() && !process.is_64_bit();
+    let program_files = |x86_on_64: &'static str, arm_on_64: &'static str, native: &'static str| {
+        let path = match (wow64, process) {
+            (true, Architecture::X86) => x86_on_64,
+            (true, _) => arm_on_64,
+            (false, _) => native,
+        };
+        Some(Directory {
+            base: Base::SystemDrive,
+            path,
+            redirected: false,
+        })
+    };
+    let program_files_x86 = |x86_on_64: &'static str, native: &'static str| {
+        let path = match os {
+            Architecture::X86 => native,
+            Architecture::X64 | Architecture::Arm64 => x86_on_64,
+            Architecture::Arm => return None,
+        };
+        Some(Directory {
+            base: Base::SystemDrive,
+            path,
+            redirected: false,
+        })
+    };
+    let program_files_x64 = |native: &'static str| {
+        if !process.is_64_bit() {
+            return None;
+        }
+        Some(Directory {
+            base: Base::SystemDrive,
+            path: native,
+            redirected: false,
+        })
+    };
+    match known_folder {
+        KnownFolder::ProgramFiles => program_files(
+            "Program Files (x86)",
+            "Program Files (Arm)",
+            "Program Files",
+        ),
+        KnownFolder::ProgramFilesCommon => program_files(
+            r"Program Files (x86)\Common Files",
+            r"Program Files (Arm)\Common Files",
+            r"Program Files\Common Files",
+        ),
+        KnownFolder::ProgramFilesX86 => program_files_x86("Program Files (x86)", "Program Files"),
+        KnownFolder::ProgramFilesCommonX86 => program_files_x86(
+            r"Program Files (x86)\Common Files",
+            r"Program Files\Common Files",
+        ),
+        KnownFolder::ProgramFilesX64 => program_files_x64("Program Files"),
+        KnownFolder::ProgramFilesCommonX64 => program_files_x64(r"Program Files\Common Files"),
+        KnownFolder::System => {
+            let path = match (wow64, process) {
+                (true, Architecture::X86) => "SysWOW64",
+                (true, _) => "SysArm32",
+                (false, _) => "System32",
+            };
+            Some(Directory {
+                base: Base::SystemRoot,
+                path,
+                redirected: wow64,
+            })
+        }
+        KnownFolder::SystemX86 => {
+            let path = match os {
+                Architecture::X86 => "System32",
+                Architecture::X64 | Architecture::Arm64 => "SysWOW64",
+                Architecture::Arm => return None,
+            };
+            Some(Directory {
+                base: Base::SystemRoot,
+                path,
+                redirected: false,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use Architecture::{Arm, Arm64, X64, X86};
+
+    fn path(
+        os: Architecture,
+        process: Architecture,
+        known_folder: KnownFolder,
+    ) -> Option<&'static str> {
+        directory(os, process, known_folder).map(|dir| dir.path())
+    }
+
+    #[test]
+    fn native_processes() {
+        for &os in &[X86, X64, Arm, Arm64] {
+            assert_eq!(
+                path(os, os, KnownFolder::ProgramFiles),
+                Some("Program Files")
+            );
+            assert_eq!(path(os, os, KnownFolder::System), Some("System32"));
+            assert!(!directory(os, os, KnownFolder::System)
+                .unwrap()
+                .is_redirected());
+        }
+        assert_eq!(
+            path(X86, X86, KnownFolder::ProgramFilesX86),
+            Some("Program Files")
+        );
+        assert_eq!(path(X86, X86, KnownFolder::SystemX86), Some("System32"));
+        assert_eq!(path(X86, X86, KnownFolder::ProgramFilesX64), None);
+        assert_eq!(
+            path(X64, X64, KnownFolder::ProgramFilesX86),
+            Some("Program Files (x86)")
+        );
+        assert_eq!(path(X64, X64, KnownFolder::SystemX86), Some("SysWOW64"));
+        assert_eq!(
+            path(X64, X64, KnownFolder::ProgramFilesCommonX64),
+            Some(r"Program Files\Common Files")
+        );
+        assert_eq!(path(Arm, Arm, KnownFolder::SystemX86), None);
+    }
+
+    #[test]
+    fn emulated_processes() {
+        assert_eq!(
+            path(X64, X86, KnownFolder::ProgramFilesCommon),
+            Some(r"Program Files (x86)\Common Files")
+        );
+        assert_eq!(path(Arm64, X86, KnownFolder::System), Some("SysWOW64"));
+        assert!(directory(Arm64, X86, KnownFolder::System)
+            .unwrap()
+            .is_redirected());
+        assert_eq!(
+            path(Arm64, Arm, KnownFolder::ProgramFiles),
+            Some("Program Files (Arm)")
+        );
+        assert_eq!(
+            path(Arm64, X64, KnownFolder::ProgramFiles),
+            Some("Program Files")
+        );
+        assert_eq!(path(Arm64, X64, KnownFolder::System), Some("System32"));
+        assert_eq!(
+            path(Arm64, X64, KnownFolder::ProgramFilesX64),
+            Some("Program Files")
+        );
+    }
+
+    #[test]
+    fn unsupported_combinations() {
+        assert!(directory(X86, X64, KnownFolder::ProgramFiles).is_none());
+        assert!(directory(X64, Arm64, KnownFolder::System).is_none());
+        assert!(directory(X64, X64, KnownFolder::Windows).is_none());
+    }
+
+    #[test]
+    fn join_to_base() {
+        let dir = directory(X64, X86, KnownFolder::System).unwrap();
+        assert_eq!(dir.base(), Base::SystemRoot);
+        assert_eq!(
+            dir.join(Path::new(r"C:\Windows")),
+            Path::new(r"C:\Windows\SysWOW64")
+        );
+    }
+}
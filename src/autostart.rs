@@ -0,0 +1,301 @@
+// src/autostart.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Inventory of the programs launched from the Startup folders at login.
+//!
+//! Explorer opens every file in the per-user [`Startup`] and the all-users
+//! [`CommonStartup`] folders when a user logs in. [`entries`] lists them with
+//! the target each one launches, and [`scan`] does the same for any
+//! directory, like a Startup folder of a mounted offline image.
+//!
+//! [`Startup`]: KnownFolder::Startup
+//! [`CommonStartup`]: KnownFolder::CommonStartup
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::desktop_ini;
+use crate::internet_shortcut::InternetShortcut;
+use crate::shell_link::ShellLink;
+use crate::{KnownFolder, Resolver, Scope};
+
+// Extensions of files that Windows runs with a script host or shell.
+const SCRIPT_EXTENSIONS: &[&str] = &["bat", "cmd", "js", "jse", "ps1", "vbe", "vbs", "wsf", "wsh"];
+
+// Extensions of files that Windows runs as programs.
+const PROGRAM_EXTENSIONS: &[&str] = &["com", "exe", "msc", "pif", "scr"];
+
+/// The kind of file that launches at login.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Kind {
+    /// A shell link (`.lnk`) file.
+    Shortcut,
+    /// An internet shortcut (`.url`) file.
+    InternetShortcut,
+    /// A script, like a `.bat`, `.cmd`, `.ps1`, or `.vbs` file.
+    Script,
+    /// A program, like an `.exe` file.
+    Program,
+    /// Any other file, which is opened with its associated application.
+    Other,
+}
+
+/// A file in a Startup folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    scope: Scope,
+    path: PathBuf,
+    kind: Kind,
+    target: Option<String>,
+    arguments: Option<String>,
+    working_dir: Option<String>,
+}
+
+impl Entry {
+    fn read<R>(path: PathBuf, scope: Scope, resolver: &R) -> Self
+    where
+        R: Resolver + ?Sized,
+    {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let mut entry = Self {
+            scope,
+            path,
+            kind: Kind::Other,
+            target: None,
+            arguments: None,
+            working_dir: None,
+        };
+        match extension.as_str() {
+            "lnk" => {
+                entry.kind = Kind::Shortcut;
+                if let Ok(link) = ShellLink::open(&entry.path) {
+                    entry.target = link
+                        .resolve_target(resolver)
+                        .and_then(|target| target.to_str().map(str::to_owned));
+                    entry.arguments = link.arguments().map(str::to_owned);
+                    entry.working_dir = link.working_dir().map(str::to_owned);
+                }
+            }
+            "url" => {
+                entry.kind = Kind::InternetShortcut;
+                if let Ok(Some(shortcut)) = InternetShortcut::open(&entry.path) {
+                    entry.target = Some(shortcut.url().to_owned());
+                    entry.working_dir = shortcut.working_dir().map(str::to_owned);
+                }
+            }
+            extension => {
+                if SCRIPT_EXTENSIONS.contains(&extension) {
+                    entry.kind = Kind::Script;
+                } else if PROGRAM_EXTENSIONS.contains(&extension) {
+                    entry.kind = Kind::Program;
+                }
+                entry.target = entry.path.to_str().map(str::to_owned);
+            }
+        }
+        entry
+    }
+
+    /// Whether the file launches for the current user or for all users.
+    #[must_use]
+    pub fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    /// The path of the file in the Startup folder.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The kind of the file.
+    #[must_use]
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// What the file launches: the resolved target of a shortcut, the URL of
+    /// an internet shortcut, or the path of the file itself.
+    ///
+    /// Returns [`None`] for shortcuts that cannot be parsed or resolved.
+    #[must_use]
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    /// The command line arguments a shortcut passes to its target.
+    #[must_use]
+    pub fn arguments(&self) -> Option<&str> {
+        self.arguments.as_deref()
+    }
+
+    /// The working directory a shortcut launches its target in.
+    #[must_use]
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref()
+    }
+}
+
+/// List the files in the per-user and all-users Startup folders as located
+/// by `resolver`.
+///
+/// Per-user entries come first, followed by the all-users entries, each
+/// ordered by file name. Startup folders that cannot be resolved or do not
+/// exist are skipped.
+///
+/// # Errors
+///
+/// If a Startup folder exists but cannot be read, an error is returned.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::autostart;
+/// use known_folders::SystemResolver;
+///
+/// for entry in autostart::entries(&SystemResolver)? {
+///     println!("{:?} {:?} {:?}", entry.scope(), entry.target(), entry.arguments());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn entries<R>(resolver: &R) -> io::Result<Vec<Entry>>
+where
+    R: Resolver + ?Sized,
+{
+    let mut entries = Vec::new();
+    for &(known_folder, scope) in &[
+        (KnownFolder::Startup, Scope::PerUser),
+        (KnownFolder::CommonStartup, Scope::AllUsers),
+    ] {
+        if let Some(dir) = resolver.resolve(known_folder) {
+            match scan(&dir, scope, resolver) {
+                Ok(found) => entries.extend(found),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// List the files in the Startup folder `dir`, ordered by file name.
+///
+/// `dir` may be any directory, like the Startup folder of an offline image.
+/// Shortcut targets anchored at a known folder are resolved with
+/// `resolver`. Subdirectories and `desktop.ini` are skipped since Explorer
+/// does not launch them.
+///
+/// # Errors
+///
+/// If `dir` cannot be read, an error is returned.
+pub fn scan<R>(dir: &Path, scope: Scope, resolver: &R) -> io::Result<Vec<Entry>>
+where
+    R: Resolver + ?Sized,
+{
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            continue;
+        }
+        let is_desktop_ini = entry.file_name().to_str().map_or(false, |name| {
+            name.eq_ignore_ascii_case(desktop_ini::FILE_NAME)
+        });
+        if !is_desktop_ini {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort_by_cached_key(|path| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+    });
+    Ok(paths
+        .into_iter()
+        .map(|path| Entry::read(path, scope, resolver))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell_link::tests::program_link;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn list_startup_entries() {
+        let temp_dir = TempDir::new("autostart");
+        let root = temp_dir.path();
+        let user = root.join("Startup");
+        let common = root.join("CommonStartup");
+        fs::create_dir_all(user.join("Disabled")).unwrap();
+        fs::create_dir_all(&common).unwrap();
+        fs::write(
+            user.join("Sync.lnk"),
+            program_link(
+                r"C:\Program Files\Sync\sync.exe",
+                "/background",
+                r"C:\Program Files\Sync",
+            ),
+        )
+        .unwrap();
+        fs::write(
+            user.join("dashboard.URL"),
+            "[InternetShortcut]\r\nURL=https://example.com/dashboard\r\n",
+        )
+        .unwrap();
+        fs::write(user.join("desktop.ini"), "[.ShellClassInfo]\r\n").unwrap();
+        fs::write(
+            common.join("mount-shares.cmd"),
+            "net use Z: \\\\server\\share\r\n",
+        )
+        .unwrap();
+        fs::write(common.join("Broken.lnk"), "").unwrap();
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Startup, &user);
+        snapshot.insert(KnownFolder::CommonStartup, &common);
+        let entries = entries(&snapshot).unwrap();
+        let summary = entries
+            .iter()
+            .map(|entry| (entry.scope(), entry.kind(), entry.target()))
+            .collect::<Vec<_>>();
+        let script = common.join("mount-shares.cmd");
+        assert_eq!(
+            summary,
+            [
+                (
+                    Scope::PerUser,
+                    Kind::InternetShortcut,
+                    Some("https://example.com/dashboard")
+                ),
+                (
+                    Scope::PerUser,
+                    Kind::Shortcut,
+                    Some(r"C:\Program Files\Sync\sync.exe")
+                ),
+                (Scope::AllUsers, Kind::Shortcut, None),
+                (Scope::AllUsers, Kind::Script, script.to_str()),
+            ]
+        );
+        assert_eq!(entries[1].arguments(), Some("/background"));
+        assert_eq!(entries[1].working_dir(), Some(r"C:\Program Files\Sync"));
+
+        snapshot.insert(KnownFolder::CommonStartup, root.join("missing"));
+        assert_eq!(super::entries(&snapshot).unwrap().len(), 2);
+        assert!(scan(&root.join("missing"), Scope::AllUsers, &snapshot).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+// src/bytes.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Bounds-checked little endian readers for the binary formats parsed by
+//! this crate.
+//!
+//! All readers return [`None`] instead of panicking when the requested range
+//! is out of bounds, so parsers can propagate truncation as an error.
+
+pub(crate) fn u16_at(buf: &[u8], offset: usize) -> Option<u16> {
+    let bytes = buf.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+pub(crate) fn u32_at(buf: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buf.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub(crate) fn i32_at(buf: &[u8], offset: usize) -> Option<i32> {
+    u32_at(buf, offset).map(|n| i32::from_le_bytes(n.to_le_bytes()))
+}
+
+pub(crate) fn u64_at(buf: &[u8], offset: usize) -> Option<u64> {
+    let lo = u32_at(buf, offset)?;
+    let hi = u32_at(buf, offset.checked_add(4)?)?;
+    Some(u64::from(hi) << 32 | u64::from(lo))
+}
+
+pub(crate) fn slice_at(buf: &[u8], offset: usize, len: usize) -> Option<&[u8]> {
+    buf.get(offset..offset.checked_add(len)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readers_are_bounds_checked() {
+        let buf = [1, 2, 3, 4, 5, 6, 7, 8];
+        assert_eq!(u16_at(&buf, 6), Some(0x0807));
+        assert_eq!(u16_at(&buf, 7), None);
+        assert_eq!(u32_at(&buf, usize::MAX), None);
+        assert_eq!(u64_at(&buf, 0), Some(0x0807_0605_0403_0201));
+        assert_eq!(slice_at(&buf, 4, 4), Some(&buf[4..]));
+        assert_eq!(slice_at(&buf, 5, 4), None);
+    }
+}
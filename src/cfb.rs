@@ -0,0 +1,404 @@
+// src/cfb.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Minimal reader for compound files (MS-CFB), the container format of
+//! automatic jump lists.
+//!
+//! Only reading the streams of a well-formed file is supported. Sector
+//! counts and chain lengths are capped by the size of the file, and the
+//! streams of a file are read within a budget of the file's size, so
+//! malformed files with cyclic or shared chains cannot cause unbounded
+//! work.
+
+use crate::bytes::{slice_at, u16_at, u32_at, u64_at};
+use crate::text;
+
+const SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+const END_OF_CHAIN: u32 = 0xFFFF_FFFE;
+const DIRECTORY_ENTRY_SIZE: usize = 128;
+const OBJECT_TYPE_STREAM: u8 = 2;
+
+/// A stream in the directory of a compound file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Stream {
+    /// The name of the stream.
+    pub(crate) name: String,
+    start: u32,
+    size: u64,
+}
+
+/// A parsed compound file.
+#[derive(Debug, Clone)]
+pub(crate) struct CompoundFile<'a> {
+    data: &'a [u8],
+    sector_shift: u32,
+    mini_sector_shift: u32,
+    mini_stream_cutoff: u64,
+    fat: Vec<u32>,
+    mini_fat: Vec<u32>,
+    mini_stream: Vec<u8>,
+    directory: Vec<u8>,
+}
+
+impl<'a> CompoundFile<'a> {
+    /// Parse the header, allocation tables, and directory of a compound
+    /// file. Returns [`None`] if `data` is not a compound file.
+    pub(crate) fn parse(data: &'a [u8]) -> Option<Self> {
+        if data.get(..8)? != SIGNATURE {
+            return None;
+        }
+        let sector_shift = u32::from(u16_at(data, 30)?);
+        let mini_sector_shift = u32::from(u16_at(data, 32)?);
+        if !(7..=16).contains(&sector_shift) || mini_sector_shift >= sector_shift {
+            return None;
+        }
+        let mut file = Self {
+            data,
+            sector_shift,
+            mini_sector_shift,
+            mini_stream_cutoff: u64::from(u32_at(data, 56)?),
+            fat: Vec::new(),
+            mini_fat: Vec::new(),
+            mini_stream: Vec::new(),
+            directory: Vec::new(),
+        };
+        file.fat = file.read_fat()?;
+        file.directory = file.read_chain(u32_at(data, 48)?, None, data.len());
+        file.mini_fat = file
+            .read_chain(u32_at(data, 60)?, None, data.len())
+            .chunks_exact(4)
+            .filter_map(|entry| u32_at(entry, 0))
+            .collect();
+        // The root entry locates the mini stream.
+        let root = file.directory.get(..DIRECTORY_ENTRY_SIZE)?;
+        let start = u32_at(root, 116)?;
+        let size = u64_at(root, 120)?;
+        file.mini_stream = file.read_chain(start, Some(size), data.len());
+        Some(file)
+    }
+
+    fn sector_size(&self) -> usize {
+        1 << self.sector_shift
+    }
+
+    /// The number of sectors the file can hold, an upper bound for every
+    /// sector count and chain length in it.
+    fn max_sectors(&self) -> usize {
+        self.data.len() >> self.sector_shift
+    }
+
+    fn sector(&self, sector: u32) -> Option<&'a [u8]> {
+        let offset = (sector as usize)
+            .checked_add(1)?
+            .checked_shl(self.sector_shift)?;
+        slice_at(self.data, offset, self.sector_size())
+    }
+
+    /// Collect the sectors of the FAT from the header and the DIFAT chain.
+    fn read_fat(&self) -> Option<Vec<u32>> {
+        let max_sectors = self.max_sectors();
+        let mut fat_sectors = (0..109)
+            .filter_map(|index| u32_at(self.data, 76 + index * 4))
+            .collect::<Vec<_>>();
+        let mut difat = u32_at(self.data, 68)?;
+        let per_sector = self.sector_size() / 4 - 1;
+        let remaining = u32_at(self.data, 72)? as usize;
+        let mut visited = vec![false; max_sectors];
+        for _ in 0..remaining.min(max_sectors) {
+            if difat >= END_OF_CHAIN {
+                break;
+            }
+            match visited.get_mut(difat as usize) {
+                Some(seen) if !*seen => *seen = true,
+                _ => break,
+            }
+            let sector = self.sector(difat)?;
+            fat_sectors.extend((0..per_sector).filter_map(|index| u32_at(sector, index * 4)));
+            difat = u32_at(sector, per_sector * 4)?;
+        }
+        let count = (u32_at(self.data, 44)? as usize).min(max_sectors);
+        let mut fat = Vec::new();
+        for &sector in fat_sectors.iter().take(count) {
+            let sector = self.sector(sector)?;
+            fat.extend(sector.chunks_exact(4).filter_map(|entry| u32_at(entry, 0)));
+        }
+        Some(fat)
+    }
+
+    /// Read the sector chain starting at `start`, truncated to `size` bytes
+    /// and to at most `budget` bytes.
+    fn read_chain(&self, start: u32, size: Option<u64>, budget: usize) -> Vec<u8> {
+        let limit = size.map_or(budget, |size| {
+            usize::try_from(size).unwrap_or(usize::MAX).min(budget)
+        });
+        let mut out = Vec::new();
+        let mut sector = start;
+        // A chain without cycles is no longer than the file.
+        for _ in 0..self.max_sectors() {
+            if sector >= END_OF_CHAIN || out.len() >= limit {
+                break;
+            }
+            match self.sector(sector) {
+                Some(data) => out.extend_from_slice(data),
+                None => break,
+            }
+            sector = match self.fat.get(sector as usize) {
+                Some(&next) => next,
+                None => break,
+            };
+        }
+        out.truncate(limit);
+        out
+    }
+
+    /// Read the mini stream chain starting at `start`, truncated to `size`
+    /// bytes and to at most `budget` bytes.
+    fn read_mini_chain(&self, start: u32, size: u64, budget: usize) -> Vec<u8> {
+        let limit = usize::try_from(size).unwrap_or(usize::MAX).min(budget);
+        let mini_sector_size = 1 << self.mini_sector_shift;
+        let mut out = Vec::new();
+        let mut sector = start;
+        for _ in 0..self.mini_stream.len() >> self.mini_sector_shift {
+            if sector >= END_OF_CHAIN || out.len() >= limit {
+                break;
+            }
+            let offset = (sector as usize) << self.mini_sector_shift;
+            match slice_at(&self.mini_stream, offset, mini_sector_size) {
+                Some(data) => out.extend_from_slice(data),
+                None => break,
+            }
+            sector = match self.mini_fat.get(sector as usize) {
+                Some(&next) => next,
+                None => break,
+            };
+        }
+        out.truncate(limit);
+        out
+    }
+
+    /// Return the streams in the directory of the file, in directory order.
+    ///
+    /// Stream contents are not read; see [`read`](Self::read).
+    pub(crate) fn streams(&self) -> Vec<Stream> {
+        let mut streams = Vec::new();
+        for entry in self.directory.chunks_exact(DIRECTORY_ENTRY_SIZE) {
+            if entry[66] != OBJECT_TYPE_STREAM {
+                continue;
+            }
+            let name_len = u16_at(entry, 64).map_or(0, usize::from).min(64);
+            // Version 3 files only use the low 32 bits of the size.
+            let size = if self.sector_shift == 9 {
+                u64::from(u32_at(entry, 120).unwrap_or_default())
+            } else {
+                u64_at(entry, 120).unwrap_or_default()
+            };
+            streams.push(Stream {
+                name: text::decode_utf16le_nul(&entry[..name_len]),
+                start: u32_at(entry, 116).unwrap_or(END_OF_CHAIN),
+                size,
+            });
+        }
+        streams
+    }
+
+    /// Read the contents of `stream`, taking the bytes read from `budget`.
+    ///
+    /// Streams of a malformed file can share sectors, so reading all of
+    /// them could take far more bytes than the file holds. Start with a
+    /// budget of the size of the file to bound the total; once it is spent,
+    /// streams read as truncated or empty.
+    pub(crate) fn read(&self, stream: &Stream, budget: &mut usize) -> Vec<u8> {
+        let data = if stream.size < self.mini_stream_cutoff {
+            self.read_mini_chain(stream.start, stream.size, *budget)
+        } else {
+            self.read_chain(stream.start, Some(stream.size), *budget)
+        };
+        *budget -= data.len();
+        data
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    const FREE: u32 = 0xFFFF_FFFF;
+    const FAT_SECTOR: u32 = 0xFFFF_FFFD;
+    const NO_STREAM: u32 = 0xFFFF_FFFF;
+
+    fn directory_entry(name: &str, object_type: u8, start: u32, size: u64) -> Vec<u8> {
+        let mut entry = name
+            .encode_utf16()
+            .chain(Some(0))
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let name_len = u16::try_from(entry.len()).unwrap();
+        entry.resize(64, 0);
+        entry.extend_from_slice(&name_len.to_le_bytes());
+        entry.push(object_type);
+        entry.push(1);
+        for _ in 0..3 {
+            entry.extend_from_slice(&NO_STREAM.to_le_bytes());
+        }
+        entry.resize(116, 0);
+        entry.extend_from_slice(&start.to_le_bytes());
+        entry.extend_from_slice(&size.to_le_bytes());
+        entry
+    }
+
+    /// Build a version 3 compound file whose streams all live in the mini
+    /// stream, like automatic jump lists with small entries.
+    pub(crate) fn build(streams: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut mini_stream = Vec::new();
+        let mut mini_fat = Vec::new();
+        let mut directory = Vec::new();
+        for &(name, data) in streams {
+            assert!(data.len() < 4096);
+            let start = u32::try_from(mini_fat.len()).unwrap();
+            let sectors = (data.len() + 63) / 64;
+            for index in 0..sectors {
+                let next = if index + 1 == sectors {
+                    END_OF_CHAIN
+                } else {
+                    start + u32::try_from(index).unwrap() + 1
+                };
+                mini_fat.push(next);
+            }
+            mini_stream.extend_from_slice(data);
+            mini_stream.resize(mini_fat.len() * 64, 0);
+            let size = u64::try_from(data.len()).unwrap();
+            directory.push(directory_entry(name, OBJECT_TYPE_STREAM, start, size));
+        }
+
+        let directory_sectors = (directory.len() + 1 + 3) / 4;
+        let mini_stream_sectors = (mini_stream.len() + 511) / 512;
+        let first_directory = 1_u32;
+        let first_mini_fat = first_directory + u32::try_from(directory_sectors).unwrap();
+        let first_mini_stream = first_mini_fat + 1;
+        let sector_count = first_mini_stream as usize + mini_stream_sectors;
+        assert!(sector_count <= 128 && mini_fat.len() <= 128);
+
+        let mut fat = vec![FREE; 128];
+        fat[0] = FAT_SECTOR;
+        let mut chain = |start: u32, count: usize| {
+            for index in 0..count {
+                let sector = start as usize + index;
+                fat[sector] = if index + 1 == count {
+                    END_OF_CHAIN
+                } else {
+                    u32::try_from(sector).unwrap() + 1
+                };
+            }
+        };
+        chain(first_directory, directory_sectors);
+        chain(first_mini_fat, 1);
+        chain(first_mini_stream, mini_stream_sectors);
+
+        let mut out = SIGNATURE.to_vec();
+        out.resize(24, 0);
+        out.extend_from_slice(&0x003E_u16.to_le_bytes());
+        out.extend_from_slice(&3_u16.to_le_bytes());
+        out.extend_from_slice(&0xFFFE_u16.to_le_bytes());
+        out.extend_from_slice(&9_u16.to_le_bytes());
+        out.extend_from_slice(&6_u16.to_le_bytes());
+        out.resize(44, 0);
+        for field in &[
+            1,
+            first_directory,
+            0,
+            4096,
+            first_mini_fat,
+            1,
+            END_OF_CHAIN,
+            0,
+        ] {
+            out.extend_from_slice(&u32::to_le_bytes(*field));
+        }
+        out.extend_from_slice(&0_u32.to_le_bytes());
+        for _ in 1..109 {
+            out.extend_from_slice(&FREE.to_le_bytes());
+        }
+        assert_eq!(out.len(), 512);
+
+        out.extend(fat.iter().flat_map(|entry| entry.to_le_bytes()));
+        let root_size = u64::try_from(mini_stream.len()).unwrap();
+        let mut entries = directory_entry("Root Entry", 5, first_mini_stream, root_size);
+        entries.extend(directory.into_iter().flatten());
+        entries.resize(directory_sectors * 512, 0);
+        out.extend_from_slice(&entries);
+        let mut mini_fat_sector = mini_fat
+            .iter()
+            .flat_map(|entry| entry.to_le_bytes())
+            .collect::<Vec<_>>();
+        mini_fat_sector.resize(512, 0xFF);
+        out.extend_from_slice(&mini_fat_sector);
+        mini_stream.resize(mini_stream_sectors * 512, 0);
+        out.extend_from_slice(&mini_stream);
+        out
+    }
+
+    #[test]
+    fn read_streams() {
+        let large = vec![7; 300];
+        let file = build(&[("DestList", b"header"), ("1", &large), ("2", b"")]);
+        let file = CompoundFile::parse(&file).unwrap();
+        let streams = file.streams();
+        assert_eq!(
+            streams
+                .iter()
+                .map(|stream| stream.name.as_str())
+                .collect::<Vec<_>>(),
+            ["DestList", "1", "2"]
+        );
+        let mut budget = usize::MAX;
+        assert_eq!(file.read(&streams[0], &mut budget), b"header");
+        assert_eq!(file.read(&streams[1], &mut budget), large);
+        assert!(file.read(&streams[2], &mut budget).is_empty());
+        assert!(CompoundFile::parse(b"not a compound file").is_none());
+    }
+
+    #[test]
+    fn reads_are_bounded_by_the_budget() {
+        let large = vec![7; 300];
+        let file = build(&[("1", &large)]);
+        let file = CompoundFile::parse(&file).unwrap();
+        let stream = &file.streams()[0];
+        // Streams that share a chain are paid for on every read.
+        let mut budget = 400;
+        assert_eq!(file.read(stream, &mut budget).len(), 300);
+        assert_eq!(file.read(stream, &mut budget).len(), 100);
+        assert!(file.read(stream, &mut budget).is_empty());
+        assert_eq!(budget, 0);
+    }
+
+    #[test]
+    fn cyclic_difat_is_bounded() {
+        let mut file = build(&[("1", b"entry")]);
+        // Append a DIFAT sector whose next pointer refers to itself and
+        // claim millions of DIFAT sectors in the header.
+        let difat = u32::try_from(file.len() / 512 - 1).unwrap();
+        let mut sector = vec![0xFF; 508];
+        sector.extend_from_slice(&difat.to_le_bytes());
+        file.extend_from_slice(&sector);
+        file[68..72].copy_from_slice(&difat.to_le_bytes());
+        file[72..76].copy_from_slice(&4_000_000_u32.to_le_bytes());
+        let parsed = CompoundFile::parse(&file).unwrap();
+        let stream = &parsed.streams()[0];
+        assert_eq!(parsed.read(stream, &mut file.len()), b"entry");
+
+        // A FAT chain that loops back on itself is no longer than the file
+        // and stops at the budget.
+        let mut file = build(&[("1", b"entry")]);
+        file[512 + 4..512 + 8].copy_from_slice(&1_u32.to_le_bytes());
+        let parsed = CompoundFile::parse(&file).unwrap();
+        assert!(parsed.read_chain(1, None, usize::MAX).len() <= file.len());
+        assert_eq!(parsed.read_chain(1, None, 1000).len(), 1000);
+    }
+}
@@ -0,0 +1,422 @@
+// src/definition.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fs;
+use std::io;
+use std::mem;
+use std::path::PathBuf;
+
+use crate::folder_descriptions::{Category, FolderDescription};
+use crate::path;
+use crate::{FolderId, KnownFolder, Resolver};
+
+/// Resolve the folder `id` by walking its parent chain.
+///
+/// Each folder is first resolved with `resolver`. Folders the resolver
+/// cannot locate are looked up with `lookup`, which returns the folder's
+/// parent and its path relative to the parent. Returns [`None`] if the chain
+/// ends before a folder is located or if it contains a cycle.
+pub(crate) fn resolve_chain<'a, R, F>(id: FolderId, resolver: &R, lookup: F) -> Option<PathBuf>
+where
+    R: Resolver + ?Sized,
+    F: Fn(FolderId) -> Option<(FolderId, &'a str)>,
+{
+    let mut visited = Vec::new();
+    let mut relative_paths = Vec::new();
+    let mut current = id;
+    let base = loop {
+        if let Some(path) = resolver.resolve_id(current) {
+            break path;
+        }
+        if visited.contains(&current) {
+            return None;
+        }
+        visited.push(current);
+        let (parent, relative_path) = lookup(current)?;
+        relative_paths.push(relative_path);
+        current = parent;
+    };
+    let path = relative_paths
+        .iter()
+        .rev()
+        .fold(base, |path, relative| path::join(&path, relative));
+    Some(path)
+}
+
+/// An application-defined known folder located relative to another known
+/// folder.
+///
+/// Definitions are resolved by a [`DefinitionResolver`].
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{Guid, KnownFolder, KnownFolderDefinition};
+///
+/// let projects = KnownFolderDefinition::new(
+///     Guid::from_u128(0xb5a0c4e1_0f3c_4c9b_9e6a_2b1c7d8e9f00),
+///     "Acme Projects",
+///     KnownFolder::Documents,
+///     r"Acme\Projects",
+/// )
+/// .with_create_if_missing(true);
+/// assert_eq!(projects.name(), "Acme Projects");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownFolderDefinition {
+    id: FolderId,
+    name: String,
+    parent: FolderId,
+    relative_path: String,
+    category: Category,
+    create: bool,
+}
+
+impl KnownFolderDefinition {
+    /// Define the folder with ID `id` and canonical name `name` at
+    /// `relative_path` under the `parent` folder.
+    ///
+    /// The parent may be a built-in [`KnownFolder`] or another custom folder.
+    /// The folder is in the [`Category::PerUser`] category and is not
+    /// created if it is missing.
+    #[must_use]
+    pub fn new<I, N, P, S>(id: I, name: N, parent: P, relative_path: S) -> Self
+    where
+        I: Into<FolderId>,
+        N: Into<String>,
+        P: Into<FolderId>,
+        S: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            parent: parent.into(),
+            relative_path: relative_path.into(),
+            category: Category::PerUser,
+            create: false,
+        }
+    }
+
+    /// Set the category of the folder.
+    #[must_use]
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Set whether the folder is created when it is resolved with
+    /// [`DefinitionResolver::resolve_or_create`] and does not exist.
+    #[must_use]
+    pub fn with_create_if_missing(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// Build a definition from a folder description read from the registry.
+    ///
+    /// Returns [`None`] if the description does not locate the folder
+    /// relative to a parent folder. `PreCreate` descriptions are created if
+    /// missing.
+    #[must_use]
+    pub fn from_description(description: &FolderDescription) -> Option<Self> {
+        Some(Self {
+            id: description.id(),
+            name: description.name().to_owned(),
+            parent: description.parent()?,
+            relative_path: description.relative_path()?.to_owned(),
+            category: description.category().unwrap_or(Category::PerUser),
+            create: description.is_pre_create(),
+        })
+    }
+
+    /// The ID of the defined folder.
+    #[must_use]
+    pub fn id(&self) -> FolderId {
+        self.id
+    }
+
+    /// The canonical name of the folder.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The ID of the folder this folder is located in.
+    #[must_use]
+    pub fn parent(&self) -> FolderId {
+        self.parent
+    }
+
+    /// The path of the folder relative to its parent.
+    #[must_use]
+    pub fn relative_path(&self) -> &str {
+        &self.relative_path
+    }
+
+    /// The category of the folder.
+    #[must_use]
+    pub fn category(&self) -> Category {
+        self.category
+    }
+
+    /// Return `true` if the folder is created when it is missing.
+    #[must_use]
+    pub fn is_create_if_missing(&self) -> bool {
+        self.create
+    }
+}
+
+/// A [`Resolver`] that extends another resolver with custom known folders.
+///
+/// Folders the inner resolver can locate resolve to that location. Other
+/// folders with a [`KnownFolderDefinition`] resolve to their parent folder
+/// joined with their relative path, where the parent is resolved the same
+/// way. Parent chains that contain a cycle do not resolve.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use known_folders::{
+///     DefinitionResolver, FolderId, Guid, KnownFolder, KnownFolderDefinition, Resolver, Snapshot,
+/// };
+///
+/// let acme = Guid::from_u128(0xb5a0c4e1_0f3c_4c9b_9e6a_2b1c7d8e9f00);
+/// let projects = Guid::from_u128(0xb5a0c4e1_0f3c_4c9b_9e6a_2b1c7d8e9f01);
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+///
+/// let mut resolver = DefinitionResolver::new(snapshot);
+/// resolver.define(KnownFolderDefinition::new(acme, "Acme", KnownFolder::Documents, "Acme"));
+/// resolver.define(KnownFolderDefinition::new(projects, "Acme Projects", acme, "Projects"));
+///
+/// assert_eq!(
+///     resolver.resolve_id(FolderId::Custom(projects)),
+///     Some(PathBuf::from(r"C:\Users\jsmith\Documents\Acme\Projects")),
+/// );
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct DefinitionResolver<R> {
+    inner: R,
+    definitions: Vec<KnownFolderDefinition>,
+}
+
+impl<R> DefinitionResolver<R> {
+    /// Construct a resolver with no custom folders that delegates to
+    /// `inner`.
+    #[must_use]
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            definitions: Vec::new(),
+        }
+    }
+
+    /// Add a custom folder definition.
+    ///
+    /// If a folder with the same ID was already defined, the old definition
+    /// is replaced and returned.
+    pub fn define(&mut self, definition: KnownFolderDefinition) -> Option<KnownFolderDefinition> {
+        if let Some(existing) = self.definitions.iter_mut().find(|d| d.id == definition.id) {
+            return Some(mem::replace(existing, definition));
+        }
+        self.definitions.push(definition);
+        None
+    }
+
+    /// Retrieve the definition of the folder with ID `id`.
+    #[must_use]
+    pub fn get(&self, id: FolderId) -> Option<&KnownFolderDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.id == id)
+    }
+
+    /// Retrieve the definition of the folder with the canonical name `name`,
+    /// ignoring ASCII case.
+    #[must_use]
+    pub fn by_name(&self, name: &str) -> Option<&KnownFolderDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Iterate over all custom folder definitions.
+    pub fn definitions(&self) -> impl Iterator<Item = &KnownFolderDefinition> {
+        self.definitions.iter()
+    }
+
+    /// Return a reference to the inner resolver.
+    #[must_use]
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    /// Consume this resolver and return the inner resolver.
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> DefinitionResolver<R>
+where
+    R: Resolver,
+{
+    /// Resolve the folder with ID `id` and create it if it is missing and
+    /// its definition requests it.
+    ///
+    /// Missing parent directories are created as well. The resolved path is
+    /// interpreted as a host path.
+    ///
+    /// # Errors
+    ///
+    /// If creating the directory fails, an error is returned.
+    pub fn resolve_or_create(&self, id: FolderId) -> io::Result<Option<PathBuf>> {
+        let path = match self.resolve_id(id) {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let create = self
+            .get(id)
+            .map_or(false, KnownFolderDefinition::is_create_if_missing);
+        if create && !path.is_dir() {
+            fs::create_dir_all(&path)?;
+        }
+        Ok(Some(path))
+    }
+}
+
+impl<R> Resolver for DefinitionResolver<R>
+where
+    R: Resolver,
+{
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.resolve_id(FolderId::Known(known_folder))
+    }
+
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        resolve_chain(id, &self.inner, |id| {
+            self.get(id)
+                .map(|definition| (definition.parent, definition.relative_path.as_str()))
+        })
+    }
+}
+
+impl<R> Extend<KnownFolderDefinition> for DefinitionResolver<R> {
+    fn extend<I: IntoIterator<Item = KnownFolderDefinition>>(&mut self, iter: I) {
+        for definition in iter {
+            self.define(definition);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::{Guid, Snapshot};
+
+    const ACME: Guid = Guid::from_u128(0xb5a0_c4e1_0f3c_4c9b_9e6a_2b1c_7d8e_9f00);
+    const PROJECTS: Guid = Guid::from_u128(0xb5a0_c4e1_0f3c_4c9b_9e6a_2b1c_7d8e_9f01);
+
+    #[test]
+    fn resolve_custom_parent_chain() {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        let mut resolver = DefinitionResolver::new(snapshot);
+        resolver.extend(vec![
+            KnownFolderDefinition::new(PROJECTS, "Acme Projects", ACME, "Projects"),
+            KnownFolderDefinition::new(ACME, "Acme", KnownFolder::Documents, r"Acme\"),
+        ]);
+        assert_eq!(
+            resolver.resolve_id(FolderId::Custom(PROJECTS)),
+            Some(PathBuf::from(r"C:\Users\jsmith\Documents\Acme\Projects"))
+        );
+        assert_eq!(
+            resolver.resolve(KnownFolder::Documents),
+            Some(PathBuf::from(r"C:\Users\jsmith\Documents"))
+        );
+        assert_eq!(resolver.resolve(KnownFolder::Downloads), None);
+        assert_eq!(
+            resolver.by_name("acme projects").unwrap().parent(),
+            FolderId::Custom(ACME)
+        );
+    }
+
+    #[test]
+    fn definitions_can_fill_in_built_in_folders() {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+        let mut resolver = DefinitionResolver::new(snapshot);
+        let downloads = KnownFolderDefinition::new(
+            KnownFolder::Downloads,
+            "Downloads",
+            KnownFolder::Profile,
+            "Downloads",
+        );
+        assert_eq!(resolver.define(downloads.clone()), None);
+        assert_eq!(resolver.define(downloads.clone()), Some(downloads));
+        assert_eq!(
+            resolver.resolve(KnownFolder::Downloads),
+            Some(PathBuf::from(r"C:\Users\jsmith\Downloads"))
+        );
+    }
+
+    #[test]
+    fn cycles_do_not_resolve() {
+        let mut resolver = DefinitionResolver::new(Snapshot::new());
+        resolver.define(KnownFolderDefinition::new(ACME, "Acme", PROJECTS, "Acme"));
+        resolver.define(KnownFolderDefinition::new(
+            PROJECTS, "Projects", ACME, "Projects",
+        ));
+        assert_eq!(resolver.resolve_id(FolderId::Custom(ACME)), None);
+        resolver.define(KnownFolderDefinition::new(ACME, "Acme", ACME, "Acme"));
+        assert_eq!(resolver.resolve_id(FolderId::Custom(ACME)), None);
+    }
+
+    #[test]
+    fn resolve_or_create() {
+        let temp_dir = TempDir::new("definition");
+        let root = temp_dir.path();
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Documents, root);
+        let mut resolver = DefinitionResolver::new(snapshot);
+        resolver.define(
+            KnownFolderDefinition::new(ACME, "Acme", KnownFolder::Documents, r"Acme\Projects")
+                .with_create_if_missing(true),
+        );
+        resolver.define(KnownFolderDefinition::new(
+            PROJECTS,
+            "Other",
+            KnownFolder::Documents,
+            "Other",
+        ));
+
+        let acme = resolver
+            .resolve_or_create(FolderId::Custom(ACME))
+            .unwrap()
+            .unwrap();
+        assert_eq!(acme, root.join("Acme").join("Projects"));
+        assert!(acme.is_dir());
+        let other = resolver
+            .resolve_or_create(FolderId::Custom(PROJECTS))
+            .unwrap()
+            .unwrap();
+        assert!(!other.exists());
+        assert_eq!(
+            resolver
+                .resolve_or_create(KnownFolder::Music.into())
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,462 @@
+// src/desktop_ini.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Folder customization files (`desktop.ini`).
+//!
+//! Known folders carry a hidden `desktop.ini` file whose `[.ShellClassInfo]`
+//! section names the string resource Explorer displays for the folder, e.g.
+//! `LocalizedResourceName=@%SystemRoot%\system32\shell32.dll,-21798`, and the
+//! folder's icon.
+//!
+//! [`DesktopIni`] is a tolerant reader for these files. Loading the
+//! referenced string resources is left to the caller.
+
+use core::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::text;
+use crate::{path, Environment, KnownFolder, Resolver};
+
+/// The file name of folder customization files.
+pub const FILE_NAME: &str = "desktop.ini";
+
+/// The section that holds shell folder customizations.
+pub const SHELL_CLASS_INFO: &str = ".ShellClassInfo";
+
+/// An indirect string resource reference like
+/// `@%SystemRoot%\system32\shell32.dll,-21798`.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::desktop_ini::ResourceString;
+///
+/// let name = ResourceString::parse(r"@%SystemRoot%\system32\shell32.dll,-21798").unwrap();
+/// assert_eq!(name.module(), r"%SystemRoot%\system32\shell32.dll");
+/// assert_eq!(name.resource_id(), 21798);
+/// assert_eq!(name.to_string(), r"@%SystemRoot%\system32\shell32.dll,-21798");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceString {
+    module: String,
+    resource_id: u32,
+    negative: bool,
+}
+
+impl ResourceString {
+    /// Parse an indirect string of the form `@module,-id`.
+    ///
+    /// The `-` before the ID is conventional but optional; whether it was
+    /// present is kept so the string displays as written. A trailing `;`
+    /// version modifier is ignored. Returns [`None`] if `value` is not an
+    /// indirect string that references a module.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().strip_prefix('@')?;
+        let value = value.split(';').next().unwrap_or(value);
+        let (module, id) = value.rsplit_once(',')?;
+        let id = id.trim();
+        let (negative, id) = match id.strip_prefix('-') {
+            Some(id) => (true, id),
+            None => (false, id),
+        };
+        let resource_id = id.parse().ok()?;
+        let module = module.trim();
+        if module.is_empty() {
+            return None;
+        }
+        Some(Self {
+            module: module.to_owned(),
+            resource_id,
+            negative,
+        })
+    }
+
+    /// The path of the module that holds the string resource, as written.
+    ///
+    /// The path may contain environment variable references.
+    #[must_use]
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// The path of the module with environment variable references
+    /// expanded against `env`.
+    #[must_use]
+    pub fn expanded_module(&self, env: &Environment) -> String {
+        env.expand(&self.module)
+    }
+
+    /// The ID of the string resource in the module's string table.
+    #[must_use]
+    pub fn resource_id(&self) -> u32 {
+        self.resource_id
+    }
+}
+
+impl fmt::Display for ResourceString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.negative { "-" } else { "" };
+        write!(f, "@{},{sign}{}", self.module, self.resource_id)
+    }
+}
+
+/// An icon reference, e.g. `%SystemRoot%\system32\imageres.dll,-184`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IconLocation {
+    path: String,
+    index: i32,
+}
+
+impl IconLocation {
+    pub(crate) fn new(path: String, index: i32) -> Self {
+        Self { path, index }
+    }
+
+    /// The path of the icon file or module, as written.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The icon index. Negative values are resource IDs.
+    #[must_use]
+    pub fn index(&self) -> i32 {
+        self.index
+    }
+}
+
+/// One `[section]` of a `desktop.ini` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    name: String,
+    entries: Vec<(String, String)>,
+}
+
+impl Section {
+    /// The section name, without brackets.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Iterate over the `key=value` entries of this section in file order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Return the value of the first entry named `key`, ignoring case.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries()
+            .find(|(name, _)| text::eq_ignore_case(name, key))
+            .map(|(_, value)| value)
+    }
+}
+
+/// A parsed `desktop.ini` file.
+///
+/// Parsing never fails. Lines that are neither section headers nor
+/// `key=value` entries are ignored, as are comments starting with `;`, like
+/// the Win32 profile APIs do.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::desktop_ini::DesktopIni;
+///
+/// let ini = DesktopIni::parse(b"\
+/// [.ShellClassInfo]\r\n\
+/// LocalizedResourceName=@%SystemRoot%\\system32\\shell32.dll,-21798\r\n\
+/// IconResource=%SystemRoot%\\system32\\imageres.dll,-184\r\n");
+///
+/// let name = ini.localized_resource_name().unwrap();
+/// assert_eq!(name.resource_id(), 21798);
+/// assert_eq!(ini.icon().unwrap().index(), -184);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct DesktopIni {
+    sections: Vec<Section>,
+}
+
+impl DesktopIni {
+    /// Parse the raw contents of a `desktop.ini` file.
+    ///
+    /// The text encoding is detected from the byte order mark. Files without
+    /// one are decoded as UTF-8 or ANSI.
+    #[must_use]
+    pub fn parse(bytes: &[u8]) -> Self {
+        Self::parse_str(&text::decode_text_file(bytes))
+    }
+
+    /// Parse the text of a `desktop.ini` file.
+    #[must_use]
+    pub fn parse_str(contents: &str) -> Self {
+        let mut sections: Vec<Section> = Vec::new();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name.split(']').next().unwrap_or(name).trim();
+                sections.push(Section {
+                    name: name.to_owned(),
+                    entries: Vec::new(),
+                });
+                continue;
+            }
+            if let (Some((key, value)), Some(section)) = (line.split_once('='), sections.last_mut())
+            {
+                section
+                    .entries
+                    .push((key.trim().to_owned(), value.trim().to_owned()));
+            }
+        }
+        Self { sections }
+    }
+
+    /// Read and parse the `desktop.ini` file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, an error is returned.
+    pub fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read(path).map(|bytes| Self::parse(&bytes))
+    }
+
+    /// Iterate over the sections of the file in file order.
+    pub fn sections(&self) -> impl Iterator<Item = &Section> {
+        self.sections.iter()
+    }
+
+    /// Return the first section named `name`, ignoring case.
+    #[must_use]
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections
+            .iter()
+            .find(|section| text::eq_ignore_case(&section.name, name))
+    }
+
+    /// Return the value of `key` in `section`, ignoring case.
+    #[must_use]
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.section(section)?.get(key)
+    }
+
+    /// The `LocalizedResourceName` of the `[.ShellClassInfo]` section, which
+    /// names the string resource Explorer displays for the folder.
+    ///
+    /// Returns [`None`] if the entry is missing or is a plain string rather
+    /// than an indirect string resource.
+    #[must_use]
+    pub fn localized_resource_name(&self) -> Option<ResourceString> {
+        ResourceString::parse(self.get(SHELL_CLASS_INFO, "LocalizedResourceName")?)
+    }
+
+    /// The icon of the folder from the `[.ShellClassInfo]` section.
+    ///
+    /// The `IconResource` entry is preferred over the legacy `IconFile` and
+    /// `IconIndex` entries. A missing or malformed index is treated as the
+    /// first icon, index 0.
+    #[must_use]
+    pub fn icon(&self) -> Option<IconLocation> {
+        if let Some(resource) = self.get(SHELL_CLASS_INFO, "IconResource") {
+            let (path, index) = resource.rsplit_once(',').unwrap_or((resource, "0"));
+            return Some(IconLocation {
+                path: path.trim().to_owned(),
+                index: index.trim().parse().unwrap_or(0),
+            });
+        }
+        let path = self.get(SHELL_CLASS_INFO, "IconFile")?;
+        let index = self
+            .get(SHELL_CLASS_INFO, "IconIndex")
+            .and_then(|index| index.trim().parse().ok())
+            .unwrap_or(0);
+        Some(IconLocation {
+            path: path.to_owned(),
+            index,
+        })
+    }
+}
+
+/// Read the `desktop.ini` file of `known_folder`.
+///
+/// The folder is resolved with `resolver` and the file is read from the host
+/// file system. Returns [`None`] if the folder cannot be resolved or has no
+/// `desktop.ini` file.
+///
+/// # Errors
+///
+/// If the file exists but cannot be read, an error is returned.
+pub fn for_known_folder<R>(
+    resolver: &R,
+    known_folder: KnownFolder,
+) -> io::Result<Option<DesktopIni>>
+where
+    R: Resolver + ?Sized,
+{
+    let path = match resolver.resolve(known_folder) {
+        Some(folder) => path::join(&folder, FILE_NAME),
+        None => return Ok(None),
+    };
+    match DesktopIni::open(path) {
+        Ok(ini) => Ok(Some(ini)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Return the known folder that contains the `desktop.ini` file at `path`.
+///
+/// The folder's resolved path must be the parent directory of `path`.
+/// Paths are compared ignoring case and the difference between `\` and `/`
+/// separators.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use known_folders::desktop_ini::known_folder_containing;
+/// use known_folders::{KnownFolder, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Downloads, r"C:\Users\jsmith\Downloads");
+///
+/// let path = Path::new(r"C:\Users\jsmith\downloads\desktop.ini");
+/// assert_eq!(known_folder_containing(path, &snapshot), Some(KnownFolder::Downloads));
+/// ```
+#[must_use]
+pub fn known_folder_containing<R>(path: &Path, resolver: &R) -> Option<KnownFolder>
+where
+    R: Resolver + ?Sized,
+{
+    let parent = path::parent(path)?;
+    KnownFolder::ALL.iter().copied().find(|&known_folder| {
+        resolver
+            .resolve(known_folder)
+            .map_or(false, |folder| path::eq_ignore_case(&folder, &parent))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn parse_utf16_file() {
+        let contents = "\r\n[.ShellClassInfo]\r\n; comment\r\nlocalizedresourcename = @%SystemRoot%\\system32\\shell32.dll,-21770\r\nIconFile=%SystemRoot%\\system32\\shell32.dll\r\nIconIndex=-235\r\n[ViewState]\r\nMode=\r\nVid=\r\nFolderType=Documents\r\n";
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(contents.encode_utf16().flat_map(u16::to_le_bytes));
+        let ini = DesktopIni::parse(&bytes);
+        assert_eq!(ini.sections().count(), 2);
+        assert_eq!(ini.get("viewstate", "FolderType"), Some("Documents"));
+        assert_eq!(ini.get("ViewState", "Mode"), Some(""));
+        let name = ini.localized_resource_name().unwrap();
+        assert_eq!(name.module(), r"%SystemRoot%\system32\shell32.dll");
+        assert_eq!(name.resource_id(), 21770);
+        let env = [("SystemRoot", r"C:\Windows")]
+            .iter()
+            .copied()
+            .collect::<Environment>();
+        assert_eq!(
+            name.expanded_module(&env),
+            r"C:\Windows\system32\shell32.dll"
+        );
+        let icon = ini.icon().unwrap();
+        assert_eq!(icon.path(), r"%SystemRoot%\system32\shell32.dll");
+        assert_eq!(icon.index(), -235);
+    }
+
+    #[test]
+    fn tolerate_malformed_lines() {
+        let ini = DesktopIni::parse(
+            b"orphan=1\n[.ShellClassInfo\nno equals sign\nLocalizedResourceName=Plain Name\n",
+        );
+        assert_eq!(ini.get(SHELL_CLASS_INFO, "orphan"), None);
+        assert_eq!(
+            ini.get(SHELL_CLASS_INFO, "LocalizedResourceName"),
+            Some("Plain Name")
+        );
+        assert_eq!(ini.localized_resource_name(), None);
+        assert_eq!(ini.icon(), None);
+
+        let ini = DesktopIni::parse_str("[.ShellClassInfo]\nIconResource=imageres.dll,bogus\n");
+        let icon = ini.icon().unwrap();
+        assert_eq!(icon.path(), "imageres.dll");
+        assert_eq!(icon.index(), 0);
+    }
+
+    #[test]
+    fn parse_resource_strings() {
+        assert_eq!(
+            ResourceString::parse("@shell32.dll,-21798;v2").map(|r| r.resource_id()),
+            Some(21798)
+        );
+        let positive = ResourceString::parse("@shell32.dll, 5").unwrap();
+        assert_eq!(positive.resource_id(), 5);
+        assert_eq!(positive.to_string(), "@shell32.dll,5");
+        assert_eq!(
+            ResourceString::parse("@shell32.dll,-5")
+                .unwrap()
+                .to_string(),
+            "@shell32.dll,-5"
+        );
+        assert_eq!(ResourceString::parse("shell32.dll,-21798"), None);
+        assert_eq!(ResourceString::parse("@,-1"), None);
+        assert_eq!(ResourceString::parse("@shell32.dll"), None);
+    }
+
+    #[test]
+    fn read_known_folder_desktop_ini() {
+        let temp_dir = TempDir::new("desktop-ini");
+        let root = temp_dir.path();
+        let music = root.join("Music");
+        fs::create_dir_all(&music).unwrap();
+        fs::write(
+            music.join(FILE_NAME),
+            "[.ShellClassInfo]\nLocalizedResourceName=@%SystemRoot%\\system32\\shell32.dll,-21790\n",
+        )
+        .unwrap();
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Music, &music);
+        snapshot.insert(KnownFolder::Videos, root.join("Videos"));
+
+        let ini = for_known_folder(&snapshot, KnownFolder::Music)
+            .unwrap()
+            .unwrap();
+        assert_eq!(ini.localized_resource_name().unwrap().resource_id(), 21790);
+        assert_eq!(
+            for_known_folder(&snapshot, KnownFolder::Videos).unwrap(),
+            None
+        );
+        assert_eq!(
+            for_known_folder(&snapshot, KnownFolder::Pictures).unwrap(),
+            None
+        );
+        assert_eq!(
+            known_folder_containing(&music.join(FILE_NAME), &snapshot),
+            Some(KnownFolder::Music)
+        );
+        assert_eq!(
+            known_folder_containing(&root.join(FILE_NAME), &snapshot),
+            None
+        );
+    }
+}
@@ -0,0 +1,589 @@
+// src/display_name.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Bundled localized display names for known folders.
+//!
+//! Explorer loads folder display names from string resources in shell32 and
+//! friends. This table carries the names of the user-visible folders for the
+//! major shell languages so they can be shown without calling into the shell.
+
+use crate::KnownFolder;
+
+/// Languages with bundled translations, in the column order of the rows
+/// returned by [`translations`].
+const LANGUAGES: [&str; 13] = [
+    "de", "es", "fr", "it", "ja", "ko", "nl", "pl", "pt", "ru", "sv", "zh-Hans", "zh-Hant",
+];
+
+type Row = [&'static str; LANGUAGES.len()];
+
+const DESKTOP: Row = [
+    "Desktop",
+    "Escritorio",
+    "Bureau",
+    "Desktop",
+    "デスクトップ",
+    "바탕 화면",
+    "Bureaublad",
+    "Pulpit",
+    "Área de Trabalho",
+    "Рабочий стол",
+    "Skrivbord",
+    "桌面",
+    "桌面",
+];
+
+const DOCUMENTS: Row = [
+    "Dokumente",
+    "Documentos",
+    "Documents",
+    "Documenti",
+    "ドキュメント",
+    "문서",
+    "Documenten",
+    "Dokumenty",
+    "Documentos",
+    "Документы",
+    "Dokument",
+    "文档",
+    "文件",
+];
+
+const DOWNLOADS: Row = [
+    "Downloads",
+    "Descargas",
+    "Téléchargements",
+    "Download",
+    "ダウンロード",
+    "다운로드",
+    "Downloads",
+    "Pobrane",
+    "Downloads",
+    "Загрузки",
+    "Hämtade filer",
+    "下载",
+    "下載",
+];
+
+const MUSIC: Row = [
+    "Musik",
+    "Música",
+    "Musique",
+    "Musica",
+    "ミュージック",
+    "음악",
+    "Muziek",
+    "Muzyka",
+    "Músicas",
+    "Музыка",
+    "Musik",
+    "音乐",
+    "音樂",
+];
+
+const PICTURES: Row = [
+    "Bilder",
+    "Imágenes",
+    "Images",
+    "Immagini",
+    "ピクチャ",
+    "사진",
+    "Afbeeldingen",
+    "Obrazy",
+    "Imagens",
+    "Изображения",
+    "Bilder",
+    "图片",
+    "圖片",
+];
+
+const VIDEOS: Row = [
+    "Videos",
+    "Vídeos",
+    "Vidéos",
+    "Video",
+    "ビデオ",
+    "동영상",
+    "Video's",
+    "Wideo",
+    "Vídeos",
+    "Видео",
+    "Videoklipp",
+    "视频",
+    "影片",
+];
+
+const FAVORITES: Row = [
+    "Favoriten",
+    "Favoritos",
+    "Favoris",
+    "Preferiti",
+    "お気に入り",
+    "즐겨찾기",
+    "Favorieten",
+    "Ulubione",
+    "Favoritos",
+    "Избранное",
+    "Favoriter",
+    "收藏夹",
+    "我的最愛",
+];
+
+const LINKS: Row = [
+    "Links",
+    "Vínculos",
+    "Liens",
+    "Collegamenti",
+    "リンク",
+    "링크",
+    "Koppelingen",
+    "Łącza",
+    "Links",
+    "Ссылки",
+    "Länkar",
+    "链接",
+    "連結",
+];
+
+const CONTACTS: Row = [
+    "Kontakte",
+    "Contactos",
+    "Contacts",
+    "Contatti",
+    "アドレス帳",
+    "연락처",
+    "Contactpersonen",
+    "Kontakty",
+    "Contatos",
+    "Контакты",
+    "Kontakter",
+    "联系人",
+    "連絡人",
+];
+
+const SAVED_GAMES: Row = [
+    "Gespeicherte Spiele",
+    "Juegos guardados",
+    "Jeux enregistrés",
+    "Giochi salvati",
+    "保存したゲーム",
+    "저장된 게임",
+    "Opgeslagen games",
+    "Zapisane gry",
+    "Jogos Salvos",
+    "Сохраненные игры",
+    "Sparade spel",
+    "保存的游戏",
+    "儲存的遊戲",
+];
+
+const SEARCHES: Row = [
+    "Suchvorgänge",
+    "Búsquedas",
+    "Recherches",
+    "Ricerche",
+    "検索",
+    "검색",
+    "Zoekopdrachten",
+    "Wyszukiwania",
+    "Pesquisas",
+    "Поиски",
+    "Sökningar",
+    "搜索",
+    "搜尋",
+];
+
+const OBJECTS_3D: Row = [
+    "3D-Objekte",
+    "Objetos 3D",
+    "Objets 3D",
+    "Oggetti 3D",
+    "3D オブジェクト",
+    "3D 개체",
+    "3D-objecten",
+    "Obiekty 3D",
+    "Objetos 3D",
+    "Объемные объекты",
+    "3D-objekt",
+    "3D 对象",
+    "3D 物件",
+];
+
+const CAMERA_ROLL: Row = [
+    "Eigene Aufnahmen",
+    "Álbum de cámara",
+    "Pellicule",
+    "Rullino",
+    "カメラ ロール",
+    "카메라 앨범",
+    "Filmrol",
+    "Z aparatu",
+    "Rolo da Câmera",
+    "Альбом камеры",
+    "Kamerabilder",
+    "本机照片",
+    "本機相簿",
+];
+
+const SAVED_PICTURES: Row = [
+    "Gespeicherte Bilder",
+    "Imágenes guardadas",
+    "Images enregistrées",
+    "Immagini salvate",
+    "保存済みの写真",
+    "저장된 사진",
+    "Opgeslagen afbeeldingen",
+    "Zapisane obrazy",
+    "Imagens Salvas",
+    "Сохраненные фотографии",
+    "Sparade bilder",
+    "保存的图片",
+    "儲存的圖片",
+];
+
+const SCREENSHOTS: Row = [
+    "Screenshots",
+    "Capturas de pantalla",
+    "Captures d’écran",
+    "Screenshot",
+    "スクリーンショット",
+    "스크린샷",
+    "Schermafbeeldingen",
+    "Zrzuty ekranu",
+    "Capturas de Tela",
+    "Снимки экрана",
+    "Skärmbilder",
+    "屏幕截图",
+    "螢幕擷取畫面",
+];
+
+const PUBLIC: Row = [
+    "Öffentlich",
+    "Acceso público",
+    "Public",
+    "Pubblica",
+    "パブリック",
+    "공용",
+    "Openbaar",
+    "Publiczny",
+    "Público",
+    "Общие",
+    "Offentlig",
+    "公用",
+    "公用",
+];
+
+const RECYCLE_BIN: Row = [
+    "Papierkorb",
+    "Papelera de reciclaje",
+    "Corbeille",
+    "Cestino",
+    "ごみ箱",
+    "휴지통",
+    "Prullenbak",
+    "Kosz",
+    "Lixeira",
+    "Корзина",
+    "Papperskorgen",
+    "回收站",
+    "資源回收筒",
+];
+
+const THIS_PC: Row = [
+    "Dieser PC",
+    "Este equipo",
+    "Ce PC",
+    "Questo PC",
+    "PC",
+    "내 PC",
+    "Deze pc",
+    "Ten komputer",
+    "Este Computador",
+    "Этот компьютер",
+    "Den här datorn",
+    "此电脑",
+    "本機",
+];
+
+const LIBRARIES: Row = [
+    "Bibliotheken",
+    "Bibliotecas",
+    "Bibliothèques",
+    "Raccolte",
+    "ライブラリ",
+    "라이브러리",
+    "Bibliotheken",
+    "Biblioteki",
+    "Bibliotecas",
+    "Библиотеки",
+    "Bibliotek",
+    "库",
+    "媒體櫃",
+];
+
+/// Return the translated names of `known_folder`, if any are bundled.
+///
+/// Folders that Explorer shows under the same name, like a library and its
+/// default member folder, share a row.
+fn translations(known_folder: KnownFolder) -> Option<&'static Row> {
+    let row = match known_folder {
+        KnownFolder::Desktop | KnownFolder::AppDataDesktop => &DESKTOP,
+        KnownFolder::Documents
+        | KnownFolder::DocumentsLibrary
+        | KnownFolder::LocalDocuments
+        | KnownFolder::SkyDriveDocuments
+        | KnownFolder::AppDataDocuments => &DOCUMENTS,
+        KnownFolder::Downloads | KnownFolder::LocalDownloads => &DOWNLOADS,
+        KnownFolder::Music
+        | KnownFolder::MusicLibrary
+        | KnownFolder::LocalMusic
+        | KnownFolder::SkyDriveMusic => &MUSIC,
+        KnownFolder::Pictures
+        | KnownFolder::PicturesLibrary
+        | KnownFolder::LocalPictures
+        | KnownFolder::SkyDrivePictures => &PICTURES,
+        KnownFolder::Videos | KnownFolder::VideosLibrary | KnownFolder::LocalVideos => &VIDEOS,
+        KnownFolder::Favorites | KnownFolder::AppDataFavorites => &FAVORITES,
+        KnownFolder::Links => &LINKS,
+        KnownFolder::Contacts => &CONTACTS,
+        KnownFolder::SavedGames => &SAVED_GAMES,
+        KnownFolder::SavedSearches => &SEARCHES,
+        KnownFolder::Objects3D => &OBJECTS_3D,
+        KnownFolder::CameraRoll
+        | KnownFolder::CameraRollLibrary
+        | KnownFolder::SkyDriveCameraRoll => &CAMERA_ROLL,
+        KnownFolder::SavedPictures | KnownFolder::SavedPicturesLibrary => &SAVED_PICTURES,
+        KnownFolder::Screenshots => &SCREENSHOTS,
+        KnownFolder::Public => &PUBLIC,
+        KnownFolder::RecycleBinFolder => &RECYCLE_BIN,
+        KnownFolder::ComputerFolder => &THIS_PC,
+        KnownFolder::Libraries | KnownFolder::UsersLibraries | KnownFolder::PublicLibraries => {
+            &LIBRARIES
+        }
+        _ => return None,
+    };
+    Some(row)
+}
+
+/// Return the column in [`LANGUAGES`] for a locale name like `fr-CA`,
+/// `pt_BR`, or `zh-TW`.
+fn language_index(locale: &str) -> Option<usize> {
+    let mut subtags = locale.split(['-', '_']);
+    let language = subtags.next()?;
+    if language.eq_ignore_ascii_case("zh") {
+        let traditional = subtags.any(|subtag| {
+            ["Hant", "TW", "HK", "MO"]
+                .iter()
+                .any(|region| subtag.eq_ignore_ascii_case(region))
+        });
+        let script = if traditional { "zh-Hant" } else { "zh-Hans" };
+        return LANGUAGES.iter().position(|&lang| lang == script);
+    }
+    LANGUAGES
+        .iter()
+        .position(|lang| lang.eq_ignore_ascii_case(language))
+}
+
+impl KnownFolder {
+    /// Return the name Explorer displays for this known folder in the given
+    /// locale.
+    ///
+    /// `locale` is a language tag like `fr`, `fr-FR`, `pt_BR`, or `zh-TW`.
+    /// Translations are bundled for German, Spanish, French, Italian,
+    /// Japanese, Korean, Dutch, Polish, Portuguese, Russian, Swedish, and
+    /// Simplified and Traditional Chinese. Other locales, and folders without
+    /// a bundled translation, fall back to the English display name.
+    ///
+    /// This function is only available with the `display-names` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::Downloads.display_name("en-US"), "Downloads");
+    /// assert_eq!(KnownFolder::Downloads.display_name("fr-FR"), "Téléchargements");
+    /// assert_eq!(KnownFolder::Downloads.display_name("es"), "Descargas");
+    /// assert_eq!(KnownFolder::Fonts.display_name("es"), "Fonts");
+    /// ```
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "display-names")))]
+    pub fn display_name(self, locale: &str) -> &'static str {
+        language_index(locale)
+            .and_then(|index| translations(self).map(|row| row[index]))
+            .unwrap_or_else(|| english(self))
+    }
+}
+
+/// Return the English display name of `known_folder`.
+#[allow(clippy::match_same_arms)]
+#[allow(clippy::too_many_lines)]
+fn english(known_folder: KnownFolder) -> &'static str {
+    match known_folder {
+        KnownFolder::AccountPictures => "Account Pictures",
+        KnownFolder::AddNewPrograms => "Get Programs",
+        KnownFolder::AdminTools | KnownFolder::CommonAdminTools => "Administrative Tools",
+        KnownFolder::AllAppMods => "AllAppMods",
+        KnownFolder::AppCaptures => "Captures",
+        KnownFolder::AppDataDesktop | KnownFolder::Desktop => "Desktop",
+        KnownFolder::AppDataDocuments
+        | KnownFolder::Documents
+        | KnownFolder::DocumentsLibrary
+        | KnownFolder::LocalDocuments
+        | KnownFolder::SkyDriveDocuments => "Documents",
+        KnownFolder::AppDataFavorites | KnownFolder::Favorites => "Favorites",
+        KnownFolder::AppDataProgramData | KnownFolder::ProgramData => "ProgramData",
+        KnownFolder::AppUpdates => "Installed Updates",
+        KnownFolder::ApplicationShortcuts => "Application Shortcuts",
+        KnownFolder::AppsFolder => "Applications",
+        KnownFolder::CDBurning => "Temporary Burn Folder",
+        KnownFolder::CameraRoll
+        | KnownFolder::CameraRollLibrary
+        | KnownFolder::SkyDriveCameraRoll => "Camera Roll",
+        KnownFolder::ChangeRemovePrograms => "Programs and Features",
+        KnownFolder::CommonOEMLinks => "OEM Links",
+        KnownFolder::CommonPrograms | KnownFolder::Programs | KnownFolder::UserProgramFiles => {
+            "Programs"
+        }
+        KnownFolder::CommonStartMenu | KnownFolder::StartMenu => "Start Menu",
+        KnownFolder::CommonStartMenuPlaces => "Places",
+        KnownFolder::CommonStartup | KnownFolder::Startup => "Startup",
+        KnownFolder::CommonTemplates | KnownFolder::Templates | KnownFolder::SearchTemplates => {
+            "Templates"
+        }
+        KnownFolder::ComputerFolder => "This PC",
+        KnownFolder::ConflictFolder => "Conflicts",
+        KnownFolder::ConnectionsFolder => "Network Connections",
+        KnownFolder::Contacts => "Contacts",
+        KnownFolder::ControlPanelFolder => "Control Panel",
+        KnownFolder::Cookies => "Cookies",
+        KnownFolder::CurrentAppMods => "CurrentAppMods",
+        KnownFolder::DevelopmentFiles => "Development Files",
+        KnownFolder::Device => "Device",
+        KnownFolder::DeviceMetadataStore => "Device Metadata Store",
+        KnownFolder::Downloads | KnownFolder::LocalDownloads => "Downloads",
+        KnownFolder::Fonts => "Fonts",
+        KnownFolder::GameTasks | KnownFolder::PublicGameTasks => "GameExplorer",
+        KnownFolder::Games => "Games",
+        KnownFolder::History | KnownFolder::SearchHistory => "History",
+        KnownFolder::HomeGroup | KnownFolder::HomeGroupCurrentUser => "Homegroup",
+        KnownFolder::ImplicitAppShortcuts => "Implicit App Shortcuts",
+        KnownFolder::InternetCache => "Temporary Internet Files",
+        KnownFolder::InternetFolder => "The Internet",
+        KnownFolder::Libraries | KnownFolder::PublicLibraries | KnownFolder::UsersLibraries => {
+            "Libraries"
+        }
+        KnownFolder::Links => "Links",
+        KnownFolder::LocalAppData => "Local",
+        KnownFolder::LocalAppDataLow => "LocalLow",
+        KnownFolder::LocalStorage => "Local Storage",
+        KnownFolder::LocalizedResourcesDir => "Localized Resources",
+        KnownFolder::Music
+        | KnownFolder::MusicLibrary
+        | KnownFolder::LocalMusic
+        | KnownFolder::SkyDriveMusic => "Music",
+        KnownFolder::NetHood => "Network Shortcuts",
+        KnownFolder::NetworkFolder => "Network",
+        KnownFolder::Objects3D => "3D Objects",
+        KnownFolder::OneDrive | KnownFolder::SkyDrive => "OneDrive",
+        KnownFolder::OriginalImages => "Original Images",
+        KnownFolder::PhotoAlbums => "Slide Shows",
+        KnownFolder::Pictures
+        | KnownFolder::PicturesLibrary
+        | KnownFolder::LocalPictures
+        | KnownFolder::SkyDrivePictures => "Pictures",
+        KnownFolder::Playlists => "Playlists",
+        KnownFolder::PrintHood => "Printer Shortcuts",
+        KnownFolder::PrintersFolder => "Printers",
+        KnownFolder::Profile => "Profile",
+        KnownFolder::ProgramFiles | KnownFolder::ProgramFilesX64 | KnownFolder::ProgramFilesX86 => {
+            "Program Files"
+        }
+        KnownFolder::ProgramFilesCommon
+        | KnownFolder::ProgramFilesCommonX64
+        | KnownFolder::ProgramFilesCommonX86 => "Common Files",
+        KnownFolder::Public => "Public",
+        KnownFolder::PublicDesktop => "Public Desktop",
+        KnownFolder::PublicDocuments => "Public Documents",
+        KnownFolder::PublicDownloads => "Public Downloads",
+        KnownFolder::PublicMusic => "Public Music",
+        KnownFolder::PublicPictures => "Public Pictures",
+        KnownFolder::PublicRingtones | KnownFolder::Ringtones => "Ringtones",
+        KnownFolder::PublicUserTiles => "Public Account Pictures",
+        KnownFolder::PublicVideos => "Public Videos",
+        KnownFolder::QuickLaunch => "Quick Launch",
+        KnownFolder::Recent => "Recent Items",
+        KnownFolder::RecordedCalls => "Recorded Calls",
+        KnownFolder::RecordedTVLibrary => "Recorded TV",
+        KnownFolder::RecycleBinFolder => "Recycle Bin",
+        KnownFolder::ResourceDir => "Resources",
+        KnownFolder::RetailDemo => "RetailDemo",
+        KnownFolder::RoamedTileImages => "Roamed Tile Images",
+        KnownFolder::RoamingAppData => "Roaming",
+        KnownFolder::RoamingTiles => "Roaming Tiles",
+        KnownFolder::SEARCH_CSC => "Offline Files",
+        KnownFolder::SEARCH_MAPI => "Microsoft Office Outlook",
+        KnownFolder::SampleMusic => "Sample Music",
+        KnownFolder::SamplePictures => "Sample Pictures",
+        KnownFolder::SamplePlaylists => "Sample Playlists",
+        KnownFolder::SampleVideos => "Sample Videos",
+        KnownFolder::SavedGames => "Saved Games",
+        KnownFolder::SavedPictures | KnownFolder::SavedPicturesLibrary => "Saved Pictures",
+        KnownFolder::SavedSearches => "Searches",
+        KnownFolder::Screenshots => "Screenshots",
+        KnownFolder::SearchHome => "Search Results",
+        KnownFolder::SendTo => "SendTo",
+        KnownFolder::SidebarDefaultParts | KnownFolder::SidebarParts => "Gadgets",
+        KnownFolder::StartMenuAllPrograms => "All Programs",
+        KnownFolder::SyncManagerFolder => "Sync Center",
+        KnownFolder::SyncResultsFolder => "Sync Results",
+        KnownFolder::SyncSetupFolder => "Sync Setup",
+        KnownFolder::System | KnownFolder::SystemX86 => "System32",
+        KnownFolder::UserPinned => "User Pinned",
+        KnownFolder::UserProfiles => "Users",
+        KnownFolder::UserProgramFilesCommon => "Common",
+        KnownFolder::UsersFiles => "User Files",
+        KnownFolder::Videos | KnownFolder::VideosLibrary | KnownFolder::LocalVideos => "Videos",
+        KnownFolder::Windows => "Windows",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_matching() {
+        assert_eq!(KnownFolder::Pictures.display_name("de-DE"), "Bilder");
+        assert_eq!(KnownFolder::Pictures.display_name("PT_br"), "Imagens");
+        assert_eq!(KnownFolder::Downloads.display_name("zh-CN"), "下载");
+        assert_eq!(KnownFolder::Downloads.display_name("zh-Hant-TW"), "下載");
+        assert_eq!(KnownFolder::Downloads.display_name("zh"), "下载");
+        assert_eq!(KnownFolder::Downloads.display_name("tlh"), "Downloads");
+        assert_eq!(KnownFolder::Downloads.display_name(""), "Downloads");
+    }
+
+    #[test]
+    fn libraries_share_member_names() {
+        for locale in LANGUAGES {
+            assert_eq!(
+                KnownFolder::DocumentsLibrary.display_name(locale),
+                KnownFolder::Documents.display_name(locale)
+            );
+        }
+    }
+
+    #[test]
+    fn every_folder_has_an_english_name() {
+        for &folder in KnownFolder::ALL {
+            assert!(!folder.display_name("en").is_empty(), "{folder:?}");
+        }
+    }
+}
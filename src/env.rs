@@ -0,0 +1,447 @@
+// src/env.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::btree_map::{self, BTreeMap};
+use std::iter::FromIterator;
+
+use crate::{path, KnownFolder, Resolver};
+
+// Variables that `Environment::unexpand` substitutes. When several have the
+// same value, the first one listed wins. This keeps the relative order in
+// which `PathUnExpandEnvStrings` checks the variables it supports.
+pub(crate) const UNEXPAND_VARIABLES: &[&str] = &[
+    "ALLUSERSPROFILE",
+    "ProgramData",
+    "APPDATA",
+    "LOCALAPPDATA",
+    "ProgramFiles",
+    "ProgramFiles(x86)",
+    "CommonProgramFiles",
+    "CommonProgramFiles(x86)",
+    "SystemRoot",
+    "SystemDrive",
+    "PUBLIC",
+    "USERPROFILE",
+];
+
+// Environment variables that name known folders.
+const FOLDER_VARIABLES: &[(&str, KnownFolder)] = &[
+    ("ALLUSERSPROFILE", KnownFolder::ProgramData),
+    ("APPDATA", KnownFolder::RoamingAppData),
+    ("CommonProgramFiles", KnownFolder::ProgramFilesCommon),
+    (
+        "CommonProgramFiles(x86)",
+        KnownFolder::ProgramFilesCommonX86,
+    ),
+    ("LOCALAPPDATA", KnownFolder::LocalAppData),
+    ("ProgramData", KnownFolder::ProgramData),
+    ("ProgramFiles", KnownFolder::ProgramFiles),
+    ("ProgramFiles(x86)", KnownFolder::ProgramFilesX86),
+    ("PUBLIC", KnownFolder::Public),
+    ("SystemRoot", KnownFolder::Windows),
+    ("USERPROFILE", KnownFolder::Profile),
+    ("windir", KnownFolder::Windows),
+];
+
+/// A set of Windows environment variables.
+///
+/// Windows environment variable names are case-insensitive. Lookups in this
+/// map ignore case but the spelling of the name as it was first inserted is
+/// preserved.
+///
+/// Registry values of type `REG_EXPAND_SZ`, such as the values stored under
+/// the `User Shell Folders` key, reference environment variables like
+/// `%USERPROFILE%`. An `Environment` can expand these references without
+/// consulting the environment of the current process, which makes it possible
+/// to resolve known folders for other users or offline Windows installations.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::Environment;
+///
+/// let mut env = Environment::new();
+/// env.insert("USERPROFILE", r"C:\Users\jsmith");
+///
+/// assert_eq!(
+///     env.expand(r"%UserProfile%\Documents"),
+///     r"C:\Users\jsmith\Documents",
+/// );
+/// assert_eq!(env.expand(r"%UNDEFINED%\x"), r"%UNDEFINED%\x");
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Environment {
+    vars: BTreeMap<String, (String, String)>,
+}
+
+impl Environment {
+    /// Construct a new, empty environment.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the environment variable `name` to `value`.
+    ///
+    /// If the variable was previously set, the old value is returned. The
+    /// original spelling of the name is kept.
+    pub fn insert<N, V>(&mut self, name: N, value: V) -> Option<String>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let name = name.into();
+        let value = value.into();
+        match self.vars.entry(name.to_uppercase()) {
+            btree_map::Entry::Occupied(mut entry) => {
+                Some(core::mem::replace(&mut entry.get_mut().1, value))
+            }
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert((name, value));
+                None
+            }
+        }
+    }
+
+    /// Retrieve the value of the environment variable `name`, ignoring case.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.vars
+            .get(&name.to_uppercase())
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Remove the environment variable `name`, ignoring case.
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.vars
+            .remove(&name.to_uppercase())
+            .map(|(_, value)| value)
+    }
+
+    /// Return `true` if the environment variable `name` is set.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.vars.contains_key(&name.to_uppercase())
+    }
+
+    /// Return the number of variables in this environment.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.vars.len()
+    }
+
+    /// Return `true` if this environment has no variables.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vars.is_empty()
+    }
+
+    /// Iterate over the `(name, value)` pairs in this environment, ordered by
+    /// name ignoring case.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.vars
+            .values()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Construct the environment variables that name known folders, like
+    /// `%USERPROFILE%` and `%LOCALAPPDATA%`, from the folders located by
+    /// `resolver`.
+    ///
+    /// `%SystemDrive%` is set to the drive of the [`Windows`] folder.
+    /// Variables whose known folder cannot be resolved are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{Environment, KnownFolder, Snapshot};
+    ///
+    /// let mut snapshot = Snapshot::new();
+    /// snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+    /// snapshot.insert(KnownFolder::Windows, r"C:\Windows");
+    ///
+    /// let env = Environment::from_known_folders(&snapshot);
+    /// assert_eq!(env.get("USERPROFILE"), Some(r"C:\Users\jsmith"));
+    /// assert_eq!(env.get("SystemDrive"), Some("C:"));
+    /// assert_eq!(env.get("APPDATA"), None);
+    /// ```
+    ///
+    /// [`Windows`]: KnownFolder::Windows
+    #[must_use]
+    pub fn from_known_folders<R>(resolver: &R) -> Self
+    where
+        R: Resolver + ?Sized,
+    {
+        let mut env = Self::new();
+        for &(name, known_folder) in FOLDER_VARIABLES {
+            let folder = resolver.resolve(known_folder);
+            if let Some(folder) = folder.as_ref().and_then(|folder| folder.to_str()) {
+                env.insert(name, folder);
+            }
+        }
+        let drive = env
+            .get("SystemRoot")
+            .and_then(|windows| windows.get(..2))
+            .filter(|drive| drive.ends_with(':'))
+            .map(str::to_owned);
+        if let Some(drive) = drive {
+            env.insert("SystemDrive", drive);
+        }
+        env
+    }
+
+    /// Replace the leading part of `path` with a reference to the
+    /// environment variable it expands from, like `%LOCALAPPDATA%`.
+    ///
+    /// This is the inverse of [`Environment::expand`] and follows the rules
+    /// of the Win32 `PathUnExpandEnvStrings` function: the variable whose
+    /// value is the longest prefix of `path` is substituted, and prefixes
+    /// only match whole path components. Case is ignored and `\` and `/` are
+    /// interchangeable. The rest of `path` is kept as written.
+    ///
+    /// Only variables that name well-known locations are substituted:
+    /// `%ALLUSERSPROFILE%`, `%ProgramData%`, `%APPDATA%`, `%LOCALAPPDATA%`,
+    /// `%ProgramFiles%`, `%ProgramFiles(x86)%`, `%CommonProgramFiles%`,
+    /// `%CommonProgramFiles(x86)%`, `%SystemRoot%`, `%SystemDrive%`,
+    /// `%PUBLIC%`, and `%USERPROFILE%`. When several of them have the same
+    /// value, the first in this list wins, so `%ALLUSERSPROFILE%` is preferred
+    /// over `%ProgramData%` as it is by Windows.
+    ///
+    /// Returns [`None`] if no variable matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::Environment;
+    ///
+    /// let env: Environment = [
+    ///     ("USERPROFILE", r"C:\Users\jsmith"),
+    ///     ("LOCALAPPDATA", r"C:\Users\jsmith\AppData\Local"),
+    ///     ("SystemDrive", "C:"),
+    /// ]
+    /// .iter()
+    /// .copied()
+    /// .collect();
+    ///
+    /// assert_eq!(
+    ///     env.unexpand(r"c:\users\jsmith\AppData\Local\Temp\setup.log").as_deref(),
+    ///     Some(r"%LOCALAPPDATA%\Temp\setup.log"),
+    /// );
+    /// assert_eq!(
+    ///     env.unexpand(r"C:\Users\jsmith\.gitconfig").as_deref(),
+    ///     Some(r"%USERPROFILE%\.gitconfig"),
+    /// );
+    /// assert_eq!(env.unexpand(r"C:\Users\jsmith2").as_deref(), Some(r"%SystemDrive%\Users\jsmith2"));
+    /// assert_eq!(env.unexpand(r"D:\Games"), None);
+    /// ```
+    #[must_use]
+    pub fn unexpand(&self, path: &str) -> Option<String> {
+        let mut best: Option<(&str, &str, usize)> = None;
+        for &name in UNEXPAND_VARIABLES {
+            let value = match self.get(name) {
+                Some(value) => value.trim_end_matches(['\\', '/']),
+                None => continue,
+            };
+            if value.is_empty() || best.map_or(false, |(_, _, len)| len >= value.len()) {
+                continue;
+            }
+            if let Some(rest) = path::strip_prefix_ignore_case(path, value) {
+                best = Some((name, rest, value.len()));
+            }
+        }
+        best.map(|(name, rest, _)| format!("%{name}%{rest}"))
+    }
+
+    /// Expand `%NAME%` references in `input`.
+    ///
+    /// This function follows the rules of the Win32 `ExpandEnvironmentStrings`
+    /// function: when a reference names a variable that is not set in this
+    /// environment, the `%` and name are copied verbatim and scanning resumes
+    /// at the closing `%`, which may begin another reference.
+    #[must_use]
+    pub fn expand(&self, input: &str) -> String {
+        let mut out = String::with_capacity(input.len());
+        let mut rest = input;
+        while let Some(start) = rest.find('%') {
+            out.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let end = after.find('%');
+            let value = end
+                .map(|end| &after[..end])
+                .filter(|name| !name.is_empty())
+                .and_then(|name| self.get(name));
+            match (end, value) {
+                (Some(end), Some(value)) => {
+                    out.push_str(value);
+                    rest = &after[end + 1..];
+                }
+                (Some(end), None) => {
+                    out.push('%');
+                    out.push_str(&after[..end]);
+                    rest = &after[end..];
+                }
+                (None, _) => {
+                    out.push_str(&rest[start..]);
+                    return out;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+impl<N, V> FromIterator<(N, V)> for Environment
+where
+    N: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        let mut env = Self::new();
+        env.extend(iter);
+        env
+    }
+}
+
+impl<N, V> Extend<(N, V)> for Environment
+where
+    N: Into<String>,
+    V: Into<String>,
+{
+    fn extend<I: IntoIterator<Item = (N, V)>>(&mut self, iter: I) {
+        for (name, value) in iter {
+            self.insert(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env() -> Environment {
+        [
+            ("USERPROFILE", r"C:\Users\jsmith"),
+            ("SystemRoot", r"C:\Windows"),
+        ]
+        .iter()
+        .copied()
+        .collect()
+    }
+
+    #[test]
+    fn lookup_ignores_case() {
+        let env = env();
+        assert_eq!(env.get("userprofile"), Some(r"C:\Users\jsmith"));
+        assert_eq!(env.get("SYSTEMROOT"), Some(r"C:\Windows"));
+        assert_eq!(env.iter().next(), Some(("SystemRoot", r"C:\Windows")));
+    }
+
+    #[test]
+    fn insert_replaces_value_and_keeps_name() {
+        let mut env = env();
+        assert_eq!(
+            env.insert("SYSTEMROOT", r"D:\Windows"),
+            Some(r"C:\Windows".to_owned())
+        );
+        assert_eq!(env.iter().next(), Some(("SystemRoot", r"D:\Windows")));
+    }
+
+    #[test]
+    fn expand_edge_cases() {
+        let env = env();
+        assert_eq!(
+            env.expand("%SystemRoot%%USERPROFILE%"),
+            r"C:\WindowsC:\Users\jsmith"
+        );
+        assert_eq!(env.expand("100%"), "100%");
+        assert_eq!(env.expand("%%"), "%%");
+        assert_eq!(env.expand("%NOPE%%SystemRoot%"), r"%NOPE%C:\Windows");
+        assert_eq!(env.expand("%NOPE%SystemRoot%"), r"%NOPEC:\Windows");
+        assert_eq!(env.expand(r"a%SystemRoot"), r"a%SystemRoot");
+    }
+
+    #[test]
+    fn unexpand_overlapping_roots() {
+        let env: Environment = [
+            ("SystemDrive", "C:"),
+            ("SystemRoot", r"C:\Windows"),
+            ("USERPROFILE", r"C:\Users\jsmith"),
+            ("APPDATA", r"C:\Users\jsmith\AppData\Roaming"),
+            ("LOCALAPPDATA", r"C:\Users\jsmith\AppData\Local"),
+            ("PUBLIC", r"C:\Users\Public"),
+            ("ALLUSERSPROFILE", r"C:\ProgramData"),
+            ("ProgramData", r"C:\ProgramData"),
+            ("ProgramFiles", r"C:\Program Files"),
+            ("ProgramFiles(x86)", r"C:\Program Files (x86)"),
+            ("CommonProgramFiles", r"C:\Program Files\Common Files"),
+            ("TEMP", r"C:\Users\jsmith\AppData\Local\Temp"),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let cases = [
+            (
+                r"C:\Users\jsmith\AppData\Local\Temp\x.log",
+                r"%LOCALAPPDATA%\Temp\x.log",
+            ),
+            (r"C:\Users\jsmith\AppData\Roaming", "%APPDATA%"),
+            (
+                r"C:\Users\jsmith\AppData\LocalLow",
+                r"%USERPROFILE%\AppData\LocalLow",
+            ),
+            (r"C:/Users/jsmith/Desktop", "%USERPROFILE%/Desktop"),
+            (r"C:\Users\Public\Music", r"%PUBLIC%\Music"),
+            (r"C:\ProgramData\Acme", r"%ALLUSERSPROFILE%\Acme"),
+            (
+                r"C:\Program Files\Common Files\x",
+                r"%CommonProgramFiles%\x",
+            ),
+            (r"C:\Program Files (x86)\Acme", r"%ProgramFiles(x86)%\Acme"),
+            (r"C:\Program Files\Acme", r"%ProgramFiles%\Acme"),
+            (r"c:\windows\System32", r"%SystemRoot%\System32"),
+            (r"C:\Windows.old", r"%SystemDrive%\Windows.old"),
+        ];
+        for &(path, expected) in &cases {
+            assert_eq!(env.unexpand(path).as_deref(), Some(expected), "{path}");
+            assert!(path::eq_ignore_case(
+                env.expand(expected).as_ref(),
+                path.as_ref()
+            ));
+        }
+        assert_eq!(env.unexpand(r"D:\Users\jsmith"), None);
+        assert_eq!(env.unexpand("Windows"), None);
+
+        let env: Environment = [("USERPROFILE", r"C:\Users\jsmith\")]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(
+            env.unexpand(r"C:\Users\jsmith\Desktop").as_deref(),
+            Some(r"%USERPROFILE%\Desktop")
+        );
+    }
+
+    #[test]
+    fn environment_from_known_folders() {
+        let mut snapshot = crate::Snapshot::new();
+        snapshot.insert(KnownFolder::ProgramData, r"D:\ProgramData");
+        snapshot.insert(KnownFolder::Windows, r"D:\Windows");
+        let env = Environment::from_known_folders(&snapshot);
+        assert_eq!(env.get("ALLUSERSPROFILE"), Some(r"D:\ProgramData"));
+        assert_eq!(env.get("windir"), Some(r"D:\Windows"));
+        assert_eq!(env.get("SystemDrive"), Some("D:"));
+        assert_eq!(env.len(), 5);
+        assert_eq!(
+            env.unexpand(r"D:\ProgramData\Acme").as_deref(),
+            Some(r"%ALLUSERSPROFILE%\Acme")
+        );
+    }
+}
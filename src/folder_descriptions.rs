@@ -0,0 +1,511 @@
+// src/folder_descriptions.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Known folder definitions registered under the `FolderDescriptions`
+//! registry key.
+//!
+//! Every known folder, whether it ships with Windows or is registered by an
+//! application with `IKnownFolderManager::RegisterFolder`, is described by a
+//! subkey of [`FOLDER_DESCRIPTIONS`] named by the folder's **KNOWNFOLDERID**.
+//! The values of that subkey mirror the fields of the Win32
+//! `KNOWNFOLDER_DEFINITION` struct.
+//!
+//! Descriptions can be read from a `.reg` export or from an offline
+//! `SOFTWARE` hive with [`FolderDescriptions`], and used to resolve custom
+//! known folders relative to their parent folders.
+
+use std::path::PathBuf;
+
+use crate::definition;
+use crate::registry::export::RegFile;
+use crate::registry::hive::{self, Hive};
+use crate::registry::{self, Value};
+use crate::{FolderId, Guid, Resolver};
+
+/// The registry key path, relative to the root of the `SOFTWARE` hive
+/// (`HKEY_LOCAL_MACHINE\SOFTWARE`), of the known folder descriptions.
+pub const FOLDER_DESCRIPTIONS: &str =
+    r"Microsoft\Windows\CurrentVersion\Explorer\FolderDescriptions";
+
+/// The category of a known folder, which determines how its location is
+/// computed and whether it can be redirected.
+///
+/// This is the `KF_CATEGORY` enumeration.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    /// A virtual folder with no file system location, such as Control
+    /// Panel.
+    Virtual,
+    /// A fixed file system folder that cannot be redirected, such as the
+    /// Windows directory.
+    Fixed,
+    /// A file system folder shared by all users, such as Public Documents.
+    Common,
+    /// A file system folder specific to each user, such as Documents.
+    PerUser,
+}
+
+impl Category {
+    /// Return the category with the given `KF_CATEGORY` value.
+    #[must_use]
+    pub fn from_u32(value: u32) -> Option<Self> {
+        match value {
+            1 => Some(Self::Virtual),
+            2 => Some(Self::Fixed),
+            3 => Some(Self::Common),
+            4 => Some(Self::PerUser),
+            _ => None,
+        }
+    }
+
+    /// Return the `KF_CATEGORY` value of this category.
+    #[must_use]
+    pub fn to_u32(self) -> u32 {
+        match self {
+            Self::Virtual => 1,
+            Self::Fixed => 2,
+            Self::Common => 3,
+            Self::PerUser => 4,
+        }
+    }
+}
+
+/// The definition of one known folder read from its `FolderDescriptions`
+/// subkey.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderDescription {
+    id: FolderId,
+    name: String,
+    category: Option<Category>,
+    description: Option<String>,
+    parent: Option<FolderId>,
+    relative_path: Option<String>,
+    parsing_name: Option<String>,
+    localized_name: Option<String>,
+    icon: Option<String>,
+    folder_type: Option<Guid>,
+    attributes: Option<u32>,
+    pre_create: bool,
+    roamable: bool,
+    local_redirect_only: bool,
+}
+
+impl FolderDescription {
+    /// Build from the `(name, data)` pairs of the subkey that describes the
+    /// known folder with ID `id`.
+    ///
+    /// Returns [`None`] if the required `Name` value is missing.
+    #[must_use]
+    pub fn from_values<'a, I>(id: FolderId, values: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a Value)>,
+    {
+        let mut name = None;
+        let mut description = Self {
+            id,
+            name: String::new(),
+            category: None,
+            description: None,
+            parent: None,
+            relative_path: None,
+            parsing_name: None,
+            localized_name: None,
+            icon: None,
+            folder_type: None,
+            attributes: None,
+            pre_create: false,
+            roamable: false,
+            local_redirect_only: false,
+        };
+        for (value_name, data) in values {
+            let string = data.as_str().filter(|s| !s.is_empty()).map(String::from);
+            let flag = data.as_u32().map_or(false, |n| n != 0);
+            match value_name.to_ascii_lowercase().as_str() {
+                "name" => name = string,
+                "category" => description.category = data.as_u32().and_then(Category::from_u32),
+                "description" => description.description = string,
+                "parentfolder" => {
+                    description.parent = data
+                        .as_str()
+                        .and_then(|s| s.parse::<Guid>().ok())
+                        .map(FolderId::from_guid);
+                }
+                "relativepath" => description.relative_path = string,
+                "parsingname" => description.parsing_name = string,
+                "localizedname" => description.localized_name = string,
+                "icon" => description.icon = string,
+                "foldertypeid" => {
+                    description.folder_type = data.as_str().and_then(|s| s.parse::<Guid>().ok());
+                }
+                "attributes" => description.attributes = data.as_u32(),
+                "precreate" => description.pre_create = flag,
+                "roamable" => description.roamable = flag,
+                "localredirectonly" => description.local_redirect_only = flag,
+                _ => {}
+            }
+        }
+        description.name = name?;
+        Some(description)
+    }
+
+    /// The ID of the described known folder.
+    #[must_use]
+    pub fn id(&self) -> FolderId {
+        self.id
+    }
+
+    /// The non-localized canonical name of the folder, e.g. `Personal`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The category of the folder, or [`None`] if the `Category` value is
+    /// missing or not recognized.
+    #[must_use]
+    pub fn category(&self) -> Option<Category> {
+        self.category
+    }
+
+    /// A short description of the folder.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// The ID of the parent folder that [`relative_path`] is relative to.
+    ///
+    /// [`relative_path`]: Self::relative_path
+    #[must_use]
+    pub fn parent(&self) -> Option<FolderId> {
+        self.parent
+    }
+
+    /// The path of the folder relative to its parent folder.
+    #[must_use]
+    pub fn relative_path(&self) -> Option<&str> {
+        self.relative_path.as_deref()
+    }
+
+    /// The shell parsing name of the folder, used for virtual folders.
+    #[must_use]
+    pub fn parsing_name(&self) -> Option<&str> {
+        self.parsing_name.as_deref()
+    }
+
+    /// The indirect string resource of the folder's display name, e.g.
+    /// `@%SystemRoot%\system32\shell32.dll,-21770`.
+    #[must_use]
+    pub fn localized_name(&self) -> Option<&str> {
+        self.localized_name.as_deref()
+    }
+
+    /// The icon resource of the folder.
+    #[must_use]
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// The folder type GUID, which selects the template used by Explorer to
+    /// display the folder.
+    #[must_use]
+    pub fn folder_type(&self) -> Option<Guid> {
+        self.folder_type
+    }
+
+    /// The file attributes applied to the folder when it is created.
+    #[must_use]
+    pub fn attributes(&self) -> Option<u32> {
+        self.attributes
+    }
+
+    /// Return `true` if the folder is created when a user first logs on.
+    #[must_use]
+    pub fn is_pre_create(&self) -> bool {
+        self.pre_create
+    }
+
+    /// Return `true` if the folder roams with the user's profile.
+    #[must_use]
+    pub fn is_roamable(&self) -> bool {
+        self.roamable
+    }
+
+    /// Return `true` if the folder can only be redirected to a local path.
+    #[must_use]
+    pub fn is_local_redirect_only(&self) -> bool {
+        self.local_redirect_only
+    }
+}
+
+/// The known folder definitions registered under the [`FOLDER_DESCRIPTIONS`]
+/// key.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use known_folders::folder_descriptions::FolderDescriptions;
+/// use known_folders::registry::export::RegFile;
+/// use known_folders::{FolderId, KnownFolder, Snapshot};
+///
+/// let reg = br#"Windows Registry Editor Version 5.00
+///
+/// [HKEY_LOCAL_MACHINE\SOFTWARE\Microsoft\Windows\CurrentVersion\Explorer\FolderDescriptions\{B5A0C4E1-0F3C-4C9B-9E6A-2B1C7D8E9F00}]
+/// "Name"="Acme Projects"
+/// "Category"=dword:00000004
+/// "ParentFolder"="{FDD39AD0-238F-46AF-ADB4-6C85480369C7}"
+/// "RelativePath"="Acme\\Projects"
+/// "PreCreate"=dword:00000001
+/// "#;
+///
+/// let descriptions = FolderDescriptions::from_reg_file(&RegFile::parse(reg)?);
+/// let acme = descriptions.by_name("Acme Projects").unwrap();
+/// assert_eq!(acme.parent(), Some(FolderId::Known(KnownFolder::Documents)));
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+/// assert_eq!(
+///     descriptions.resolve(acme.id(), &snapshot),
+///     Some(r"C:\Users\jsmith\Documents\Acme\Projects".into()),
+/// );
+/// # Ok::<(), known_folders::registry::export::ParseError>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct FolderDescriptions {
+    descriptions: Vec<FolderDescription>,
+}
+
+impl FolderDescriptions {
+    /// Read every folder description in a `.reg` file.
+    ///
+    /// Descriptions are found by their key path, so exports of
+    /// `HKEY_LOCAL_MACHINE` or of the `FolderDescriptions` key itself are
+    /// supported. Subkeys whose names are not GUIDs are ignored.
+    #[must_use]
+    pub fn from_reg_file(file: &RegFile) -> Self {
+        let descriptions = file
+            .keys()
+            .filter(|key| !key.is_deleted())
+            .filter_map(|key| {
+                let (parent, name) = key.path().rsplit_once('\\')?;
+                if !registry::key_path_ends_with(parent, "FolderDescriptions") {
+                    return None;
+                }
+                let id = FolderId::from_guid(name.parse().ok()?);
+                FolderDescription::from_values(id, key.values())
+            })
+            .collect();
+        Self { descriptions }
+    }
+
+    /// Read every folder description in a `SOFTWARE` hive.
+    ///
+    /// Returns an empty set if the hive has no [`FOLDER_DESCRIPTIONS`] key.
+    ///
+    /// # Errors
+    ///
+    /// If the hive is malformed, an error is returned.
+    pub fn from_hive(hive: &Hive) -> Result<Self, hive::ParseError> {
+        match hive.open_key(FOLDER_DESCRIPTIONS)? {
+            Some(key) => Self::from_hive_key(&key),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Read every folder description under a `FolderDescriptions` key in a
+    /// registry hive.
+    ///
+    /// # Errors
+    ///
+    /// If the key is malformed, an error is returned.
+    pub fn from_hive_key(key: &hive::Key<'_>) -> Result<Self, hive::ParseError> {
+        let mut descriptions = Vec::new();
+        for subkey in key.subkeys()? {
+            let id = match subkey.name().parse::<Guid>() {
+                Ok(guid) => FolderId::from_guid(guid),
+                Err(_) => continue,
+            };
+            let values = subkey.values()?;
+            let values = values.iter().map(|entry| (entry.name(), entry.data()));
+            if let Some(description) = FolderDescription::from_values(id, values) {
+                descriptions.push(description);
+            }
+        }
+        Ok(Self { descriptions })
+    }
+
+    /// Iterate over all folder descriptions.
+    pub fn iter(&self) -> impl Iterator<Item = &FolderDescription> {
+        self.descriptions.iter()
+    }
+
+    /// Return the number of folder descriptions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.descriptions.len()
+    }
+
+    /// Return `true` if there are no folder descriptions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.descriptions.is_empty()
+    }
+
+    /// Retrieve the description of the known folder with ID `id`.
+    #[must_use]
+    pub fn get(&self, id: FolderId) -> Option<&FolderDescription> {
+        self.descriptions
+            .iter()
+            .find(|description| description.id == id)
+    }
+
+    /// Retrieve the description of the known folder with the canonical name
+    /// `name`, ignoring ASCII case.
+    #[must_use]
+    pub fn by_name(&self, name: &str) -> Option<&FolderDescription> {
+        self.descriptions
+            .iter()
+            .find(|description| description.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Resolve the known folder with ID `id`.
+    ///
+    /// A folder that `resolver` can locate, e.g. one that has been
+    /// redirected, resolves to that location. Otherwise the folder's
+    /// [`parent`] is resolved recursively and the folder's [`relative_path`]
+    /// is appended to it.
+    ///
+    /// Returns [`None`] if neither the resolver nor the descriptions locate
+    /// the folder, or if the parent chain contains a cycle.
+    ///
+    /// [`parent`]: FolderDescription::parent
+    /// [`relative_path`]: FolderDescription::relative_path
+    #[must_use]
+    pub fn resolve<R>(&self, id: FolderId, resolver: &R) -> Option<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        definition::resolve_chain(id, resolver, |id| {
+            let description = self.get(id)?;
+            Some((description.parent()?, description.relative_path()?))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::registry::test_hive::{HiveBuilder, TestKey};
+    use crate::shell_folders::{HiveResolver, USER_SHELL_FOLDERS};
+    use crate::{Environment, KnownFolder, Snapshot};
+
+    const ACME: &str = "{B5A0C4E1-0F3C-4C9B-9E6A-2B1C7D8E9F00}";
+    const ACME_DATA: &str = "{B5A0C4E1-0F3C-4C9B-9E6A-2B1C7D8E9F01}";
+
+    fn descriptions() -> FolderDescriptions {
+        let mut root = TestKey::new("ROOT");
+        root.key(&format!(r"{FOLDER_DESCRIPTIONS}\{ACME}"))
+            .sz("Name", "Acme")
+            .dword("Category", 4)
+            .sz("ParentFolder", "{5E6C858F-0E22-4760-9AFE-EA3317B67173}")
+            .sz("RelativePath", "Acme")
+            .dword("PreCreate", 1)
+            .dword("Roamable", 0);
+        root.key(&format!(r"{FOLDER_DESCRIPTIONS}\{ACME_DATA}"))
+            .sz("Name", "Acme Data")
+            .dword("Category", 4)
+            .sz("ParentFolder", ACME)
+            .sz("RelativePath", r"Data\Current")
+            .dword("Roamable", 1);
+        root.key(&format!(r"{FOLDER_DESCRIPTIONS}\NotAGuid"))
+            .sz("Name", "Ignored");
+        root.key(&format!(
+            r"{FOLDER_DESCRIPTIONS}\{{00000000-0000-0000-0000-000000000001}}"
+        ))
+        .sz("RelativePath", "NoName");
+        let hive = Hive::parse(HiveBuilder::new().build(&root)).unwrap();
+        FolderDescriptions::from_hive(&hive).unwrap()
+    }
+
+    #[test]
+    fn parse_hive() {
+        let descriptions = descriptions();
+        assert_eq!(descriptions.len(), 2);
+        let acme = descriptions.by_name("acme").unwrap();
+        assert_eq!(acme.id(), FolderId::Custom(ACME.parse().unwrap()));
+        assert_eq!(acme.category(), Some(Category::PerUser));
+        assert_eq!(acme.parent(), Some(FolderId::Known(KnownFolder::Profile)));
+        assert!(acme.is_pre_create());
+        assert!(!acme.is_roamable());
+        let data = descriptions.get(ACME_DATA.parse().unwrap()).unwrap();
+        assert_eq!(data.relative_path(), Some(r"Data\Current"));
+        assert!(data.is_roamable());
+    }
+
+    #[test]
+    fn resolve_parent_chain() {
+        let descriptions = descriptions();
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+        assert_eq!(
+            descriptions.resolve(ACME_DATA.parse().unwrap(), &snapshot),
+            Some(PathBuf::from(r"C:\Users\jsmith\Acme\Data\Current"))
+        );
+        assert_eq!(
+            descriptions.resolve(ACME_DATA.parse().unwrap(), &Snapshot::new()),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_redirected_custom_folder() {
+        let descriptions = descriptions();
+        let mut root = TestKey::new("ROOT");
+        root.key(USER_SHELL_FOLDERS).expand_sz(ACME, r"D:\Acme");
+        let hive = Hive::parse(HiveBuilder::new().build(&root)).unwrap();
+        let resolver = HiveResolver::from_hive(&hive, &Environment::new()).unwrap();
+        assert_eq!(
+            descriptions.resolve(ACME_DATA.parse().unwrap(), &resolver),
+            Some(PathBuf::from(r"D:\Acme\Data\Current"))
+        );
+    }
+
+    #[test]
+    fn resolve_detects_cycles() {
+        let reg = format!(
+            r#"Windows Registry Editor Version 5.00
+
+[HKEY_LOCAL_MACHINE\SOFTWARE\{FOLDER_DESCRIPTIONS}\{ACME}]
+"Name"="Acme"
+"ParentFolder"="{ACME_DATA}"
+"RelativePath"="Acme"
+
+[HKEY_LOCAL_MACHINE\SOFTWARE\{FOLDER_DESCRIPTIONS}\{ACME_DATA}]
+"Name"="Acme Data"
+"ParentFolder"="{ACME}"
+"RelativePath"="Data"
+"#
+        );
+        let file = RegFile::parse_str(&reg).unwrap();
+        let descriptions = FolderDescriptions::from_reg_file(&file);
+        assert_eq!(descriptions.len(), 2);
+        assert_eq!(
+            descriptions.resolve(ACME.parse().unwrap(), &Snapshot::new()),
+            None
+        );
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Profile, Path::new(r"C:\Users\jsmith"));
+        assert_eq!(descriptions.resolve(ACME.parse().unwrap(), &snapshot), None);
+    }
+}
@@ -0,0 +1,127 @@
+// src/folder_id.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Guid, KnownFolder, ParseGuidError};
+
+/// The **KNOWNFOLDERID** of any known folder, including folders registered
+/// by ISVs.
+///
+/// Windows ships a fixed set of known folders, represented by
+/// [`KnownFolder`]. Applications can register additional known folders with
+/// their own GUIDs; those are represented as [`FolderId::Custom`].
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{FolderId, Guid, KnownFolder};
+///
+/// let downloads = "{374DE290-123F-4565-9164-39C4925E467B}".parse::<FolderId>()?;
+/// assert_eq!(downloads, FolderId::Known(KnownFolder::Downloads));
+///
+/// let custom = "{B5A0C4E1-0F3C-4C9B-9E6A-2B1C7D8E9F00}".parse::<FolderId>()?;
+/// assert!(matches!(custom, FolderId::Custom(_)));
+/// assert_eq!(custom.known_folder(), None);
+/// # Ok::<(), known_folders::ParseGuidError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+// A `Guid` is 16 bytes, which is small enough to store inline.
+#[allow(variant_size_differences)]
+pub enum FolderId {
+    /// A known folder that ships with Windows.
+    Known(KnownFolder),
+    /// A known folder that is not one of the [`KnownFolder`] variants, such
+    /// as one registered by an application.
+    Custom(Guid),
+}
+
+impl FolderId {
+    /// Return the ID for the known folder identified by `guid`.
+    ///
+    /// GUIDs of built-in known folders map to [`FolderId::Known`]; all other
+    /// GUIDs map to [`FolderId::Custom`].
+    #[must_use]
+    pub fn from_guid(guid: Guid) -> Self {
+        match KnownFolder::from_guid(guid) {
+            Some(known_folder) => Self::Known(known_folder),
+            None => Self::Custom(guid),
+        }
+    }
+
+    /// Return the **KNOWNFOLDERID** GUID of this folder.
+    #[must_use]
+    pub const fn guid(self) -> Guid {
+        match self {
+            Self::Known(known_folder) => known_folder.guid(),
+            Self::Custom(guid) => guid,
+        }
+    }
+
+    /// Return the built-in known folder this ID refers to, if any.
+    #[must_use]
+    pub const fn known_folder(self) -> Option<KnownFolder> {
+        match self {
+            Self::Known(known_folder) => Some(known_folder),
+            Self::Custom(_) => None,
+        }
+    }
+}
+
+impl From<KnownFolder> for FolderId {
+    fn from(known_folder: KnownFolder) -> Self {
+        Self::Known(known_folder)
+    }
+}
+
+impl From<Guid> for FolderId {
+    fn from(guid: Guid) -> Self {
+        Self::from_guid(guid)
+    }
+}
+
+impl From<FolderId> for Guid {
+    fn from(id: FolderId) -> Self {
+        id.guid()
+    }
+}
+
+impl fmt::Display for FolderId {
+    /// Format the folder's GUID in braced registry form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.guid(), f)
+    }
+}
+
+impl FromStr for FolderId {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Guid>().map(Self::from_guid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_guids_are_not_custom() {
+        for &known_folder in KnownFolder::ALL {
+            let id = FolderId::from_guid(known_folder.guid());
+            assert!(matches!(id, FolderId::Known(_)), "{known_folder:?}");
+            assert_eq!(id.guid(), known_folder.guid());
+        }
+        let guid = Guid::from_u128(0xb5a0_c4e1_0f3c_4c9b_9e6a_2b1c_7d8e_9f00);
+        assert_eq!(FolderId::from(guid), FolderId::Custom(guid));
+        assert_eq!(Guid::from(FolderId::Custom(guid)), guid);
+    }
+}
@@ -0,0 +1,256 @@
+// src/guid.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use core::fmt;
+use core::str::FromStr;
+use std::error::Error;
+
+/// A platform-independent globally unique identifier.
+///
+/// Known Folder IDs, shell item CLSIDs, and many other identifiers found in
+/// the registry and in shell binary formats are GUIDs. This type represents
+/// them without depending on the Win32 `GUID` struct so they can be parsed
+/// and compared on any platform.
+///
+/// The [`Display`] implementation formats the GUID in the braced, upper case
+/// registry form, e.g. `{374DE290-123F-4565-9164-39C4925E467B}`.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::Guid;
+///
+/// let guid = "{374DE290-123F-4565-9164-39C4925E467B}".parse::<Guid>()?;
+/// assert_eq!(guid, Guid::from_u128(0x374de290_123f_4565_9164_39c4925e467b));
+/// assert_eq!(guid.to_string(), "{374DE290-123F-4565-9164-39C4925E467B}");
+/// # Ok::<(), known_folders::ParseGuidError>(())
+/// ```
+///
+/// [`Display`]: fmt::Display
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guid(u128);
+
+impl Guid {
+    /// The all-zero GUID, `GUID_NULL`.
+    pub const NIL: Self = Self(0);
+
+    /// Construct a GUID from its 128-bit integer representation.
+    ///
+    /// The most significant bits hold `Data1`, matching the layout used by
+    /// `windows_sys::core::GUID::from_u128`.
+    #[must_use]
+    pub const fn from_u128(value: u128) -> Self {
+        Self(value)
+    }
+
+    /// Return the 128-bit integer representation of this GUID.
+    #[must_use]
+    pub const fn to_u128(self) -> u128 {
+        self.0
+    }
+
+    /// Construct a GUID from its `Data1`, `Data2`, `Data3`, and `Data4`
+    /// fields.
+    #[must_use]
+    pub const fn from_fields(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        Self(
+            (data1 as u128) << 96
+                | (data2 as u128) << 80
+                | (data3 as u128) << 64
+                | (u64::from_be_bytes(data4) as u128),
+        )
+    }
+
+    /// Return the `Data1`, `Data2`, `Data3`, and `Data4` fields of this GUID.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_fields(self) -> (u32, u16, u16, [u8; 8]) {
+        (
+            (self.0 >> 96) as u32,
+            (self.0 >> 80) as u16,
+            (self.0 >> 64) as u16,
+            (self.0 as u64).to_be_bytes(),
+        )
+    }
+
+    /// Construct a GUID from its 16-byte in-memory representation.
+    ///
+    /// This is the mixed-endian layout Windows uses when GUIDs are serialized
+    /// in binary formats such as registry values, shell item ID lists, and
+    /// shell links: `Data1`, `Data2`, and `Data3` are little endian and
+    /// `Data4` is a plain byte array.
+    #[must_use]
+    pub const fn from_bytes_le(bytes: [u8; 16]) -> Self {
+        let data1 = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let data2 = u16::from_le_bytes([bytes[4], bytes[5]]);
+        let data3 = u16::from_le_bytes([bytes[6], bytes[7]]);
+        let data4 = [
+            bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14], bytes[15],
+        ];
+        Self::from_fields(data1, data2, data3, data4)
+    }
+
+    /// Construct a GUID from the first 16 bytes of `bytes` in the Windows
+    /// in-memory layout.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than 16 bytes.
+    ///
+    /// See [`Guid::from_bytes_le`] for a description of the layout.
+    #[must_use]
+    pub fn from_slice_le(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.get(..16)?;
+        let mut buf = [0; 16];
+        buf.copy_from_slice(bytes);
+        Some(Self::from_bytes_le(buf))
+    }
+
+    /// Return the 16-byte in-memory representation of this GUID.
+    ///
+    /// See [`Guid::from_bytes_le`] for a description of the layout.
+    #[must_use]
+    pub const fn to_bytes_le(self) -> [u8; 16] {
+        let (data1, data2, data3, data4) = self.to_fields();
+        let data1 = data1.to_le_bytes();
+        let data2 = data2.to_le_bytes();
+        let data3 = data3.to_le_bytes();
+        [
+            data1[0], data1[1], data1[2], data1[3], data2[0], data2[1], data3[0], data3[1],
+            data4[0], data4[1], data4[2], data4[3], data4[4], data4[5], data4[6], data4[7],
+        ]
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (data1, data2, data3, data4) = self.to_fields();
+        write!(
+            f,
+            "{{{:08X}-{:04X}-{:04X}-{:02X}{:02X}-{:02X}{:02X}{:02X}{:02X}{:02X}{:02X}}}",
+            data1,
+            data2,
+            data3,
+            data4[0],
+            data4[1],
+            data4[2],
+            data4[3],
+            data4[4],
+            data4[5],
+            data4[6],
+            data4[7],
+        )
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    /// Parse a GUID in registry form.
+    ///
+    /// Both the braced form `{374DE290-123F-4565-9164-39C4925E467B}` and the
+    /// bare hyphenated form are accepted. Hex digits are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = match s.strip_prefix('{') {
+            Some(rest) => rest
+                .strip_suffix('}')
+                .ok_or(ParseGuidError { _private: () })?,
+            None => s,
+        };
+        let bytes = inner.as_bytes();
+        if bytes.len() != 36 {
+            return Err(ParseGuidError { _private: () });
+        }
+        let mut value = 0_u128;
+        for (idx, &byte) in bytes.iter().enumerate() {
+            if matches!(idx, 8 | 13 | 18 | 23) {
+                if byte != b'-' {
+                    return Err(ParseGuidError { _private: () });
+                }
+                continue;
+            }
+            let digit = char::from(byte)
+                .to_digit(16)
+                .ok_or(ParseGuidError { _private: () })?;
+            value = value << 4 | u128::from(digit);
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Error returned when parsing a [`Guid`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseGuidError {
+    _private: (),
+}
+
+impl fmt::Display for ParseGuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid GUID syntax")
+    }
+}
+
+impl Error for ParseGuidError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOWNLOADS: Guid = Guid::from_u128(0x374de290_123f_4565_9164_39c4925e467b);
+
+    #[test]
+    fn parse_braced_and_bare() {
+        assert_eq!(
+            "{374DE290-123F-4565-9164-39C4925E467B}".parse::<Guid>(),
+            Ok(DOWNLOADS)
+        );
+        assert_eq!(
+            "374de290-123f-4565-9164-39c4925e467b".parse::<Guid>(),
+            Ok(DOWNLOADS)
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for input in [
+            "",
+            "{}",
+            "{374DE290-123F-4565-9164-39C4925E467B",
+            "374DE290123F456591643-9C4925E467B",
+            "{374DE290-123F-4565-9164-39C4925E467G}",
+            "{374DE290-123F-4565-9164-39C4925E467BB}",
+        ] {
+            assert!(input.parse::<Guid>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let s = DOWNLOADS.to_string();
+        assert_eq!(s, "{374DE290-123F-4565-9164-39C4925E467B}");
+        assert_eq!(s.parse::<Guid>(), Ok(DOWNLOADS));
+    }
+
+    #[test]
+    fn bytes_le_layout() {
+        let bytes = [
+            0x90, 0xe2, 0x4d, 0x37, 0x3f, 0x12, 0x65, 0x45, 0x91, 0x64, 0x39, 0xc4, 0x92, 0x5e,
+            0x46, 0x7b,
+        ];
+        assert_eq!(Guid::from_bytes_le(bytes), DOWNLOADS);
+        assert_eq!(DOWNLOADS.to_bytes_le(), bytes);
+        assert_eq!(Guid::from_slice_le(&bytes), Some(DOWNLOADS));
+        assert_eq!(Guid::from_slice_le(&bytes[..15]), None);
+    }
+}
@@ -0,0 +1,447 @@
+// src/internet_shortcut.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parser for internet shortcut (`.url`) files and the bookmarks of the
+//! Favorites folders.
+//!
+//! The [`Favorites`], [`AppDataFavorites`], and [`Links`] folders hold the
+//! bookmarks of Internet Explorer and Edge as `.url` files, which are INI
+//! files with an `[InternetShortcut]` section. [`InternetShortcut`] parses
+//! one file and [`favorites`] reads the folder tree of all bookmarks.
+//!
+//! [`Favorites`]: KnownFolder::Favorites
+//! [`AppDataFavorites`]: KnownFolder::AppDataFavorites
+//! [`Links`]: KnownFolder::Links
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::desktop_ini::{DesktopIni, IconLocation};
+use crate::{path, KnownFolder, Resolver};
+
+/// The name of the section that describes an internet shortcut.
+pub const INTERNET_SHORTCUT: &str = "InternetShortcut";
+
+/// A parsed internet shortcut (`.url`) file.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::internet_shortcut::InternetShortcut;
+///
+/// let shortcut = InternetShortcut::parse_str(
+///     "[InternetShortcut]\r\nURL=https://example.com/\r\nIconFile=https://example.com/favicon.ico\r\nIconIndex=1\r\n",
+/// )
+/// .unwrap();
+/// assert_eq!(shortcut.url(), "https://example.com/");
+/// assert_eq!(shortcut.icon().unwrap().path(), "https://example.com/favicon.ico");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InternetShortcut {
+    url: String,
+    icon: Option<IconLocation>,
+    working_dir: Option<String>,
+    modified: Option<u64>,
+    ini: DesktopIni,
+}
+
+impl InternetShortcut {
+    /// Parse the contents of an internet shortcut file.
+    ///
+    /// Returns [`None`] if the file has no `URL` entry in its
+    /// `[InternetShortcut]` section.
+    #[must_use]
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        Self::from_ini(DesktopIni::parse(bytes))
+    }
+
+    /// Parse the text of an internet shortcut file.
+    ///
+    /// Returns [`None`] if the file has no `URL` entry in its
+    /// `[InternetShortcut]` section.
+    #[must_use]
+    pub fn parse_str(contents: &str) -> Option<Self> {
+        Self::from_ini(DesktopIni::parse_str(contents))
+    }
+
+    /// Read and parse the internet shortcut file at `path`.
+    ///
+    /// Returns `Ok(None)` if the file has no `URL` entry in its
+    /// `[InternetShortcut]` section.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, an error is returned.
+    pub fn open<P>(path: P) -> io::Result<Option<Self>>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read(path).map(|bytes| Self::parse(&bytes))
+    }
+
+    fn from_ini(ini: DesktopIni) -> Option<Self> {
+        let url = ini.get(INTERNET_SHORTCUT, "URL")?.to_owned();
+        let icon = ini.get(INTERNET_SHORTCUT, "IconFile").map(|file| {
+            let index = ini
+                .get(INTERNET_SHORTCUT, "IconIndex")
+                .and_then(|index| index.trim().parse().ok())
+                .unwrap_or_default();
+            IconLocation::new(file.to_owned(), index)
+        });
+        let working_dir = ini
+            .get(INTERNET_SHORTCUT, "WorkingDirectory")
+            .map(str::to_owned);
+        let modified = ini
+            .get(INTERNET_SHORTCUT, "Modified")
+            .and_then(parse_filetime);
+        Some(Self {
+            url,
+            icon,
+            working_dir,
+            modified,
+            ini,
+        })
+    }
+
+    /// The URL the shortcut opens.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The icon of the shortcut, from the `IconFile` and `IconIndex`
+    /// entries.
+    #[must_use]
+    pub fn icon(&self) -> Option<&IconLocation> {
+        self.icon.as_ref()
+    }
+
+    /// The working directory of shortcuts to `file:` URLs.
+    #[must_use]
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref()
+    }
+
+    /// The time the shortcut was last modified as a `FILETIME`, decoded
+    /// from the hex blob of the `Modified` entry.
+    #[must_use]
+    pub fn modified(&self) -> Option<u64> {
+        self.modified
+    }
+
+    /// The underlying INI file, for entries this parser does not decode,
+    /// like the property sets written by Internet Explorer.
+    #[must_use]
+    pub fn ini(&self) -> &DesktopIni {
+        &self.ini
+    }
+}
+
+/// Decode the little endian `FILETIME` in the leading 16 hex digits of a
+/// `Modified` blob. Trailing digits hold a checksum and are ignored.
+fn parse_filetime(value: &str) -> Option<u64> {
+    let digits = value.trim().get(..16)?;
+    let mut bytes = [0; 8];
+    for (byte, pair) in bytes.iter_mut().zip(digits.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(core::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    match u64::from_le_bytes(bytes) {
+        0 => None,
+        filetime => Some(filetime),
+    }
+}
+
+/// A bookmark in a Favorites folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    name: String,
+    path: PathBuf,
+    shortcut: InternetShortcut,
+}
+
+impl Bookmark {
+    /// The title of the bookmark, the file name of its `.url` file without
+    /// the extension.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path of the `.url` file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The parsed internet shortcut.
+    #[must_use]
+    pub fn shortcut(&self) -> &InternetShortcut {
+        &self.shortcut
+    }
+
+    /// The URL of the bookmark.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        self.shortcut.url()
+    }
+}
+
+/// A folder of bookmarks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BookmarkFolder {
+    name: String,
+    path: PathBuf,
+    known_folder: Option<KnownFolder>,
+    folders: Vec<BookmarkFolder>,
+    bookmarks: Vec<Bookmark>,
+}
+
+impl BookmarkFolder {
+    /// Read the bookmark tree below `dir`.
+    ///
+    /// Subdirectories become nested folders and `.url` files become
+    /// bookmarks. Other files and `.url` files without a URL are skipped.
+    /// Folders and bookmarks are ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// If a directory or `.url` file cannot be read, an error is returned.
+    pub fn read(dir: &Path) -> io::Result<Self> {
+        let name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut folder = Self {
+            name,
+            path: dir.to_owned(),
+            known_folder: None,
+            folders: Vec::new(),
+            bookmarks: Vec::new(),
+        };
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                folder.folders.push(Self::read(&path)?);
+                continue;
+            }
+            let is_url = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map_or(false, |extension| extension.eq_ignore_ascii_case("url"));
+            let name = path.file_stem().and_then(|name| name.to_str());
+            if let (true, Some(name)) = (is_url, name) {
+                if let Some(shortcut) = InternetShortcut::open(&path)? {
+                    folder.bookmarks.push(Bookmark {
+                        name: name.to_owned(),
+                        path,
+                        shortcut,
+                    });
+                }
+            }
+        }
+        folder
+            .folders
+            .sort_by_cached_key(|folder| folder.name.to_lowercase());
+        folder
+            .bookmarks
+            .sort_by_cached_key(|bookmark| bookmark.name.to_lowercase());
+        Ok(folder)
+    }
+
+    /// The name of the folder.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path of the folder.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The known folder this folder was read from, for the roots returned
+    /// by [`favorites`].
+    #[must_use]
+    pub fn known_folder(&self) -> Option<KnownFolder> {
+        self.known_folder
+    }
+
+    /// The subfolders of this folder.
+    #[must_use]
+    pub fn folders(&self) -> &[BookmarkFolder] {
+        &self.folders
+    }
+
+    /// The bookmarks directly in this folder.
+    #[must_use]
+    pub fn bookmarks(&self) -> &[Bookmark] {
+        &self.bookmarks
+    }
+}
+
+/// Read the bookmark trees of the [`Favorites`], [`AppDataFavorites`], and
+/// [`Links`] folders as located by `resolver`.
+///
+/// One root is returned per folder that exists, in that order. A folder is
+/// skipped if it resolves to the same path as a previous one or lies within
+/// it, like the Links folder inside Favorites, whose bookmarks are already
+/// part of the Favorites tree.
+///
+/// # Errors
+///
+/// If a folder exists but its tree cannot be read, an error is returned.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::internet_shortcut::favorites;
+/// use known_folders::SystemResolver;
+///
+/// for root in favorites(&SystemResolver)? {
+///     for bookmark in root.bookmarks() {
+///         println!("{}: {}", bookmark.name(), bookmark.url());
+///     }
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [`Favorites`]: KnownFolder::Favorites
+/// [`AppDataFavorites`]: KnownFolder::AppDataFavorites
+/// [`Links`]: KnownFolder::Links
+pub fn favorites<R>(resolver: &R) -> io::Result<Vec<BookmarkFolder>>
+where
+    R: Resolver + ?Sized,
+{
+    let mut roots: Vec<BookmarkFolder> = Vec::new();
+    for &known_folder in &[
+        KnownFolder::Favorites,
+        KnownFolder::AppDataFavorites,
+        KnownFolder::Links,
+    ] {
+        let dir = match resolver.resolve(known_folder) {
+            Some(dir) => dir,
+            None => continue,
+        };
+        let visited = roots.iter().any(|root| {
+            let mut ancestor = Some(dir.clone());
+            while let Some(candidate) = ancestor {
+                if path::eq_ignore_case(&candidate, &root.path) {
+                    return true;
+                }
+                ancestor = path::parent(&candidate);
+            }
+            false
+        });
+        if visited {
+            continue;
+        }
+        match BookmarkFolder::read(&dir) {
+            Ok(mut root) => {
+                root.known_folder = Some(known_folder);
+                roots.push(root);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn parse_shortcut() {
+        let shortcut = InternetShortcut::parse(
+            b"[DEFAULT]\r\nBASEURL=https://example.com/\r\n[InternetShortcut]\r\nURL=https://example.com/\r\nIDList=\r\nIconFile=C:\\icons\\example.ico\r\nIconIndex=-3\r\nModified=00F4A9DE0B23D9010C\r\n[{000214A0-0000-0000-C000-000000000046}]\r\nProp3=19,11\r\n",
+        )
+        .unwrap();
+        assert_eq!(shortcut.url(), "https://example.com/");
+        let icon = shortcut.icon().unwrap();
+        assert_eq!(icon.path(), r"C:\icons\example.ico");
+        assert_eq!(icon.index(), -3);
+        assert_eq!(shortcut.modified(), Some(0x01D9_230B_DEA9_F400));
+        assert_eq!(shortcut.working_dir(), None);
+        assert_eq!(
+            shortcut
+                .ini()
+                .get("{000214A0-0000-0000-C000-000000000046}", "Prop3"),
+            Some("19,11")
+        );
+        assert_eq!(
+            InternetShortcut::parse_str("[InternetShortcut]\nIconIndex=1\n"),
+            None
+        );
+        assert_eq!(parse_filetime("0000000000000000"), None);
+        assert_eq!(parse_filetime("zz"), None);
+    }
+
+    #[test]
+    fn read_favorites_tree() {
+        let temp_dir = TempDir::new("favorites");
+        let root = temp_dir.path();
+        let favorites_dir = root.join("Favorites");
+        fs::create_dir_all(favorites_dir.join("Links")).unwrap();
+        fs::create_dir_all(favorites_dir.join("News")).unwrap();
+        fs::write(
+            favorites_dir.join("Search.url"),
+            "[InternetShortcut]\r\nURL=https://search.example/\r\n",
+        )
+        .unwrap();
+        fs::write(
+            favorites_dir.join("News").join("daily.URL"),
+            "[InternetShortcut]\r\nURL=https://news.example/\r\n",
+        )
+        .unwrap();
+        fs::write(
+            favorites_dir.join("Links").join("Intranet.url"),
+            "[InternetShortcut]\r\nURL=http://intranet/\r\n",
+        )
+        .unwrap();
+        fs::write(favorites_dir.join("desktop.ini"), "").unwrap();
+        fs::write(favorites_dir.join("empty.url"), "").unwrap();
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Favorites, &favorites_dir);
+        snapshot.insert(KnownFolder::AppDataFavorites, &favorites_dir);
+        snapshot.insert(KnownFolder::Links, favorites_dir.join("Links"));
+        let roots = favorites(&snapshot).unwrap();
+        assert_eq!(roots.len(), 1);
+        let favorites_root = &roots[0];
+        assert_eq!(favorites_root.known_folder(), Some(KnownFolder::Favorites));
+        assert_eq!(
+            favorites_root
+                .bookmarks()
+                .iter()
+                .map(Bookmark::url)
+                .collect::<Vec<_>>(),
+            ["https://search.example/"]
+        );
+        let folders = favorites_root
+            .folders()
+            .iter()
+            .map(|folder| (folder.name(), folder.bookmarks()[0].name()))
+            .collect::<Vec<_>>();
+        assert_eq!(folders, [("Links", "Intranet"), ("News", "daily")]);
+
+        snapshot.insert(KnownFolder::Favorites, root.join("missing"));
+        snapshot.insert(KnownFolder::AppDataFavorites, root.join("missing"));
+        let roots = favorites(&snapshot).unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].known_folder(), Some(KnownFolder::Links));
+    }
+}
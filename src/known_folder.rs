@@ -0,0 +1,1957 @@
+// src/known_folder.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::Guid;
+
+/// GUIDs that identify standard folders registered with the system as
+/// [Known Folders].
+///
+/// These folders are installed with Windows Vista and later operating systems,
+/// and a computer will have only folders appropriate to it installed.
+///
+/// For details on the **KNOWNFOLDERID** constants this enum represents, please
+/// refer to the [upstream documentation].
+///
+/// # Compatibility Notes
+///
+/// The Known Folders API allows for ISVs to extend the set of Known Folder IDs,
+/// but this enum only has support for first-party Known Folder IDs included in
+#[cfg_attr(windows, doc = "[`windows_sys`].")]
+#[cfg_attr(not(windows), doc = "`windows_sys`.")]
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::{get_known_folder_path, KnownFolder};
+///
+/// let profile_dir = get_known_folder_path(KnownFolder::Profile);
+/// ```
+///
+/// [Known Folders]: https://learn.microsoft.com/en-us/windows/win32/shell/known-folders
+/// [upstream documentation]: https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#constants
+#[non_exhaustive]
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum KnownFolder {
+    /// Known Folder ID `FOLDERID_AccountPictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AccountPictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AccountPictures>
+    AccountPictures,
+    /// Known Folder ID `FOLDERID_AddNewPrograms`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AddNewPrograms`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AddNewPrograms>
+    AddNewPrograms,
+    /// Known Folder ID `FOLDERID_AdminTools`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AdminTools`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AdminTools>
+    AdminTools,
+    /// Known Folder ID `FOLDERID_AllAppMods`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AllAppMods`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AllAppMods>
+    AllAppMods,
+    /// Known Folder ID `FOLDERID_AppCaptures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppCaptures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppCaptures>
+    AppCaptures,
+    /// Known Folder ID `FOLDERID_AppDataDesktop`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppDataDesktop`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppDataDesktop>
+    AppDataDesktop,
+    /// Known Folder ID `FOLDERID_AppDataDocuments`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppDataDocuments`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppDataDocuments>
+    AppDataDocuments,
+    /// Known Folder ID `FOLDERID_AppDataFavorites`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppDataFavorites`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppDataFavorites>
+    AppDataFavorites,
+    /// Known Folder ID `FOLDERID_AppDataProgramData`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppDataProgramData`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppDataProgramData>
+    AppDataProgramData,
+    /// Known Folder ID `FOLDERID_AppUpdates`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppUpdates`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppUpdates>
+    AppUpdates,
+    /// Known Folder ID `FOLDERID_ApplicationShortcuts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ApplicationShortcuts`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ApplicationShortcuts>
+    ApplicationShortcuts,
+    /// Known Folder ID `FOLDERID_AppsFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_AppsFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_AppsFolder>
+    AppsFolder,
+    /// Known Folder ID `FOLDERID_CDBurning`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CDBurning`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CDBurning>
+    CDBurning,
+    /// Known Folder ID `FOLDERID_CameraRoll`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CameraRoll`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CameraRoll>
+    CameraRoll,
+    /// Known Folder ID `FOLDERID_CameraRollLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CameraRollLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CameraRollLibrary>
+    CameraRollLibrary,
+    /// Known Folder ID `FOLDERID_ChangeRemovePrograms`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ChangeRemovePrograms`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ChangeRemovePrograms>
+    ChangeRemovePrograms,
+    /// Known Folder ID `FOLDERID_CommonAdminTools`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonAdminTools`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonAdminTools>
+    CommonAdminTools,
+    /// Known Folder ID `FOLDERID_CommonOEMLinks`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonOEMLinks`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonOEMLinks>
+    CommonOEMLinks,
+    /// Known Folder ID `FOLDERID_CommonPrograms`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonPrograms`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonPrograms>
+    CommonPrograms,
+    /// Known Folder ID `FOLDERID_CommonStartMenu`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonStartMenu`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonStartMenu>
+    CommonStartMenu,
+    /// Known Folder ID `FOLDERID_CommonStartMenuPlaces`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonStartMenuPlaces`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonStartMenuPlaces>
+    CommonStartMenuPlaces,
+    /// Known Folder ID `FOLDERID_CommonStartup`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonStartup`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonStartup>
+    CommonStartup,
+    /// Known Folder ID `FOLDERID_CommonTemplates`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CommonTemplates`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CommonTemplates>
+    CommonTemplates,
+    /// Known Folder ID `FOLDERID_ComputerFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ComputerFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ComputerFolder>
+    ComputerFolder,
+    /// Known Folder ID `FOLDERID_ConflictFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ConflictFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ConflictFolder>
+    ConflictFolder,
+    /// Known Folder ID `FOLDERID_ConnectionsFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ConnectionsFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ConnectionsFolder>
+    ConnectionsFolder,
+    /// Known Folder ID `FOLDERID_Contacts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Contacts`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Contacts>
+    Contacts,
+    /// Known Folder ID `FOLDERID_ControlPanelFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ControlPanelFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ControlPanelFolder>
+    ControlPanelFolder,
+    /// Known Folder ID `FOLDERID_Cookies`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Cookies`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Cookies>
+    Cookies,
+    /// Known Folder ID `FOLDERID_CurrentAppMods`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_CurrentAppMods`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_CurrentAppMods>
+    CurrentAppMods,
+    /// Known Folder ID `FOLDERID_Desktop`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Desktop`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Desktop>
+    Desktop,
+    /// Known Folder ID `FOLDERID_DevelopmentFiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_DevelopmentFiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_DevelopmentFiles>
+    DevelopmentFiles,
+    /// Known Folder ID `FOLDERID_Device`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Device`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Device>
+    Device,
+    /// Known Folder ID `FOLDERID_DeviceMetadataStore`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_DeviceMetadataStore`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_DeviceMetadataStore>
+    DeviceMetadataStore,
+    /// Known Folder ID `FOLDERID_Documents`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Documents`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Documents>
+    Documents,
+    /// Known Folder ID `FOLDERID_DocumentsLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_DocumentsLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_DocumentsLibrary>
+    DocumentsLibrary,
+    /// Known Folder ID `FOLDERID_Downloads`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Downloads`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Downloads>
+    Downloads,
+    /// Known Folder ID `FOLDERID_Favorites`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Favorites`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Favorites>
+    Favorites,
+    /// Known Folder ID `FOLDERID_Fonts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Fonts`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Fonts>
+    Fonts,
+    /// Known Folder ID `FOLDERID_GameTasks`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_GameTasks`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_GameTasks>
+    GameTasks,
+    /// Known Folder ID `FOLDERID_Games`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Games`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Games>
+    Games,
+    /// Known Folder ID `FOLDERID_History`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_History`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_History>
+    History,
+    /// Known Folder ID `FOLDERID_HomeGroup`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_HomeGroup`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_HomeGroup>
+    HomeGroup,
+    /// Known Folder ID `FOLDERID_HomeGroupCurrentUser`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_HomeGroupCurrentUser`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_HomeGroupCurrentUser>
+    HomeGroupCurrentUser,
+    /// Known Folder ID `FOLDERID_ImplicitAppShortcuts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ImplicitAppShortcuts`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ImplicitAppShortcuts>
+    ImplicitAppShortcuts,
+    /// Known Folder ID `FOLDERID_InternetCache`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_InternetCache`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_InternetCache>
+    InternetCache,
+    /// Known Folder ID `FOLDERID_InternetFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_InternetFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_InternetFolder>
+    InternetFolder,
+    /// Known Folder ID `FOLDERID_Libraries`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Libraries`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Libraries>
+    Libraries,
+    /// Known Folder ID `FOLDERID_Links`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Links`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Links>
+    Links,
+    /// Known Folder ID `FOLDERID_LocalAppData`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalAppData`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalAppData>
+    LocalAppData,
+    /// Known Folder ID `FOLDERID_LocalAppDataLow`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalAppDataLow`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalAppDataLow>
+    LocalAppDataLow,
+    /// Known Folder ID `FOLDERID_LocalDocuments`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalDocuments`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalDocuments>
+    LocalDocuments,
+    /// Known Folder ID `FOLDERID_LocalDownloads`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalDownloads`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalDownloads>
+    LocalDownloads,
+    /// Known Folder ID `FOLDERID_LocalMusic`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalMusic`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalMusic>
+    LocalMusic,
+    /// Known Folder ID `FOLDERID_LocalPictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalPictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalPictures>
+    LocalPictures,
+    /// Known Folder ID `FOLDERID_LocalStorage`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalStorage`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalStorage>
+    LocalStorage,
+    /// Known Folder ID `FOLDERID_LocalVideos`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalVideos`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalVideos>
+    LocalVideos,
+    /// Known Folder ID `FOLDERID_LocalizedResourcesDir`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_LocalizedResourcesDir`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_LocalizedResourcesDir>
+    LocalizedResourcesDir,
+    /// Known Folder ID `FOLDERID_Music`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Music`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Music>
+    Music,
+    /// Known Folder ID `FOLDERID_MusicLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_MusicLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_MusicLibrary>
+    MusicLibrary,
+    /// Known Folder ID `FOLDERID_NetHood`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_NetHood`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_NetHood>
+    NetHood,
+    /// Known Folder ID `FOLDERID_NetworkFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_NetworkFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_NetworkFolder>
+    NetworkFolder,
+    /// Known Folder ID `FOLDERID_Objects3D`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Objects3D`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Objects3D>
+    Objects3D,
+    /// Known Folder ID `FOLDERID_OneDrive`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_OneDrive`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_OneDrive>
+    OneDrive,
+    /// Known Folder ID `FOLDERID_OriginalImages`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_OriginalImages`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_OriginalImages>
+    OriginalImages,
+    /// Known Folder ID `FOLDERID_PhotoAlbums`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PhotoAlbums`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PhotoAlbums>
+    PhotoAlbums,
+    /// Known Folder ID `FOLDERID_Pictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Pictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Pictures>
+    Pictures,
+    /// Known Folder ID `FOLDERID_PicturesLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PicturesLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PicturesLibrary>
+    PicturesLibrary,
+    /// Known Folder ID `FOLDERID_Playlists`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Playlists`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Playlists>
+    Playlists,
+    /// Known Folder ID `FOLDERID_PrintHood`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PrintHood`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PrintHood>
+    PrintHood,
+    /// Known Folder ID `FOLDERID_PrintersFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PrintersFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PrintersFolder>
+    PrintersFolder,
+    /// Known Folder ID `FOLDERID_Profile`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Profile`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Profile>
+    Profile,
+    /// Known Folder ID `FOLDERID_ProgramData`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramData`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramData>
+    ProgramData,
+    /// Known Folder ID `FOLDERID_ProgramFiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFiles>
+    ProgramFiles,
+    /// Known Folder ID `FOLDERID_ProgramFilesCommon`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFilesCommon`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFilesCommon>
+    ProgramFilesCommon,
+    /// Known Folder ID `FOLDERID_ProgramFilesCommonX64`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFilesCommonX64`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFilesCommonX64>
+    ProgramFilesCommonX64,
+    /// Known Folder ID `FOLDERID_ProgramFilesCommonX86`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFilesCommonX86`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFilesCommonX86>
+    ProgramFilesCommonX86,
+    /// Known Folder ID `FOLDERID_ProgramFilesX64`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFilesX64`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFilesX64>
+    ProgramFilesX64,
+    /// Known Folder ID `FOLDERID_ProgramFilesX86`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ProgramFilesX86`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ProgramFilesX86>
+    ProgramFilesX86,
+    /// Known Folder ID `FOLDERID_Programs`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Programs`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Programs>
+    Programs,
+    /// Known Folder ID `FOLDERID_Public`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Public`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Public>
+    Public,
+    /// Known Folder ID `FOLDERID_PublicDesktop`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicDesktop`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicDesktop>
+    PublicDesktop,
+    /// Known Folder ID `FOLDERID_PublicDocuments`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicDocuments`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicDocuments>
+    PublicDocuments,
+    /// Known Folder ID `FOLDERID_PublicDownloads`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicDownloads`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicDownloads>
+    PublicDownloads,
+    /// Known Folder ID `FOLDERID_PublicGameTasks`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicGameTasks`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicGameTasks>
+    PublicGameTasks,
+    /// Known Folder ID `FOLDERID_PublicLibraries`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicLibraries`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicLibraries>
+    PublicLibraries,
+    /// Known Folder ID `FOLDERID_PublicMusic`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicMusic`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicMusic>
+    PublicMusic,
+    /// Known Folder ID `FOLDERID_PublicPictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicPictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicPictures>
+    PublicPictures,
+    /// Known Folder ID `FOLDERID_PublicRingtones`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicRingtones`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicRingtones>
+    PublicRingtones,
+    /// Known Folder ID `FOLDERID_PublicUserTiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicUserTiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicUserTiles>
+    PublicUserTiles,
+    /// Known Folder ID `FOLDERID_PublicVideos`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_PublicVideos`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_PublicVideos>
+    PublicVideos,
+    /// Known Folder ID `FOLDERID_QuickLaunch`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_QuickLaunch`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_QuickLaunch>
+    QuickLaunch,
+    /// Known Folder ID `FOLDERID_Recent`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Recent`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Recent>
+    Recent,
+    /// Known Folder ID `FOLDERID_RecordedCalls`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RecordedCalls`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RecordedCalls>
+    RecordedCalls,
+    /// Known Folder ID `FOLDERID_RecordedTVLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RecordedTVLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RecordedTVLibrary>
+    RecordedTVLibrary,
+    /// Known Folder ID `FOLDERID_RecycleBinFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RecycleBinFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RecycleBinFolder>
+    RecycleBinFolder,
+    /// Known Folder ID `FOLDERID_ResourceDir`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_ResourceDir`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_ResourceDir>
+    ResourceDir,
+    /// Known Folder ID `FOLDERID_RetailDemo`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RetailDemo`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RetailDemo>
+    RetailDemo,
+    /// Known Folder ID `FOLDERID_Ringtones`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Ringtones`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Ringtones>
+    Ringtones,
+    /// Known Folder ID `FOLDERID_RoamedTileImages`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RoamedTileImages`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RoamedTileImages>
+    RoamedTileImages,
+    /// Known Folder ID `FOLDERID_RoamingAppData`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RoamingAppData`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RoamingAppData>
+    RoamingAppData,
+    /// Known Folder ID `FOLDERID_RoamingTiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_RoamingTiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_RoamingTiles>
+    RoamingTiles,
+    /// Known Folder ID `FOLDERID_SEARCH_CSC`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SEARCH_CSC`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SEARCH_CSC>
+    SEARCH_CSC,
+    /// Known Folder ID `FOLDERID_SEARCH_MAPI`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SEARCH_MAPI`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SEARCH_MAPI>
+    SEARCH_MAPI,
+    /// Known Folder ID `FOLDERID_SampleMusic`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SampleMusic`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SampleMusic>
+    SampleMusic,
+    /// Known Folder ID `FOLDERID_SamplePictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SamplePictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SamplePictures>
+    SamplePictures,
+    /// Known Folder ID `FOLDERID_SamplePlaylists`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SamplePlaylists`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SamplePlaylists>
+    SamplePlaylists,
+    /// Known Folder ID `FOLDERID_SampleVideos`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SampleVideos`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SampleVideos>
+    SampleVideos,
+    /// Known Folder ID `FOLDERID_SavedGames`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SavedGames`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SavedGames>
+    SavedGames,
+    /// Known Folder ID `FOLDERID_SavedPictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SavedPictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SavedPictures>
+    SavedPictures,
+    /// Known Folder ID `FOLDERID_SavedPicturesLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SavedPicturesLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SavedPicturesLibrary>
+    SavedPicturesLibrary,
+    /// Known Folder ID `FOLDERID_SavedSearches`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SavedSearches`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SavedSearches>
+    SavedSearches,
+    /// Known Folder ID `FOLDERID_Screenshots`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Screenshots`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Screenshots>
+    Screenshots,
+    /// Known Folder ID `FOLDERID_SearchHistory`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SearchHistory`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SearchHistory>
+    SearchHistory,
+    /// Known Folder ID `FOLDERID_SearchHome`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SearchHome`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SearchHome>
+    SearchHome,
+    /// Known Folder ID `FOLDERID_SearchTemplates`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SearchTemplates`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SearchTemplates>
+    SearchTemplates,
+    /// Known Folder ID `FOLDERID_SendTo`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SendTo`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SendTo>
+    SendTo,
+    /// Known Folder ID `FOLDERID_SidebarDefaultParts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SidebarDefaultParts`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SidebarDefaultParts>
+    SidebarDefaultParts,
+    /// Known Folder ID `FOLDERID_SidebarParts`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SidebarParts`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SidebarParts>
+    SidebarParts,
+    /// Known Folder ID `FOLDERID_SkyDrive`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SkyDrive`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SkyDrive>
+    SkyDrive,
+    /// Known Folder ID `FOLDERID_SkyDriveCameraRoll`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SkyDriveCameraRoll`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SkyDriveCameraRoll>
+    SkyDriveCameraRoll,
+    /// Known Folder ID `FOLDERID_SkyDriveDocuments`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SkyDriveDocuments`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SkyDriveDocuments>
+    SkyDriveDocuments,
+    /// Known Folder ID `FOLDERID_SkyDriveMusic`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SkyDriveMusic`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SkyDriveMusic>
+    SkyDriveMusic,
+    /// Known Folder ID `FOLDERID_SkyDrivePictures`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SkyDrivePictures`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SkyDrivePictures>
+    SkyDrivePictures,
+    /// Known Folder ID `FOLDERID_StartMenu`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_StartMenu`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_StartMenu>
+    StartMenu,
+    /// Known Folder ID `FOLDERID_StartMenuAllPrograms`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_StartMenuAllPrograms`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_StartMenuAllPrograms>
+    StartMenuAllPrograms,
+    /// Known Folder ID `FOLDERID_Startup`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Startup`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Startup>
+    Startup,
+    /// Known Folder ID `FOLDERID_SyncManagerFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SyncManagerFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SyncManagerFolder>
+    SyncManagerFolder,
+    /// Known Folder ID `FOLDERID_SyncResultsFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SyncResultsFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SyncResultsFolder>
+    SyncResultsFolder,
+    /// Known Folder ID `FOLDERID_SyncSetupFolder`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SyncSetupFolder`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SyncSetupFolder>
+    SyncSetupFolder,
+    /// Known Folder ID `FOLDERID_System`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_System`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_System>
+    System,
+    /// Known Folder ID `FOLDERID_SystemX86`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_SystemX86`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_SystemX86>
+    SystemX86,
+    /// Known Folder ID `FOLDERID_Templates`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Templates`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Templates>
+    Templates,
+    /// Known Folder ID `FOLDERID_UserPinned`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UserPinned`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UserPinned>
+    UserPinned,
+    /// Known Folder ID `FOLDERID_UserProfiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UserProfiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UserProfiles>
+    UserProfiles,
+    /// Known Folder ID `FOLDERID_UserProgramFiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UserProgramFiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UserProgramFiles>
+    UserProgramFiles,
+    /// Known Folder ID `FOLDERID_UserProgramFilesCommon`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UserProgramFilesCommon`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UserProgramFilesCommon>
+    UserProgramFilesCommon,
+    /// Known Folder ID `FOLDERID_UsersFiles`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UsersFiles`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UsersFiles>
+    UsersFiles,
+    /// Known Folder ID `FOLDERID_UsersLibraries`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_UsersLibraries`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_UsersLibraries>
+    UsersLibraries,
+    /// Known Folder ID `FOLDERID_Videos`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Videos`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Videos>
+    Videos,
+    /// Known Folder ID `FOLDERID_VideosLibrary`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(
+        windows,
+        doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_VideosLibrary`]"
+    )]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_VideosLibrary>
+    VideosLibrary,
+    /// Known Folder ID `FOLDERID_Windows`.
+    ///
+    /// # Upstream Documentation
+    ///
+    #[cfg_attr(windows, doc = "- [`windows_sys::Win32::UI::Shell::FOLDERID_Windows`]")]
+    /// - <https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid#FOLDERID_Windows>
+    Windows,
+}
+
+impl KnownFolder {
+    /// All Known Folder IDs represented by this enum, in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert!(KnownFolder::ALL.contains(&KnownFolder::Downloads));
+    /// ```
+    pub const ALL: &'static [Self] = &[
+        Self::AccountPictures,
+        Self::AddNewPrograms,
+        Self::AdminTools,
+        Self::AllAppMods,
+        Self::AppCaptures,
+        Self::AppDataDesktop,
+        Self::AppDataDocuments,
+        Self::AppDataFavorites,
+        Self::AppDataProgramData,
+        Self::AppUpdates,
+        Self::ApplicationShortcuts,
+        Self::AppsFolder,
+        Self::CDBurning,
+        Self::CameraRoll,
+        Self::CameraRollLibrary,
+        Self::ChangeRemovePrograms,
+        Self::CommonAdminTools,
+        Self::CommonOEMLinks,
+        Self::CommonPrograms,
+        Self::CommonStartMenu,
+        Self::CommonStartMenuPlaces,
+        Self::CommonStartup,
+        Self::CommonTemplates,
+        Self::ComputerFolder,
+        Self::ConflictFolder,
+        Self::ConnectionsFolder,
+        Self::Contacts,
+        Self::ControlPanelFolder,
+        Self::Cookies,
+        Self::CurrentAppMods,
+        Self::Desktop,
+        Self::DevelopmentFiles,
+        Self::Device,
+        Self::DeviceMetadataStore,
+        Self::Documents,
+        Self::DocumentsLibrary,
+        Self::Downloads,
+        Self::Favorites,
+        Self::Fonts,
+        Self::GameTasks,
+        Self::Games,
+        Self::History,
+        Self::HomeGroup,
+        Self::HomeGroupCurrentUser,
+        Self::ImplicitAppShortcuts,
+        Self::InternetCache,
+        Self::InternetFolder,
+        Self::Libraries,
+        Self::Links,
+        Self::LocalAppData,
+        Self::LocalAppDataLow,
+        Self::LocalDocuments,
+        Self::LocalDownloads,
+        Self::LocalMusic,
+        Self::LocalPictures,
+        Self::LocalStorage,
+        Self::LocalVideos,
+        Self::LocalizedResourcesDir,
+        Self::Music,
+        Self::MusicLibrary,
+        Self::NetHood,
+        Self::NetworkFolder,
+        Self::Objects3D,
+        Self::OneDrive,
+        Self::OriginalImages,
+        Self::PhotoAlbums,
+        Self::Pictures,
+        Self::PicturesLibrary,
+        Self::Playlists,
+        Self::PrintHood,
+        Self::PrintersFolder,
+        Self::Profile,
+        Self::ProgramData,
+        Self::ProgramFiles,
+        Self::ProgramFilesCommon,
+        Self::ProgramFilesCommonX64,
+        Self::ProgramFilesCommonX86,
+        Self::ProgramFilesX64,
+        Self::ProgramFilesX86,
+        Self::Programs,
+        Self::Public,
+        Self::PublicDesktop,
+        Self::PublicDocuments,
+        Self::PublicDownloads,
+        Self::PublicGameTasks,
+        Self::PublicLibraries,
+        Self::PublicMusic,
+        Self::PublicPictures,
+        Self::PublicRingtones,
+        Self::PublicUserTiles,
+        Self::PublicVideos,
+        Self::QuickLaunch,
+        Self::Recent,
+        Self::RecordedCalls,
+        Self::RecordedTVLibrary,
+        Self::RecycleBinFolder,
+        Self::ResourceDir,
+        Self::RetailDemo,
+        Self::Ringtones,
+        Self::RoamedTileImages,
+        Self::RoamingAppData,
+        Self::RoamingTiles,
+        Self::SEARCH_CSC,
+        Self::SEARCH_MAPI,
+        Self::SampleMusic,
+        Self::SamplePictures,
+        Self::SamplePlaylists,
+        Self::SampleVideos,
+        Self::SavedGames,
+        Self::SavedPictures,
+        Self::SavedPicturesLibrary,
+        Self::SavedSearches,
+        Self::Screenshots,
+        Self::SearchHistory,
+        Self::SearchHome,
+        Self::SearchTemplates,
+        Self::SendTo,
+        Self::SidebarDefaultParts,
+        Self::SidebarParts,
+        Self::SkyDrive,
+        Self::SkyDriveCameraRoll,
+        Self::SkyDriveDocuments,
+        Self::SkyDriveMusic,
+        Self::SkyDrivePictures,
+        Self::StartMenu,
+        Self::StartMenuAllPrograms,
+        Self::Startup,
+        Self::SyncManagerFolder,
+        Self::SyncResultsFolder,
+        Self::SyncSetupFolder,
+        Self::System,
+        Self::SystemX86,
+        Self::Templates,
+        Self::UserPinned,
+        Self::UserProfiles,
+        Self::UserProgramFiles,
+        Self::UserProgramFilesCommon,
+        Self::UsersFiles,
+        Self::UsersLibraries,
+        Self::Videos,
+        Self::VideosLibrary,
+        Self::Windows,
+    ];
+
+    /// Return the **KNOWNFOLDERID** GUID that identifies this known folder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{Guid, KnownFolder};
+    ///
+    /// assert_eq!(
+    ///     KnownFolder::Downloads.guid(),
+    ///     Guid::from_u128(0x374de290_123f_4565_9164_39c4925e467b),
+    /// );
+    /// ```
+    #[must_use]
+    #[allow(clippy::match_same_arms)]
+    #[allow(clippy::too_many_lines)]
+    pub const fn guid(self) -> Guid {
+        match self {
+            Self::AccountPictures => Guid::from_u128(0x008ca0b1_55b4_4c56_b8a8_4de4b299d3be),
+            Self::AddNewPrograms => Guid::from_u128(0xde61d971_5ebc_4f02_a3a9_6c82895e5c04),
+            Self::AdminTools => Guid::from_u128(0x724ef170_a42d_4fef_9f26_b60e846fba4f),
+            Self::AllAppMods => Guid::from_u128(0x7ad67899_66af_43ba_9156_6aad42e6c596),
+            Self::AppCaptures => Guid::from_u128(0xedc0fe71_98d8_4f4a_b920_c8dc133cb165),
+            Self::AppDataDesktop => Guid::from_u128(0xb2c5e279_7add_439f_b28c_c41fe1bbf672),
+            Self::AppDataDocuments => Guid::from_u128(0x7be16610_1f7f_44ac_bff0_83e15f2ffca1),
+            Self::AppDataFavorites => Guid::from_u128(0x7cfbefbc_de1f_45aa_b843_a542ac536cc9),
+            Self::AppDataProgramData => Guid::from_u128(0x559d40a3_a036_40fa_af61_84cb430a4d34),
+            Self::AppUpdates => Guid::from_u128(0xa305ce99_f527_492b_8b1a_7e76fa98d6e4),
+            Self::ApplicationShortcuts => Guid::from_u128(0xa3918781_e5f2_4890_b3d9_a7e54332328c),
+            Self::AppsFolder => Guid::from_u128(0x1e87508d_89c2_42f0_8a7e_645a0f50ca58),
+            Self::CDBurning => Guid::from_u128(0x9e52ab10_f80d_49df_acb8_4330f5687855),
+            Self::CameraRoll => Guid::from_u128(0xab5fb87b_7ce2_4f83_915d_550846c9537b),
+            Self::CameraRollLibrary => Guid::from_u128(0x2b20df75_1eda_4039_8097_38798227d5b7),
+            Self::ChangeRemovePrograms => Guid::from_u128(0xdf7266ac_9274_4867_8d55_3bd661de872d),
+            Self::CommonAdminTools => Guid::from_u128(0xd0384e7d_bac3_4797_8f14_cba229b392b5),
+            Self::CommonOEMLinks => Guid::from_u128(0xc1bae2d0_10df_4334_bedd_7aa20b227a9d),
+            Self::CommonPrograms => Guid::from_u128(0x0139d44e_6afe_49f2_8690_3dafcae6ffb8),
+            Self::CommonStartMenu => Guid::from_u128(0xa4115719_d62e_491d_aa7c_e74b8be3b067),
+            Self::CommonStartMenuPlaces => Guid::from_u128(0xa440879f_87a0_4f7d_b700_0207b966194a),
+            Self::CommonStartup => Guid::from_u128(0x82a5ea35_d9cd_47c5_9629_e15d2f714e6e),
+            Self::CommonTemplates => Guid::from_u128(0xb94237e7_57ac_4347_9151_b08c6c32d1f7),
+            Self::ComputerFolder => Guid::from_u128(0x0ac0837c_bbf8_452a_850d_79d08e667ca7),
+            Self::ConflictFolder => Guid::from_u128(0x4bfefb45_347d_4006_a5be_ac0cb0567192),
+            Self::ConnectionsFolder => Guid::from_u128(0x6f0cd92b_2e97_45d1_88ff_b0d186b8dedd),
+            Self::Contacts => Guid::from_u128(0x56784854_c6cb_462b_8169_88e350acb882),
+            Self::ControlPanelFolder => Guid::from_u128(0x82a74aeb_aeb4_465c_a014_d097ee346d63),
+            Self::Cookies => Guid::from_u128(0x2b0f765d_c0e9_4171_908e_08a611b84ff6),
+            Self::CurrentAppMods => Guid::from_u128(0x3db40b20_2a30_4dbe_917e_771dd21dd099),
+            Self::Desktop => Guid::from_u128(0xb4bfcc3a_db2c_424c_b029_7fe99a87c641),
+            Self::DevelopmentFiles => Guid::from_u128(0xdbe8e08e_3053_4bbc_b183_2a7b2b191e59),
+            Self::Device => Guid::from_u128(0x1c2ac1dc_4358_4b6c_9733_af21156576f0),
+            Self::DeviceMetadataStore => Guid::from_u128(0x5ce4a5e9_e4eb_479d_b89f_130c02886155),
+            Self::Documents => Guid::from_u128(0xfdd39ad0_238f_46af_adb4_6c85480369c7),
+            Self::DocumentsLibrary => Guid::from_u128(0x7b0db17d_9cd2_4a93_9733_46cc89022e7c),
+            Self::Downloads => Guid::from_u128(0x374de290_123f_4565_9164_39c4925e467b),
+            Self::Favorites => Guid::from_u128(0x1777f761_68ad_4d8a_87bd_30b759fa33dd),
+            Self::Fonts => Guid::from_u128(0xfd228cb7_ae11_4ae3_864c_16f3910ab8fe),
+            Self::GameTasks => Guid::from_u128(0x054fae61_4dd8_4787_80b6_090220c4b700),
+            Self::Games => Guid::from_u128(0xcac52c1a_b53d_4edc_92d7_6b2e8ac19434),
+            Self::History => Guid::from_u128(0xd9dc8a3b_b784_432e_a781_5a1130a75963),
+            Self::HomeGroup => Guid::from_u128(0x52528a6b_b9e3_4add_b60d_588c2dba842d),
+            Self::HomeGroupCurrentUser => Guid::from_u128(0x9b74b6a3_0dfd_4f11_9e78_5f7800f2e772),
+            Self::ImplicitAppShortcuts => Guid::from_u128(0xbcb5256f_79f6_4cee_b725_dc34e402fd46),
+            Self::InternetCache => Guid::from_u128(0x352481e8_33be_4251_ba85_6007caedcf9d),
+            Self::InternetFolder => Guid::from_u128(0x4d9f7874_4e0c_4904_967b_40b0d20c3e4b),
+            Self::Libraries => Guid::from_u128(0x1b3ea5dc_b587_4786_b4ef_bd1dc332aeae),
+            Self::Links => Guid::from_u128(0xbfb9d5e0_c6a9_404c_b2b2_ae6db6af4968),
+            Self::LocalAppData => Guid::from_u128(0xf1b32785_6fba_4fcf_9d55_7b8e7f157091),
+            Self::LocalAppDataLow => Guid::from_u128(0xa520a1a4_1780_4ff6_bd18_167343c5af16),
+            Self::LocalDocuments => Guid::from_u128(0xf42ee2d3_909f_4907_8871_4c22fc0bf756),
+            Self::LocalDownloads => Guid::from_u128(0x7d83ee9b_2244_4e70_b1f5_5393042af1e4),
+            Self::LocalMusic => Guid::from_u128(0xa0c69a99_21c8_4671_8703_7934162fcf1d),
+            Self::LocalPictures => Guid::from_u128(0x0ddd015d_b06c_45d5_8c4c_f59713854639),
+            Self::LocalStorage => Guid::from_u128(0xb3eb08d3_a1f3_496b_865a_42b536cda0ec),
+            Self::LocalVideos => Guid::from_u128(0x35286a68_3c57_41a1_bbb1_0eae73d76c95),
+            Self::LocalizedResourcesDir => Guid::from_u128(0x2a00375e_224c_49de_b8d1_440df7ef3ddc),
+            Self::Music => Guid::from_u128(0x4bd8d571_6d19_48d3_be97_422220080e43),
+            Self::MusicLibrary => Guid::from_u128(0x2112ab0a_c86a_4ffe_a368_0de96e47012e),
+            Self::NetHood => Guid::from_u128(0xc5abbf53_e17f_4121_8900_86626fc2c973),
+            Self::NetworkFolder => Guid::from_u128(0xd20beec4_5ca8_4905_ae3b_bf251ea09b53),
+            Self::Objects3D => Guid::from_u128(0x31c0dd25_9439_4f12_bf41_7ff4eda38722),
+            Self::OneDrive => Guid::from_u128(0xa52bba46_e9e1_435f_b3d9_28daa648c0f6),
+            Self::OriginalImages => Guid::from_u128(0x2c36c0aa_5812_4b87_bfd0_4cd0dfb19b39),
+            Self::PhotoAlbums => Guid::from_u128(0x69d2cf90_fc33_4fb7_9a0c_ebb0f0fcb43c),
+            Self::Pictures => Guid::from_u128(0x33e28130_4e1e_4676_835a_98395c3bc3bb),
+            Self::PicturesLibrary => Guid::from_u128(0xa990ae9f_a03b_4e80_94bc_9912d7504104),
+            Self::Playlists => Guid::from_u128(0xde92c1c7_837f_4f69_a3bb_86e631204a23),
+            Self::PrintHood => Guid::from_u128(0x9274bd8d_cfd1_41c3_b35e_b13f55a758f4),
+            Self::PrintersFolder => Guid::from_u128(0x76fc4e2d_d6ad_4519_a663_37bd56068185),
+            Self::Profile => Guid::from_u128(0x5e6c858f_0e22_4760_9afe_ea3317b67173),
+            Self::ProgramData => Guid::from_u128(0x62ab5d82_fdc1_4dc3_a9dd_070d1d495d97),
+            Self::ProgramFiles => Guid::from_u128(0x905e63b6_c1bf_494e_b29c_65b732d3d21a),
+            Self::ProgramFilesCommon => Guid::from_u128(0xf7f1ed05_9f6d_47a2_aaae_29d317c6f066),
+            Self::ProgramFilesCommonX64 => Guid::from_u128(0x6365d5a7_0f0d_45e5_87f6_0da56b6a4f7d),
+            Self::ProgramFilesCommonX86 => Guid::from_u128(0xde974d24_d9c6_4d3e_bf91_f4455120b917),
+            Self::ProgramFilesX64 => Guid::from_u128(0x6d809377_6af0_444b_8957_a3773f02200e),
+            Self::ProgramFilesX86 => Guid::from_u128(0x7c5a40ef_a0fb_4bfc_874a_c0f2e0b9fa8e),
+            Self::Programs => Guid::from_u128(0xa77f5d77_2e2b_44c3_a6a2_aba601054a51),
+            Self::Public => Guid::from_u128(0xdfdf76a2_c82a_4d63_906a_5644ac457385),
+            Self::PublicDesktop => Guid::from_u128(0xc4aa340d_f20f_4863_afef_f87ef2e6ba25),
+            Self::PublicDocuments => Guid::from_u128(0xed4824af_dce4_45a8_81e2_fc7965083634),
+            Self::PublicDownloads => Guid::from_u128(0x3d644c9b_1fb8_4f30_9b45_f670235f79c0),
+            Self::PublicGameTasks => Guid::from_u128(0xdebf2536_e1a8_4c59_b6a2_414586476aea),
+            Self::PublicLibraries => Guid::from_u128(0x48daf80b_e6cf_4f4e_b800_0e69d84ee384),
+            Self::PublicMusic => Guid::from_u128(0x3214fab5_9757_4298_bb61_92a9deaa44ff),
+            Self::PublicPictures => Guid::from_u128(0xb6ebfb86_6907_413c_9af7_4fc2abf07cc5),
+            Self::PublicRingtones => Guid::from_u128(0xe555ab60_153b_4d17_9f04_a5fe99fc15ec),
+            Self::PublicUserTiles => Guid::from_u128(0x0482af6c_08f1_4c34_8c90_e17ec98b1e17),
+            Self::PublicVideos => Guid::from_u128(0x2400183a_6185_49fb_a2d8_4a392a602ba3),
+            Self::QuickLaunch => Guid::from_u128(0x52a4f021_7b75_48a9_9f6b_4b87a210bc8f),
+            Self::Recent => Guid::from_u128(0xae50c081_ebd2_438a_8655_8a092e34987a),
+            Self::RecordedCalls => Guid::from_u128(0x2f8b40c2_83ed_48ee_b383_a1f157ec6f9a),
+            Self::RecordedTVLibrary => Guid::from_u128(0x1a6fdba2_f42d_4358_a798_b74d745926c5),
+            Self::RecycleBinFolder => Guid::from_u128(0xb7534046_3ecb_4c18_be4e_64cd4cb7d6ac),
+            Self::ResourceDir => Guid::from_u128(0x8ad10c31_2adb_4296_a8f7_e4701232c972),
+            Self::RetailDemo => Guid::from_u128(0x12d4c69e_24ad_4923_be19_31321c43a767),
+            Self::Ringtones => Guid::from_u128(0xc870044b_f49e_4126_a9c3_b52a1ff411e8),
+            Self::RoamedTileImages => Guid::from_u128(0xaaa8d5a5_f1d6_4259_baa8_78e7ef60835e),
+            Self::RoamingAppData => Guid::from_u128(0x3eb685db_65f9_4cf6_a03a_e3ef65729f3d),
+            Self::RoamingTiles => Guid::from_u128(0x00bcfc5a_ed94_4e48_96a1_3f6217f21990),
+            Self::SEARCH_CSC => Guid::from_u128(0xee32e446_31ca_4aba_814f_a5ebd2fd6d5e),
+            Self::SEARCH_MAPI => Guid::from_u128(0x98ec0e18_2098_4d44_8644_66979315a281),
+            Self::SampleMusic => Guid::from_u128(0xb250c668_f57d_4ee1_a63c_290ee7d1aa1f),
+            Self::SamplePictures => Guid::from_u128(0xc4900540_2379_4c75_844b_64e6faf8716b),
+            Self::SamplePlaylists => Guid::from_u128(0x15ca69b3_30ee_49c1_ace1_6b5ec372afb5),
+            Self::SampleVideos => Guid::from_u128(0x859ead94_2e85_48ad_a71a_0969cb56a6cd),
+            Self::SavedGames => Guid::from_u128(0x4c5c32ff_bb9d_43b0_b5b4_2d72e54eaaa4),
+            Self::SavedPictures => Guid::from_u128(0x3b193882_d3ad_4eab_965a_69829d1fb59f),
+            Self::SavedPicturesLibrary => Guid::from_u128(0xe25b5812_be88_4bd9_94b0_29233477b6c3),
+            Self::SavedSearches => Guid::from_u128(0x7d1d3a04_debb_4115_95cf_2f29da2920da),
+            Self::Screenshots => Guid::from_u128(0xb7bede81_df94_4682_a7d8_57a52620b86f),
+            Self::SearchHistory => Guid::from_u128(0x0d4c3db6_03a3_462f_a0e6_08924c41b5d4),
+            Self::SearchHome => Guid::from_u128(0x190337d1_b8ca_4121_a639_6d472d16972a),
+            Self::SearchTemplates => Guid::from_u128(0x7e636bfe_dfa9_4d5e_b456_d7b39851d8a9),
+            Self::SendTo => Guid::from_u128(0x8983036c_27c0_404b_8f08_102d10dcfd74),
+            Self::SidebarDefaultParts => Guid::from_u128(0x7b396e54_9ec5_4300_be0a_2482ebae1a26),
+            Self::SidebarParts => Guid::from_u128(0xa75d362e_50fc_4fb7_ac2c_a8beaa314493),
+            Self::SkyDrive => Guid::from_u128(0xa52bba46_e9e1_435f_b3d9_28daa648c0f6),
+            Self::SkyDriveCameraRoll => Guid::from_u128(0x767e6811_49cb_4273_87c2_20f355e1085b),
+            Self::SkyDriveDocuments => Guid::from_u128(0x24d89e24_2f19_4534_9dde_6a6671fbb8fe),
+            Self::SkyDriveMusic => Guid::from_u128(0xc3f2459e_80d6_45dc_bfef_1f769f2be730),
+            Self::SkyDrivePictures => Guid::from_u128(0x339719b5_8c47_4894_94c2_d8f77add44a6),
+            Self::StartMenu => Guid::from_u128(0x625b53c3_ab48_4ec1_ba1f_a1ef4146fc19),
+            Self::StartMenuAllPrograms => Guid::from_u128(0xf26305ef_6948_40b9_b255_81453d09c785),
+            Self::Startup => Guid::from_u128(0xb97d20bb_f46a_4c97_ba10_5e3608430854),
+            Self::SyncManagerFolder => Guid::from_u128(0x43668bf8_c14e_49b2_97c9_747784d784b7),
+            Self::SyncResultsFolder => Guid::from_u128(0x289a9a43_be44_4057_a41b_587a76d7e7f9),
+            Self::SyncSetupFolder => Guid::from_u128(0x0f214138_b1d3_4a90_bba9_27cbc0c5389a),
+            Self::System => Guid::from_u128(0x1ac14e77_02e7_4e5d_b744_2eb1ae5198b7),
+            Self::SystemX86 => Guid::from_u128(0xd65231b0_b2f1_4857_a4ce_a8e7c6ea7d27),
+            Self::Templates => Guid::from_u128(0xa63293e8_664e_48db_a079_df759e0509f7),
+            Self::UserPinned => Guid::from_u128(0x9e3995ab_1f9c_4f13_b827_48b24b6c7174),
+            Self::UserProfiles => Guid::from_u128(0x0762d272_c50a_4bb0_a382_697dcd729b80),
+            Self::UserProgramFiles => Guid::from_u128(0x5cd7aee2_2219_4a67_b85d_6c9ce15660cb),
+            Self::UserProgramFilesCommon => Guid::from_u128(0xbcbd3057_ca5c_4622_b42d_bc56db0ae516),
+            Self::UsersFiles => Guid::from_u128(0xf3ce0f7c_4901_4acc_8648_d5d44b04ef8f),
+            Self::UsersLibraries => Guid::from_u128(0xa302545d_deff_464b_abe8_61c8648d939b),
+            Self::Videos => Guid::from_u128(0x18989b1d_99b5_455b_841c_ab7c74e4ddfc),
+            Self::VideosLibrary => Guid::from_u128(0x491e922f_5643_4af4_a7eb_4e7a138d8174),
+            Self::Windows => Guid::from_u128(0xf38bf404_1d43_42f2_9305_67de0b28fc23),
+        }
+    }
+
+    /// Look up the known folder identified by the given **KNOWNFOLDERID**
+    /// GUID.
+    ///
+    /// Returns [`None`] if the GUID does not identify a first-party known
+    /// folder represented by this enum.
+    ///
+    /// `FOLDERID_SkyDrive` and `FOLDERID_OneDrive` share a GUID. This
+    /// function returns [`KnownFolder::OneDrive`] for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{Guid, KnownFolder};
+    ///
+    /// let guid = "{374DE290-123F-4565-9164-39C4925E467B}".parse::<Guid>()?;
+    /// assert_eq!(KnownFolder::from_guid(guid), Some(KnownFolder::Downloads));
+    /// # Ok::<(), known_folders::ParseGuidError>(())
+    /// ```
+    #[must_use]
+    pub fn from_guid(guid: Guid) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|folder| folder.guid() == guid)
+    }
+
+    /// Return the name of this known folder, which is the name of its
+    /// **KNOWNFOLDERID** constant without the `FOLDERID_` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::RoamingAppData.name(), "RoamingAppData");
+    /// assert_eq!(KnownFolder::SEARCH_CSC.name(), "SEARCH_CSC");
+    /// ```
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::AccountPictures => "AccountPictures",
+            Self::AddNewPrograms => "AddNewPrograms",
+            Self::AdminTools => "AdminTools",
+            Self::AllAppMods => "AllAppMods",
+            Self::AppCaptures => "AppCaptures",
+            Self::AppDataDesktop => "AppDataDesktop",
+            Self::AppDataDocuments => "AppDataDocuments",
+            Self::AppDataFavorites => "AppDataFavorites",
+            Self::AppDataProgramData => "AppDataProgramData",
+            Self::AppUpdates => "AppUpdates",
+            Self::ApplicationShortcuts => "ApplicationShortcuts",
+            Self::AppsFolder => "AppsFolder",
+            Self::CDBurning => "CDBurning",
+            Self::CameraRoll => "CameraRoll",
+            Self::CameraRollLibrary => "CameraRollLibrary",
+            Self::ChangeRemovePrograms => "ChangeRemovePrograms",
+            Self::CommonAdminTools => "CommonAdminTools",
+            Self::CommonOEMLinks => "CommonOEMLinks",
+            Self::CommonPrograms => "CommonPrograms",
+            Self::CommonStartMenu => "CommonStartMenu",
+            Self::CommonStartMenuPlaces => "CommonStartMenuPlaces",
+            Self::CommonStartup => "CommonStartup",
+            Self::CommonTemplates => "CommonTemplates",
+            Self::ComputerFolder => "ComputerFolder",
+            Self::ConflictFolder => "ConflictFolder",
+            Self::ConnectionsFolder => "ConnectionsFolder",
+            Self::Contacts => "Contacts",
+            Self::ControlPanelFolder => "ControlPanelFolder",
+            Self::Cookies => "Cookies",
+            Self::CurrentAppMods => "CurrentAppMods",
+            Self::Desktop => "Desktop",
+            Self::DevelopmentFiles => "DevelopmentFiles",
+            Self::Device => "Device",
+            Self::DeviceMetadataStore => "DeviceMetadataStore",
+            Self::Documents => "Documents",
+            Self::DocumentsLibrary => "DocumentsLibrary",
+            Self::Downloads => "Downloads",
+            Self::Favorites => "Favorites",
+            Self::Fonts => "Fonts",
+            Self::GameTasks => "GameTasks",
+            Self::Games => "Games",
+            Self::History => "History",
+            Self::HomeGroup => "HomeGroup",
+            Self::HomeGroupCurrentUser => "HomeGroupCurrentUser",
+            Self::ImplicitAppShortcuts => "ImplicitAppShortcuts",
+            Self::InternetCache => "InternetCache",
+            Self::InternetFolder => "InternetFolder",
+            Self::Libraries => "Libraries",
+            Self::Links => "Links",
+            Self::LocalAppData => "LocalAppData",
+            Self::LocalAppDataLow => "LocalAppDataLow",
+            Self::LocalDocuments => "LocalDocuments",
+            Self::LocalDownloads => "LocalDownloads",
+            Self::LocalMusic => "LocalMusic",
+            Self::LocalPictures => "LocalPictures",
+            Self::LocalStorage => "LocalStorage",
+            Self::LocalVideos => "LocalVideos",
+            Self::LocalizedResourcesDir => "LocalizedResourcesDir",
+            Self::Music => "Music",
+            Self::MusicLibrary => "MusicLibrary",
+            Self::NetHood => "NetHood",
+            Self::NetworkFolder => "NetworkFolder",
+            Self::Objects3D => "Objects3D",
+            Self::OneDrive => "OneDrive",
+            Self::OriginalImages => "OriginalImages",
+            Self::PhotoAlbums => "PhotoAlbums",
+            Self::Pictures => "Pictures",
+            Self::PicturesLibrary => "PicturesLibrary",
+            Self::Playlists => "Playlists",
+            Self::PrintHood => "PrintHood",
+            Self::PrintersFolder => "PrintersFolder",
+            Self::Profile => "Profile",
+            Self::ProgramData => "ProgramData",
+            Self::ProgramFiles => "ProgramFiles",
+            Self::ProgramFilesCommon => "ProgramFilesCommon",
+            Self::ProgramFilesCommonX64 => "ProgramFilesCommonX64",
+            Self::ProgramFilesCommonX86 => "ProgramFilesCommonX86",
+            Self::ProgramFilesX64 => "ProgramFilesX64",
+            Self::ProgramFilesX86 => "ProgramFilesX86",
+            Self::Programs => "Programs",
+            Self::Public => "Public",
+            Self::PublicDesktop => "PublicDesktop",
+            Self::PublicDocuments => "PublicDocuments",
+            Self::PublicDownloads => "PublicDownloads",
+            Self::PublicGameTasks => "PublicGameTasks",
+            Self::PublicLibraries => "PublicLibraries",
+            Self::PublicMusic => "PublicMusic",
+            Self::PublicPictures => "PublicPictures",
+            Self::PublicRingtones => "PublicRingtones",
+            Self::PublicUserTiles => "PublicUserTiles",
+            Self::PublicVideos => "PublicVideos",
+            Self::QuickLaunch => "QuickLaunch",
+            Self::Recent => "Recent",
+            Self::RecordedCalls => "RecordedCalls",
+            Self::RecordedTVLibrary => "RecordedTVLibrary",
+            Self::RecycleBinFolder => "RecycleBinFolder",
+            Self::ResourceDir => "ResourceDir",
+            Self::RetailDemo => "RetailDemo",
+            Self::Ringtones => "Ringtones",
+            Self::RoamedTileImages => "RoamedTileImages",
+            Self::RoamingAppData => "RoamingAppData",
+            Self::RoamingTiles => "RoamingTiles",
+            Self::SEARCH_CSC => "SEARCH_CSC",
+            Self::SEARCH_MAPI => "SEARCH_MAPI",
+            Self::SampleMusic => "SampleMusic",
+            Self::SamplePictures => "SamplePictures",
+            Self::SamplePlaylists => "SamplePlaylists",
+            Self::SampleVideos => "SampleVideos",
+            Self::SavedGames => "SavedGames",
+            Self::SavedPictures => "SavedPictures",
+            Self::SavedPicturesLibrary => "SavedPicturesLibrary",
+            Self::SavedSearches => "SavedSearches",
+            Self::Screenshots => "Screenshots",
+            Self::SearchHistory => "SearchHistory",
+            Self::SearchHome => "SearchHome",
+            Self::SearchTemplates => "SearchTemplates",
+            Self::SendTo => "SendTo",
+            Self::SidebarDefaultParts => "SidebarDefaultParts",
+            Self::SidebarParts => "SidebarParts",
+            Self::SkyDrive => "SkyDrive",
+            Self::SkyDriveCameraRoll => "SkyDriveCameraRoll",
+            Self::SkyDriveDocuments => "SkyDriveDocuments",
+            Self::SkyDriveMusic => "SkyDriveMusic",
+            Self::SkyDrivePictures => "SkyDrivePictures",
+            Self::StartMenu => "StartMenu",
+            Self::StartMenuAllPrograms => "StartMenuAllPrograms",
+            Self::Startup => "Startup",
+            Self::SyncManagerFolder => "SyncManagerFolder",
+            Self::SyncResultsFolder => "SyncResultsFolder",
+            Self::SyncSetupFolder => "SyncSetupFolder",
+            Self::System => "System",
+            Self::SystemX86 => "SystemX86",
+            Self::Templates => "Templates",
+            Self::UserPinned => "UserPinned",
+            Self::UserProfiles => "UserProfiles",
+            Self::UserProgramFiles => "UserProgramFiles",
+            Self::UserProgramFilesCommon => "UserProgramFilesCommon",
+            Self::UsersFiles => "UsersFiles",
+            Self::UsersLibraries => "UsersLibraries",
+            Self::Videos => "Videos",
+            Self::VideosLibrary => "VideosLibrary",
+            Self::Windows => "Windows",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn guids_are_unique() {
+        let guids = KnownFolder::ALL
+            .iter()
+            .filter(|&&folder| folder != KnownFolder::SkyDrive)
+            .map(|folder| folder.guid())
+            .collect::<HashSet<_>>();
+        assert_eq!(guids.len(), KnownFolder::ALL.len() - 1);
+        assert_eq!(KnownFolder::SkyDrive.guid(), KnownFolder::OneDrive.guid());
+    }
+
+    #[test]
+    fn from_guid_round_trips() {
+        for &folder in KnownFolder::ALL {
+            let expected = match folder {
+                KnownFolder::SkyDrive => KnownFolder::OneDrive,
+                folder => folder,
+            };
+            assert_eq!(KnownFolder::from_guid(folder.guid()), Some(expected));
+        }
+        assert_eq!(KnownFolder::from_guid(Guid::NIL), None);
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn guids_match_windows_sys() {
+        for &folder in KnownFolder::ALL {
+            let guid = folder.to_guid();
+            let expected = Guid::from_fields(guid.data1, guid.data2, guid.data3, guid.data4);
+            assert_eq!(folder.guid(), expected, "{folder:?}");
+        }
+    }
+}
@@ -34,7 +34,11 @@
 //!
 //! The Known Folders API first appeared in Windows Vista.
 //!
-//! Note that this crate is completely empty on non-Windows platforms.
+//! [`get_known_folder_path`] and [`SystemResolver`] are only available on
+//! Windows. The rest of this crate is platform-independent: [`KnownFolder`]
+//! IDs, registry parsers, and [`Resolver`] implementations backed by data
+//! captured from a Windows system can be used on any platform to resolve
+//! known folders offline.
 //!
 //! ## Linkage
 //!
@@ -50,8 +54,36 @@
 //! let profile_dir = get_known_folder_path(KnownFolder::Profile);
 //! ```
 //!
+//! Known folder locations can also be resolved from the `User Shell Folders`
+//! key of a registry export, which works on all platforms:
+//!
+//! ```
+//! use known_folders::registry::export::RegFile;
+//! use known_folders::shell_folders::ShellFolders;
+//! use known_folders::{Environment, KnownFolder, Resolver};
+//!
+//! let reg = br#"Windows Registry Editor Version 5.00
+//!
+//! [HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders]
+//! "Personal"="D:\\Documents"
+//! "#;
+//!
+//! let file = RegFile::parse(reg)?;
+//! let folders = ShellFolders::from_reg_file(&file).unwrap();
+//! let resolver = folders.to_snapshot(&Environment::new());
+//! let documents = resolver.resolve(KnownFolder::Documents);
+//! # Ok::<(), known_folders::registry::export::ParseError>(())
+//! ```
+//!
 //! [Known Folders]: https://learn.microsoft.com/en-us/windows/win32/shell/known-folders
-
+#![cfg_attr(
+    not(windows),
+    doc = "[`get_known_folder_path`]: https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath"
+)]
+#![cfg_attr(
+    not(windows),
+    doc = "[`SystemResolver`]: https://learn.microsoft.com/en-us/windows/win32/shell/known-folders"
+)]
 #![doc(html_root_url = "https://docs.rs/known-folders/1.1.0")]
 
 // Ensure code blocks in `README.md` compile
@@ -59,10 +91,59 @@
 #[doc = include_str!("../README.md")]
 mod readme {}
 
+mod alias;
+pub mod arch;
+pub mod autostart;
+mod bytes;
+mod cfb;
+mod definition;
+pub mod desktop_ini;
+#[cfg(feature = "display-names")]
+mod display_name;
+mod env;
+pub mod folder_descriptions;
+mod folder_id;
+mod guid;
+pub mod internet_shortcut;
+mod known_folder;
+pub mod library;
+pub mod offline;
+mod parsing_name;
+mod path;
+pub mod pidl;
+pub mod property_store;
+pub mod recent;
+pub mod recycle_bin;
+mod redact;
+pub mod registry;
+mod relative;
+mod resolver;
+pub mod saved_search;
+mod scope;
+pub mod shell_folders;
+pub mod shell_link;
+pub mod start_menu;
+#[cfg(test)]
+mod temp_dir;
+mod text;
+mod version;
 #[cfg(windows)]
 #[allow(clippy::too_many_lines)]
 mod win;
+mod xml;
 
+pub use self::alias::AliasResolver;
+pub use self::definition::{DefinitionResolver, KnownFolderDefinition};
+pub use self::env::Environment;
+pub use self::folder_id::FolderId;
+pub use self::guid::{Guid, ParseGuidError};
+pub use self::known_folder::KnownFolder;
+pub use self::parsing_name::{FolderLocation, ParsingName};
+pub use self::redact::Redactor;
+pub use self::relative::relativize;
+pub use self::resolver::{Resolver, Snapshot, SnapshotIter};
+pub use self::scope::{search_paths, Scope};
+pub use self::version::{ParseWindowsVersionError, WindowsVersion};
 #[cfg(windows)]
 pub use self::win::*;
 
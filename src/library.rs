@@ -0,0 +1,697 @@
+// src/library.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Library description files (`.library-ms`).
+//!
+//! Library known folders such as [`KnownFolder::DocumentsLibrary`] resolve
+//! to an XML library description file rather than a directory. The file
+//! lists the folders that are members of the library and which of them new
+//! files are saved to.
+//!
+//! [`Library`] parses library description files and [`member_folders`]
+//! resolves a library known folder to the folders it contains.
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::text;
+use crate::xml::{self, Element};
+use crate::{path, FolderId, Guid, KnownFolder, Resolver};
+
+/// The file names Windows gives the description files of the built-in
+/// libraries in the [`KnownFolder::Libraries`] folder.
+const LIBRARY_FILES: &[(KnownFolder, &str)] = &[
+    (KnownFolder::CameraRollLibrary, "CameraRoll.library-ms"),
+    (KnownFolder::DocumentsLibrary, "Documents.library-ms"),
+    (KnownFolder::MusicLibrary, "Music.library-ms"),
+    (KnownFolder::PicturesLibrary, "Pictures.library-ms"),
+    (KnownFolder::RecordedTVLibrary, "RecordedTV.library-ms"),
+    (
+        KnownFolder::SavedPicturesLibrary,
+        "SavedPictures.library-ms",
+    ),
+    (KnownFolder::VideosLibrary, "Videos.library-ms"),
+];
+
+// The member folders of the built-in libraries on a new installation, in
+// library order. The first member is the default save location.
+const LIBRARY_MEMBERS: &[(KnownFolder, &[KnownFolder])] = &[
+    (KnownFolder::CameraRollLibrary, &[KnownFolder::CameraRoll]),
+    (
+        KnownFolder::DocumentsLibrary,
+        &[KnownFolder::Documents, KnownFolder::PublicDocuments],
+    ),
+    (
+        KnownFolder::MusicLibrary,
+        &[KnownFolder::Music, KnownFolder::PublicMusic],
+    ),
+    (
+        KnownFolder::PicturesLibrary,
+        &[KnownFolder::Pictures, KnownFolder::PublicPictures],
+    ),
+    (
+        KnownFolder::SavedPicturesLibrary,
+        &[KnownFolder::SavedPictures],
+    ),
+    (
+        KnownFolder::VideosLibrary,
+        &[KnownFolder::Videos, KnownFolder::PublicVideos],
+    ),
+];
+
+impl KnownFolder {
+    /// Return the folders that the library known folder `self` is composed
+    /// of by default.
+    ///
+    /// This is static metadata describing a new Windows installation, so it
+    /// is available where `.library-ms` files are not. Users can add and
+    /// remove library locations; use [`member_folders`] to read the current
+    /// composition of a library. Returns an empty slice if `self` is not a
+    /// built-in library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(
+    ///     KnownFolder::DocumentsLibrary.library_members(),
+    ///     [KnownFolder::Documents, KnownFolder::PublicDocuments],
+    /// );
+    /// assert!(KnownFolder::Documents.library_members().is_empty());
+    /// ```
+    #[must_use]
+    pub fn library_members(self) -> &'static [Self] {
+        LIBRARY_MEMBERS
+            .iter()
+            .find(|&&(library, _)| library == self)
+            .map_or(&[], |&(_, members)| members)
+    }
+
+    /// Return the built-in library known folder that includes `self` by
+    /// default.
+    ///
+    /// This is the inverse of [`KnownFolder::library_members`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::PublicMusic.library_of(), Some(KnownFolder::MusicLibrary));
+    /// assert_eq!(KnownFolder::SavedPictures.library_of(), Some(KnownFolder::SavedPicturesLibrary));
+    /// assert_eq!(KnownFolder::Downloads.library_of(), None);
+    /// ```
+    #[must_use]
+    pub fn library_of(self) -> Option<Self> {
+        LIBRARY_MEMBERS
+            .iter()
+            .find(|&&(_, members)| members.contains(&self))
+            .map(|&(library, _)| library)
+    }
+}
+
+/// The kind of error that occurred while parsing a library description.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The file is not well-formed XML.
+    InvalidXml,
+    /// The root element is not `libraryDescription`.
+    NotALibrary,
+}
+
+/// Error returned when parsing a library description fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the decoded document where the error was
+    /// detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidXml => write!(f, "invalid XML at offset {}", self.offset),
+            ParseErrorKind::NotALibrary => f.write_str("not a library description"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error returned when reading a library description file fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+    /// The file is not a valid library description.
+    Parse {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying parse error.
+        error: ParseError,
+    },
+}
+
+impl Error {
+    /// The path of the file that could not be read.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Where a library member folder is located.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// A known folder, stored as a `knownfolder:{GUID}` URL.
+    KnownFolder(FolderId),
+    /// A file system path, such as `D:\Photos` or `\\server\share`.
+    Path(String),
+}
+
+/// A folder that is a member of a library, described by a
+/// `searchConnectorDescription` element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibraryLocation {
+    url: String,
+    description: Option<String>,
+    default_save_location: bool,
+    default_non_owner_save_location: bool,
+    supported: bool,
+}
+
+impl LibraryLocation {
+    fn from_element(element: &Element) -> Option<Self> {
+        let url = element.child("simpleLocation")?.child_text("url")?;
+        Some(Self {
+            url: url.to_owned(),
+            description: element.child_text("description").map(String::from),
+            default_save_location: parse_bool(element.child_text("isDefaultSaveLocation")),
+            default_non_owner_save_location: parse_bool(
+                element.child_text("isDefaultNonOwnerSaveLocation"),
+            ),
+            supported: element
+                .child_text("isSupported")
+                .map_or(true, |s| parse_bool(Some(s))),
+        })
+    }
+
+    /// The location URL as stored in the file, e.g.
+    /// `knownfolder:{FDD39AD0-238F-46AF-ADB4-6C85480369C7}` or `D:\Photos`.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The parsed location of the folder.
+    #[must_use]
+    pub fn location(&self) -> Location {
+        let known_folder = self
+            .url
+            .get(..12)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("knownfolder:"))
+            .and_then(|_| self.url[12..].parse::<Guid>().ok());
+        match known_folder {
+            Some(guid) => Location::KnownFolder(FolderId::from_guid(guid)),
+            None => Location::Path(self.url.clone()),
+        }
+    }
+
+    /// The description of the location, often an indirect string resource.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Return `true` if new files saved to the library by its owner are
+    /// saved to this location.
+    #[must_use]
+    pub fn is_default_save_location(&self) -> bool {
+        self.default_save_location
+    }
+
+    /// Return `true` if new files saved to the library by users other than
+    /// its owner are saved to this location.
+    #[must_use]
+    pub fn is_default_non_owner_save_location(&self) -> bool {
+        self.default_non_owner_save_location
+    }
+
+    /// Return `false` if the location is not supported by the library, for
+    /// example because it is not indexed.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// Resolve the location to a path.
+    ///
+    /// Known folder locations are resolved with `resolver`. File system
+    /// locations are returned as stored.
+    #[must_use]
+    pub fn resolve<R>(&self, resolver: &R) -> Option<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        match self.location() {
+            Location::KnownFolder(id) => resolver.resolve_id(id),
+            Location::Path(path) => Some(path.into()),
+        }
+    }
+}
+
+fn parse_bool(text: Option<&str>) -> bool {
+    matches!(text, Some(text) if text.eq_ignore_ascii_case("true") || text == "1")
+}
+
+/// A parsed library description file.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::library::{Library, Location};
+/// use known_folders::{FolderId, KnownFolder};
+///
+/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <libraryDescription xmlns="http://schemas.microsoft.com/windows/2009/library">
+///   <name>@shell32.dll,-34575</name>
+///   <ownerSID>S-1-5-21-1-2-3-1001</ownerSID>
+///   <templateInfo>
+///     <folderType>{7D49D726-3C21-4F05-99AA-FDC2C9474656}</folderType>
+///   </templateInfo>
+///   <searchConnectorDescriptionList>
+///     <searchConnectorDescription>
+///       <isDefaultSaveLocation>true</isDefaultSaveLocation>
+///       <simpleLocation>
+///         <url>knownfolder:{FDD39AD0-238F-46AF-ADB4-6C85480369C7}</url>
+///       </simpleLocation>
+///     </searchConnectorDescription>
+///     <searchConnectorDescription>
+///       <simpleLocation><url>D:\Shared Documents</url></simpleLocation>
+///     </searchConnectorDescription>
+///   </searchConnectorDescriptionList>
+/// </libraryDescription>"#;
+///
+/// let library = Library::parse_str(xml)?;
+/// assert_eq!(library.owner_sid(), Some("S-1-5-21-1-2-3-1001"));
+/// assert_eq!(library.locations().len(), 2);
+/// assert_eq!(
+///     library.default_save_location().map(|location| location.location()),
+///     Some(Location::KnownFolder(FolderId::Known(KnownFolder::Documents))),
+/// );
+/// # Ok::<(), known_folders::library::ParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Library {
+    name: Option<String>,
+    owner_sid: Option<String>,
+    version: Option<u32>,
+    pinned: bool,
+    icon: Option<String>,
+    folder_type: Option<Guid>,
+    locations: Vec<LibraryLocation>,
+}
+
+impl Library {
+    /// Parse the raw contents of a `.library-ms` file.
+    ///
+    /// The text encoding is detected from the byte order mark.
+    ///
+    /// # Errors
+    ///
+    /// If the file is not a well-formed library description, an error is
+    /// returned.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_str(&text::decode_text_file(bytes))
+    }
+
+    /// Parse the text of a `.library-ms` file.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a well-formed library description, an error is
+    /// returned.
+    pub fn parse_str(contents: &str) -> Result<Self, ParseError> {
+        let root = xml::parse(contents).map_err(|offset| ParseError {
+            kind: ParseErrorKind::InvalidXml,
+            offset,
+        })?;
+        if root.name != "libraryDescription" {
+            return Err(ParseError {
+                kind: ParseErrorKind::NotALibrary,
+                offset: 0,
+            });
+        }
+        let locations = root
+            .child("searchConnectorDescriptionList")
+            .into_iter()
+            .flat_map(|list| list.children_named("searchConnectorDescription"))
+            .filter_map(LibraryLocation::from_element)
+            .collect();
+        Ok(Self {
+            name: root.child_text("name").map(String::from),
+            owner_sid: root.child_text("ownerSID").map(String::from),
+            version: root.child_text("version").and_then(|v| v.parse().ok()),
+            pinned: parse_bool(root.child_text("isLibraryPinned")),
+            icon: root.child_text("iconReference").map(String::from),
+            folder_type: root
+                .child("templateInfo")
+                .and_then(|info| info.child_text("folderType"))
+                .and_then(|guid| guid.parse().ok()),
+            locations,
+        })
+    }
+
+    /// Read and parse the `.library-ms` file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a well-formed library
+    /// description, an error is returned.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| Error::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        Self::parse(&bytes).map_err(|error| Error::Parse {
+            path: path.to_owned(),
+            error,
+        })
+    }
+
+    /// The display name of the library, often an indirect string resource
+    /// like `@shell32.dll,-34575`.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The security identifier of the user who owns the library.
+    #[must_use]
+    pub fn owner_sid(&self) -> Option<&str> {
+        self.owner_sid.as_deref()
+    }
+
+    /// The version number of the library description, incremented each time
+    /// the library is modified.
+    #[must_use]
+    pub fn version(&self) -> Option<u32> {
+        self.version
+    }
+
+    /// Return `true` if the library is pinned to the navigation pane.
+    #[must_use]
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// The icon resource of the library.
+    #[must_use]
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// The folder type GUID that selects how Explorer displays the library,
+    /// e.g. `FOLDERTYPEID_Documents`.
+    #[must_use]
+    pub fn folder_type(&self) -> Option<Guid> {
+        self.folder_type
+    }
+
+    /// The member locations of the library, in the order they are listed.
+    #[must_use]
+    pub fn locations(&self) -> &[LibraryLocation] {
+        &self.locations
+    }
+
+    /// The location new files are saved to.
+    ///
+    /// This is the location marked as the default save location, or the
+    /// first location if none is marked.
+    #[must_use]
+    pub fn default_save_location(&self) -> Option<&LibraryLocation> {
+        self.locations
+            .iter()
+            .find(|location| location.default_save_location)
+            .or_else(|| self.locations.first())
+    }
+
+    /// Resolve every member location to a path, skipping locations that
+    /// `resolver` cannot resolve.
+    #[must_use]
+    pub fn member_folders<R>(&self, resolver: &R) -> Vec<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        self.locations
+            .iter()
+            .filter_map(|location| location.resolve(resolver))
+            .collect()
+    }
+}
+
+/// Return the path of the description file of the library known folder
+/// `library`.
+///
+/// The path is resolved with `resolver`. If the resolver does not locate the
+/// library itself, the built-in libraries are looked up by their default
+/// file name in the [`KnownFolder::Libraries`] folder.
+#[must_use]
+pub fn library_path<R>(resolver: &R, library: KnownFolder) -> Option<PathBuf>
+where
+    R: Resolver + ?Sized,
+{
+    if let Some(path) = resolver.resolve(library) {
+        return Some(path);
+    }
+    let file_name = LIBRARY_FILES
+        .iter()
+        .find(|&&(known_folder, _)| known_folder == library)
+        .map(|&(_, file_name)| file_name)?;
+    let libraries = resolver.resolve(KnownFolder::Libraries).or_else(|| {
+        resolver
+            .resolve(KnownFolder::RoamingAppData)
+            .map(|path| path::join(&path, r"Microsoft\Windows\Libraries"))
+    })?;
+    Some(path::join(&libraries, file_name))
+}
+
+/// Resolve the library known folder `library`, such as
+/// [`KnownFolder::DocumentsLibrary`], to its member folders.
+///
+/// The library description file is located with [`library_path`] and read
+/// from the host file system. Member folders are resolved with `resolver`.
+/// Returns an empty list if the library cannot be located. For the default
+/// composition of a built-in library, see [`KnownFolder::library_members`].
+///
+/// # Errors
+///
+/// If the library description file cannot be read or parsed, an error is
+/// returned.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::library::member_folders;
+/// use known_folders::{KnownFolder, SystemResolver};
+///
+/// for folder in member_folders(&SystemResolver, KnownFolder::PicturesLibrary)? {
+///     println!("{}", folder.display());
+/// }
+/// # Ok::<(), known_folders::library::Error>(())
+/// ```
+pub fn member_folders<R>(resolver: &R, library: KnownFolder) -> Result<Vec<PathBuf>, Error>
+where
+    R: Resolver + ?Sized,
+{
+    match library_path(resolver, library) {
+        Some(path) => Ok(Library::open(path)?.member_folders(resolver)),
+        None => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn library_members_round_trip() {
+        for &library in KnownFolder::ALL {
+            for &member in library.library_members() {
+                assert_eq!(member.library_of(), Some(library), "{member:?}");
+            }
+        }
+        for &(library, _) in LIBRARY_MEMBERS {
+            assert!(LIBRARY_FILES
+                .iter()
+                .any(|&(known_folder, _)| known_folder == library));
+        }
+    }
+
+    const DOCUMENTS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<libraryDescription xmlns="http://schemas.microsoft.com/windows/2009/library">
+  <name>@shell32.dll,-34575</name>
+  <ownerSID>S-1-5-21-1-2-3-1001</ownerSID>
+  <version>6</version>
+  <isLibraryPinned>true</isLibraryPinned>
+  <iconReference>imageres.dll,-1002</iconReference>
+  <templateInfo>
+    <folderType>{7d49d726-3c21-4f05-99aa-fdc2c9474656}</folderType>
+  </templateInfo>
+  <searchConnectorDescriptionList>
+    <searchConnectorDescription publisher="Microsoft" product="Windows">
+      <description>@shell32.dll,-34577</description>
+      <isDefaultNonOwnerSaveLocation>true</isDefaultNonOwnerSaveLocation>
+      <simpleLocation>
+        <url>knownfolder:{ED4824AF-DCE4-45A8-81E2-FC7965083634}</url>
+        <serialized>MBAAAEAFCAAA</serialized>
+      </simpleLocation>
+    </searchConnectorDescription>
+    <searchConnectorDescription>
+      <isDefaultSaveLocation>true</isDefaultSaveLocation>
+      <isSupported>false</isSupported>
+      <simpleLocation>
+        <url>knownfolder:{FDD39AD0-238F-46AF-ADB4-6C85480369C7}</url>
+      </simpleLocation>
+    </searchConnectorDescription>
+    <searchConnectorDescription>
+      <simpleLocation>
+        <url>\\fileserver\share</url>
+      </simpleLocation>
+    </searchConnectorDescription>
+    <searchConnectorDescription>
+      <description>no location</description>
+    </searchConnectorDescription>
+  </searchConnectorDescriptionList>
+</libraryDescription>
+"#;
+
+    #[test]
+    fn parse_library() {
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend(DOCUMENTS.encode_utf16().flat_map(u16::to_le_bytes));
+        let library = Library::parse(&utf16).unwrap();
+        assert_eq!(library.name(), Some("@shell32.dll,-34575"));
+        assert_eq!(library.version(), Some(6));
+        assert!(library.is_pinned());
+        assert_eq!(library.icon(), Some("imageres.dll,-1002"));
+        assert_eq!(
+            library.folder_type(),
+            Some(Guid::from_u128(0x7d49d726_3c21_4f05_99aa_fdc2c9474656))
+        );
+        let locations = library.locations();
+        assert_eq!(locations.len(), 3);
+        assert_eq!(
+            locations[0].location(),
+            Location::KnownFolder(FolderId::Known(KnownFolder::PublicDocuments))
+        );
+        assert!(locations[0].is_default_non_owner_save_location());
+        assert!(locations[0].is_supported());
+        assert!(!locations[1].is_supported());
+        assert_eq!(
+            locations[2].location(),
+            Location::Path(r"\\fileserver\share".to_owned())
+        );
+        assert_eq!(library.default_save_location(), Some(&locations[1]));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Library::parse_str("<libraryDescription>").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidXml);
+        let err = Library::parse_str("<searchConnectorDescription/>").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::NotALibrary);
+    }
+
+    #[test]
+    fn resolve_library_members() {
+        let temp_dir = TempDir::new("library");
+        let root = temp_dir.path();
+        let libraries = root.join("Microsoft").join("Windows").join("Libraries");
+        fs::create_dir_all(&libraries).unwrap();
+        fs::write(libraries.join("Documents.library-ms"), DOCUMENTS).unwrap();
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::RoamingAppData, root);
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        assert_eq!(
+            library_path(&snapshot, KnownFolder::DocumentsLibrary),
+            Some(libraries.join("Documents.library-ms"))
+        );
+        let members = member_folders(&snapshot, KnownFolder::DocumentsLibrary).unwrap();
+        assert_eq!(
+            members,
+            [
+                PathBuf::from(r"C:\Users\jsmith\Documents"),
+                PathBuf::from(r"\\fileserver\share")
+            ]
+        );
+        let err = member_folders(&snapshot, KnownFolder::MusicLibrary).unwrap_err();
+        assert!(matches!(err, Error::Io { .. }));
+        assert_eq!(
+            member_folders(&Snapshot::new(), KnownFolder::DocumentsLibrary).unwrap(),
+            Vec::<PathBuf>::new()
+        );
+    }
+}
@@ -0,0 +1,872 @@
+// src/offline.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Known folders of every user of an offline Windows installation.
+//!
+//! An [`OfflineImage`] reads the registry hives of a Windows volume that is
+//! mounted on the host, e.g. a disk image mounted at `/mnt/windows`. The
+//! `SOFTWARE` hive lists user profiles under [`PROFILE_LIST`] and records
+//! machine-wide folder locations; the `SYSTEM` hive records the system
+//! environment and the drive letters of the installation. Each user's
+//! folders are then read from the `NTUSER.DAT` hive in their profile
+//! directory.
+//!
+//! Windows paths are translated to host paths by mapping drive letters to
+//! host directories. The system drive is mapped to the mount root; other
+//! volumes can be mapped with [`OfflineImage::mount`].
+//!
+//! Registry transaction logs are not replayed. Hives that were not cleanly
+//! unloaded may be missing recent changes, see [`Hive::is_dirty`].
+
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::folder_descriptions::FolderDescriptions;
+use crate::registry::hive::{self, Hive};
+use crate::registry::Value;
+use crate::shell_folders::{HiveResolver, ShellFolders};
+use crate::text;
+use crate::{Environment, FolderId, KnownFolder, Resolver, Snapshot};
+
+/// The registry key path, relative to the root of the `SOFTWARE` hive, of
+/// the user profile list.
+pub const PROFILE_LIST: &str = r"Microsoft\Windows NT\CurrentVersion\ProfileList";
+
+/// The path of the registry hive directory relative to the system root.
+const CONFIG_DIR: &str = r"System32\config";
+
+const CURRENT_VERSION: &str = r"Microsoft\Windows\CurrentVersion";
+const NT_CURRENT_VERSION: &str = r"Microsoft\Windows NT\CurrentVersion";
+const COMMON_SHELL_FOLDERS: &str = r"Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders";
+const SESSION_ENVIRONMENT: &str = r"Control\Session Manager\Environment";
+
+/// Machine-wide folders and the environment variables that locate them,
+/// listed under the `ProfileList` key.
+const PROFILE_DIRS: &[(&str, KnownFolder, &[&str])] = &[
+    (
+        "ProgramData",
+        KnownFolder::ProgramData,
+        &["ProgramData", "ALLUSERSPROFILE"],
+    ),
+    ("Public", KnownFolder::Public, &["PUBLIC"]),
+    ("ProfilesDirectory", KnownFolder::UserProfiles, &[]),
+];
+
+/// Program Files folders and the environment variables that locate them,
+/// listed under the `CurrentVersion` key.
+const PROGRAM_FILES_DIRS: &[(&str, KnownFolder, &str)] = &[
+    ("ProgramFilesDir", KnownFolder::ProgramFiles, "ProgramFiles"),
+    (
+        "ProgramFilesDir (x86)",
+        KnownFolder::ProgramFilesX86,
+        "ProgramFiles(x86)",
+    ),
+    (
+        "ProgramW6432Dir",
+        KnownFolder::ProgramFilesX64,
+        "ProgramW6432",
+    ),
+    (
+        "CommonFilesDir",
+        KnownFolder::ProgramFilesCommon,
+        "CommonProgramFiles",
+    ),
+    (
+        "CommonFilesDir (x86)",
+        KnownFolder::ProgramFilesCommonX86,
+        "CommonProgramFiles(x86)",
+    ),
+    (
+        "CommonW6432Dir",
+        KnownFolder::ProgramFilesCommonX64,
+        "CommonProgramW6432",
+    ),
+];
+
+/// Error returned when reading an offline Windows installation fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// A file could not be read from the mounted volume.
+    Io {
+        /// The host path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+    /// A registry hive is malformed.
+    Hive {
+        /// The host path of the hive file.
+        path: PathBuf,
+        /// The underlying parse error.
+        error: hive::ParseError,
+    },
+}
+
+impl Error {
+    /// The host path of the file that could not be read.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Hive { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Hive { path, error } => write!(f, "failed to parse {}: {error}", path.display()),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Hive { error, .. } => Some(error),
+        }
+    }
+}
+
+/// Read and parse the hive file at `path`.
+fn read_hive(path: &Path) -> Result<Hive, Error> {
+    let data = fs::read(path).map_err(|error| Error::Io {
+        path: path.to_owned(),
+        error,
+    })?;
+    Hive::parse(data).map_err(|error| Error::Hive {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Read a string value, expanding `REG_EXPAND_SZ` data against `env`.
+fn read_string(
+    key: Option<&hive::Key<'_>>,
+    name: &str,
+    env: &Environment,
+) -> Result<Option<String>, hive::ParseError> {
+    let value = match key {
+        Some(key) => key.value(name)?,
+        None => return Ok(None),
+    };
+    Ok(match value {
+        Some(Value::String(value)) => Some(value),
+        Some(Value::ExpandString(value)) => Some(env.expand(&value)),
+        _ => None,
+    })
+}
+
+/// Return the uppercase drive letter of an absolute Windows path like
+/// `C:\Windows`.
+fn drive_letter(path: &str) -> Option<char> {
+    let path = path.strip_prefix(r"\\?\").unwrap_or(path);
+    let mut chars = path.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), Some(':')) if letter.is_ascii_alphabetic() => {
+            Some(letter.to_ascii_uppercase())
+        }
+        _ => None,
+    }
+}
+
+/// Join the components of a Windows path to `base`, matching each component
+/// against existing host directory entries without regard to case.
+///
+/// Components that do not exist on the host are joined as given.
+fn join_case_insensitive(base: &Path, relative: &str) -> PathBuf {
+    let mut path = base.to_owned();
+    let mut exists = true;
+    for component in relative.split(['\\', '/']) {
+        match component {
+            "" | "." => continue,
+            ".." => {
+                if path != base {
+                    path.pop();
+                }
+                continue;
+            }
+            _ => {}
+        }
+        let exact = path.join(component);
+        if exists && !exact.exists() {
+            let found = fs::read_dir(&path).ok().and_then(|entries| {
+                entries.filter_map(Result::ok).find(|entry| {
+                    entry
+                        .file_name()
+                        .to_str()
+                        .map_or(false, |name| text::eq_ignore_case(name, component))
+                })
+            });
+            if let Some(entry) = found {
+                path = entry.path();
+                continue;
+            }
+            exists = false;
+        }
+        path = exact;
+    }
+    path
+}
+
+/// The parts of the `SYSTEM` hive an [`OfflineImage`] is built from.
+#[derive(Debug, Clone)]
+struct SystemHive {
+    session_environment: Vec<(String, Value)>,
+    drive_letters: Vec<char>,
+}
+
+impl SystemHive {
+    fn read(system: &Hive) -> Result<Self, hive::ParseError> {
+        Ok(Self {
+            session_environment: read_session_environment(system)?,
+            drive_letters: read_drive_letters(system)?,
+        })
+    }
+
+    /// Overlay the system environment variables onto `env`.
+    fn apply_environment(&self, env: &mut Environment) {
+        for (name, data) in &self.session_environment {
+            let value = match data {
+                Value::String(value) => value.clone(),
+                Value::ExpandString(value) => env.expand(value),
+                _ => continue,
+            };
+            env.insert(name.as_str(), value);
+        }
+    }
+}
+
+/// Read the system environment variables of the current control set of the
+/// `SYSTEM` hive.
+fn read_session_environment(system: &Hive) -> Result<Vec<(String, Value)>, hive::ParseError> {
+    let control_set = system
+        .open_key("Select")?
+        .map(|key| key.value("Current"))
+        .transpose()?
+        .flatten()
+        .and_then(|value| value.as_u32())
+        .unwrap_or(1);
+    let session_environment = system.open_key(&format!(
+        r"ControlSet{control_set:03}\{SESSION_ENVIRONMENT}"
+    ))?;
+    let mut vars = Vec::new();
+    if let Some(key) = session_environment {
+        for entry in key.values()? {
+            vars.push((entry.name().to_owned(), entry.data().clone()));
+        }
+    }
+    Ok(vars)
+}
+
+/// Read the drive letters assigned in the `MountedDevices` key of the
+/// `SYSTEM` hive.
+fn read_drive_letters(system: &Hive) -> Result<Vec<char>, hive::ParseError> {
+    let mut drive_letters = Vec::new();
+    if let Some(key) = system.open_key("MountedDevices")? {
+        for entry in key.values()? {
+            let letter = entry
+                .name()
+                .strip_prefix(r"\DosDevices\")
+                .and_then(drive_letter);
+            if let Some(letter) = letter {
+                drive_letters.push(letter);
+            }
+        }
+    }
+    drive_letters.sort_unstable();
+    drive_letters.dedup();
+    Ok(drive_letters)
+}
+
+/// Read the profiles registered under the `ProfileList` key.
+fn read_profiles(
+    profile_list: &hive::Key<'_>,
+    env: &Environment,
+) -> Result<Vec<Profile>, hive::ParseError> {
+    let mut profiles = Vec::new();
+    for subkey in profile_list.subkeys()? {
+        let sid = subkey.name();
+        // Profiles that failed to load are backed up with a `.bak`
+        // suffix and are not used by Windows.
+        if sid.to_ascii_lowercase().ends_with(".bak") {
+            continue;
+        }
+        if let Some(path) = read_string(Some(&subkey), "ProfileImagePath", env)? {
+            profiles.push(Profile { sid, path });
+        }
+    }
+    Ok(profiles)
+}
+
+/// A user profile registered in the `ProfileList` key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    sid: String,
+    path: String,
+}
+
+impl Profile {
+    /// The security identifier of the profile's user, e.g.
+    /// `S-1-5-21-…-1001`.
+    #[must_use]
+    pub fn sid(&self) -> &str {
+        &self.sid
+    }
+
+    /// The Windows path of the profile directory, with environment variable
+    /// references expanded.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// A Windows installation mounted on the host.
+///
+/// # Examples
+///
+/// ```no_run
+/// use known_folders::offline::OfflineImage;
+/// use known_folders::{KnownFolder, Resolver};
+///
+/// let image = OfflineImage::open("/mnt/windows")?;
+/// for user in image.users() {
+///     let documents = user.resolve(KnownFolder::Documents);
+///     println!("{}: {:?}", user.profile().sid(), documents);
+/// }
+/// # Ok::<(), known_folders::offline::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct OfflineImage {
+    root: PathBuf,
+    system_drive: char,
+    drive_letters: Vec<char>,
+    mounts: BTreeMap<char, PathBuf>,
+    environment: Environment,
+    machine_folders: Snapshot,
+    common_folders: ShellFolders,
+    folder_descriptions: FolderDescriptions,
+    default_profile: Option<String>,
+    profiles: Vec<Profile>,
+}
+
+impl OfflineImage {
+    /// Read the Windows installation whose system volume is mounted at
+    /// `root`.
+    ///
+    /// The `SOFTWARE` and `SYSTEM` hives are read from
+    /// `Windows\System32\config`. Path components are matched without regard
+    /// to case.
+    ///
+    /// # Errors
+    ///
+    /// If either hive cannot be read or is malformed, an error is returned.
+    pub fn open<P>(root: P) -> Result<Self, Error>
+    where
+        P: Into<PathBuf>,
+    {
+        let root = root.into();
+        let config = join_case_insensitive(&root, &format!(r"Windows\{CONFIG_DIR}"));
+        let software_path = join_case_insensitive(&config, "SOFTWARE");
+        let system_path = join_case_insensitive(&config, "SYSTEM");
+        let software = read_hive(&software_path)?;
+        let system = read_hive(&system_path)?;
+        let system = SystemHive::read(&system).map_err(|error| Error::Hive {
+            path: system_path,
+            error,
+        })?;
+        Self::from_software_hive(root, &software, &system).map_err(|error| Error::Hive {
+            path: software_path,
+            error,
+        })
+    }
+
+    /// Build from already parsed `SOFTWARE` and `SYSTEM` hives of the
+    /// Windows installation whose system volume is mounted at `root`.
+    ///
+    /// # Errors
+    ///
+    /// If either hive is malformed, an error is returned.
+    pub fn from_hives<P>(root: P, software: &Hive, system: &Hive) -> Result<Self, hive::ParseError>
+    where
+        P: Into<PathBuf>,
+    {
+        let system = SystemHive::read(system)?;
+        Self::from_software_hive(root, software, &system)
+    }
+
+    fn from_software_hive<P>(
+        root: P,
+        software: &Hive,
+        system: &SystemHive,
+    ) -> Result<Self, hive::ParseError>
+    where
+        P: Into<PathBuf>,
+    {
+        let nt_current_version = software.open_key(NT_CURRENT_VERSION)?;
+        let system_root = read_string(
+            nt_current_version.as_ref(),
+            "SystemRoot",
+            &Environment::new(),
+        )?
+        .unwrap_or_else(|| r"C:\Windows".to_owned());
+        let system_drive = drive_letter(&system_root).unwrap_or('C');
+
+        let mut env = Environment::new();
+        env.insert("SystemDrive", format!("{system_drive}:"));
+        env.insert("SystemRoot", system_root.as_str());
+        env.insert("windir", system_root.as_str());
+
+        let mut machine_folders = Snapshot::new();
+        machine_folders.insert(KnownFolder::Windows, system_root.as_str());
+        machine_folders.insert(KnownFolder::System, format!(r"{system_root}\System32"));
+        machine_folders.insert(KnownFolder::Fonts, format!(r"{system_root}\Fonts"));
+        machine_folders.insert(
+            KnownFolder::ResourceDir,
+            format!(r"{system_root}\resources"),
+        );
+
+        let profile_list = software.open_key(PROFILE_LIST)?;
+        for &(name, known_folder, vars) in PROFILE_DIRS {
+            if let Some(path) = read_string(profile_list.as_ref(), name, &env)? {
+                for &var in vars {
+                    env.insert(var, path.as_str());
+                }
+                machine_folders.insert(known_folder, path);
+            }
+        }
+
+        let default_profile = read_string(profile_list.as_ref(), "Default", &env)?;
+
+        let current_version = software.open_key(CURRENT_VERSION)?;
+        for &(name, known_folder, var) in PROGRAM_FILES_DIRS {
+            if let Some(path) = read_string(current_version.as_ref(), name, &env)? {
+                env.insert(var, path.as_str());
+                machine_folders.insert(known_folder, path);
+            }
+        }
+        // On 32-bit Windows the x86 folders are the native folders.
+        if machine_folders.get(KnownFolder::ProgramFilesX86).is_some() {
+            machine_folders.insert(KnownFolder::SystemX86, format!(r"{system_root}\SysWOW64"));
+        } else {
+            machine_folders.insert(KnownFolder::SystemX86, format!(r"{system_root}\System32"));
+            for &(native, x86) in &[
+                (KnownFolder::ProgramFiles, KnownFolder::ProgramFilesX86),
+                (
+                    KnownFolder::ProgramFilesCommon,
+                    KnownFolder::ProgramFilesCommonX86,
+                ),
+            ] {
+                if let Some(path) = machine_folders.get(native).map(Path::to_path_buf) {
+                    machine_folders.insert(x86, path);
+                }
+            }
+        }
+
+        system.apply_environment(&mut env);
+        let drive_letters = system.drive_letters.clone();
+
+        let common_folders = match software.open_key(COMMON_SHELL_FOLDERS)? {
+            Some(key) => ShellFolders::from_hive_key(&key)?,
+            None => ShellFolders::default(),
+        };
+
+        let folder_descriptions = FolderDescriptions::from_hive(software)?;
+
+        let profiles = match profile_list {
+            Some(key) => read_profiles(&key, &env)?,
+            None => Vec::new(),
+        };
+
+        let root = root.into();
+        let mut mounts = BTreeMap::new();
+        mounts.insert(system_drive, root.clone());
+        Ok(Self {
+            root,
+            system_drive,
+            drive_letters,
+            mounts,
+            environment: env,
+            machine_folders,
+            common_folders,
+            folder_descriptions,
+            default_profile,
+            profiles,
+        })
+    }
+
+    /// The host directory the system volume is mounted at.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The drive letter of the system volume, e.g. `'C'`.
+    #[must_use]
+    pub fn system_drive(&self) -> char {
+        self.system_drive
+    }
+
+    /// The drive letters assigned to volumes in the `MountedDevices` key of
+    /// the `SYSTEM` hive, in alphabetical order.
+    #[must_use]
+    pub fn drive_letters(&self) -> &[char] {
+        &self.drive_letters
+    }
+
+    /// Map the volume with drive letter `letter` to the host directory
+    /// `path`, so Windows paths on that volume can be translated.
+    ///
+    /// If the drive letter was already mapped, the old host directory is
+    /// returned.
+    pub fn mount<P>(&mut self, letter: char, path: P) -> Option<PathBuf>
+    where
+        P: Into<PathBuf>,
+    {
+        self.mounts.insert(letter.to_ascii_uppercase(), path.into())
+    }
+
+    /// Translate an absolute Windows path like `C:\Users\jsmith` to a path on
+    /// the host.
+    ///
+    /// Returns [`None`] if the path is not on a mapped drive, e.g. if it is
+    /// on a network share.
+    #[must_use]
+    pub fn host_path(&self, path: &str) -> Option<PathBuf> {
+        let letter = drive_letter(path)?;
+        let base = self.mounts.get(&letter)?;
+        let path = path.strip_prefix(r"\\?\").unwrap_or(path);
+        Some(join_case_insensitive(base, &path[2..]))
+    }
+
+    /// The machine environment, as seen by processes started on the offline
+    /// installation before any user-specific variables are applied.
+    #[must_use]
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// The Windows path of the default profile that new user profiles are
+    /// copied from, e.g. `C:\Users\Default`.
+    #[must_use]
+    pub fn default_profile(&self) -> Option<&str> {
+        self.default_profile.as_deref()
+    }
+
+    /// The user profiles registered with the installation.
+    #[must_use]
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    /// Resolve the known folders of every user whose `NTUSER.DAT` hive is
+    /// present in their profile directory.
+    ///
+    /// Profiles whose directory is not on a mapped drive or that have no
+    /// `NTUSER.DAT` are skipped, as are profiles whose hive cannot be read
+    /// or is malformed. To see why a profile is missing, read its hive with
+    /// [`Hive::parse`] and pass it to [`user`](Self::user).
+    #[must_use]
+    pub fn users(&self) -> Vec<ProfileResolver> {
+        let mut users = Vec::with_capacity(self.profiles.len());
+        for profile in &self.profiles {
+            let path = match self.host_path(&format!(r"{}\NTUSER.DAT", profile.path)) {
+                Some(path) if path.is_file() => path,
+                _ => continue,
+            };
+            let user = read_hive(&path)
+                .ok()
+                .and_then(|hive| self.user(profile, &hive).ok());
+            users.extend(user);
+        }
+        users
+    }
+
+    /// Resolve the known folders of the user with `profile` from their
+    /// already parsed `NTUSER.DAT` hive.
+    ///
+    /// Folders that are not recorded in the user or machine hives resolve
+    /// to their default location from the folder descriptions in the
+    /// `SOFTWARE` hive, like `%USERPROFILE%\Downloads`.
+    ///
+    /// # Errors
+    ///
+    /// If the hive is malformed, an error is returned.
+    pub fn user(
+        &self,
+        profile: &Profile,
+        hive: &Hive,
+    ) -> Result<ProfileResolver, hive::ParseError> {
+        let mut env = self.environment.clone();
+        env.insert("USERPROFILE", profile.path.as_str());
+        if let Some(letter) = drive_letter(&profile.path) {
+            let path = profile.path.strip_prefix(r"\\?\").unwrap_or(&profile.path);
+            env.insert("HOMEDRIVE", format!("{letter}:"));
+            env.insert("HOMEPATH", &path[2..]);
+        }
+        if let Some(name) = profile.path.rsplit('\\').next() {
+            env.insert("USERNAME", name);
+        }
+
+        let user_folders = HiveResolver::from_hive(hive, &env)?;
+        let env = user_folders.environment();
+        let mut windows_paths = self.machine_folders.clone();
+        windows_paths.extend(&self.common_folders.to_snapshot(env));
+        windows_paths.insert(KnownFolder::Profile, profile.path.as_str());
+        windows_paths.extend(&Snapshot::capture(&user_folders));
+        // Folders that are not recorded anywhere are at their default
+        // location relative to their parent folder.
+        let defaults = KnownFolder::ALL
+            .iter()
+            .filter(|&&known_folder| windows_paths.get(known_folder).is_none())
+            .filter_map(|&known_folder| {
+                let path = self
+                    .folder_descriptions
+                    .resolve(FolderId::Known(known_folder), &windows_paths)?;
+                Some((known_folder, path))
+            })
+            .collect::<Vec<_>>();
+        windows_paths.extend(defaults);
+
+        let host_paths = windows_paths
+            .iter()
+            .filter_map(|(known_folder, path)| {
+                let path = self.host_path(path.to_str()?)?;
+                Some((known_folder, path))
+            })
+            .collect();
+        Ok(ProfileResolver {
+            profile: profile.clone(),
+            windows_paths,
+            host_paths,
+        })
+    }
+}
+
+/// A [`Resolver`] for the known folders of one user of an [`OfflineImage`].
+///
+/// Resolved paths are host paths under the mount. Folders on volumes that
+/// are not mapped to a host directory, such as folders redirected to a
+/// network share, do not resolve; their Windows paths are available from
+/// [`ProfileResolver::windows_paths`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileResolver {
+    profile: Profile,
+    windows_paths: Snapshot,
+    host_paths: Snapshot,
+}
+
+impl ProfileResolver {
+    /// The user profile whose folders are resolved.
+    #[must_use]
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+
+    /// The user's known folders as Windows paths, as the Known Folders API
+    /// would return them on the running installation.
+    #[must_use]
+    pub fn windows_paths(&self) -> &Snapshot {
+        &self.windows_paths
+    }
+
+    /// The user's known folders as host paths.
+    #[must_use]
+    pub fn host_paths(&self) -> &Snapshot {
+        &self.host_paths
+    }
+}
+
+impl Resolver for ProfileResolver {
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.host_paths.resolve(known_folder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::folder_descriptions::FOLDER_DESCRIPTIONS;
+    use crate::registry::test_hive::{HiveBuilder, TestKey};
+    use crate::registry::REG_BINARY;
+    use crate::shell_folders::{ENVIRONMENT, USER_SHELL_FOLDERS};
+    use crate::temp_dir::TempDir;
+
+    fn software() -> Vec<u8> {
+        let mut root = TestKey::new("ROOT");
+        root.key(NT_CURRENT_VERSION).sz("SystemRoot", r"C:\WINDOWS");
+        root.key(CURRENT_VERSION)
+            .sz("ProgramFilesDir", r"C:\Program Files")
+            .sz("ProgramFilesDir (x86)", r"C:\Program Files (x86)")
+            .sz("CommonFilesDir", r"C:\Program Files\Common Files");
+        root.key(COMMON_SHELL_FOLDERS)
+            .expand_sz("Common Documents", r"%PUBLIC%\Documents")
+            .expand_sz("Common AppData", r"%ProgramData%");
+        root.key(&format!(
+            r"{FOLDER_DESCRIPTIONS}\{{374DE290-123F-4565-9164-39C4925E467B}}"
+        ))
+        .sz("Name", "Downloads")
+        .dword("Category", 4)
+        .sz("ParentFolder", "{5E6C858F-0E22-4760-9AFE-EA3317B67173}")
+        .sz("RelativePath", "Downloads");
+        root.key(PROFILE_LIST)
+            .expand_sz("ProfilesDirectory", r"%SystemDrive%\Users")
+            .expand_sz("ProgramData", r"%SystemDrive%\ProgramData")
+            .expand_sz("Public", r"%SystemDrive%\Users\Public")
+            .expand_sz("Default", r"%SystemDrive%\Users\Default");
+        root.key(&format!(r"{PROFILE_LIST}\S-1-5-18")).expand_sz(
+            "ProfileImagePath",
+            r"%systemroot%\system32\config\systemprofile",
+        );
+        root.key(&format!(r"{PROFILE_LIST}\S-1-5-21-1-2-3-1001"))
+            .expand_sz("ProfileImagePath", r"C:\Users\jsmith");
+        root.key(&format!(r"{PROFILE_LIST}\S-1-5-21-1-2-3-1001.bak"))
+            .expand_sz("ProfileImagePath", r"C:\Users\TEMP");
+        HiveBuilder::new().build(&root)
+    }
+
+    fn system() -> Vec<u8> {
+        let mut root = TestKey::new("ROOT");
+        root.key("Select").dword("Current", 2);
+        root.key(&format!(r"ControlSet001\{SESSION_ENVIRONMENT}"))
+            .sz("TEMP", r"C:\Stale");
+        root.key(&format!(r"ControlSet002\{SESSION_ENVIRONMENT}"))
+            .expand_sz("TEMP", r"%SystemRoot%\TEMP");
+        root.key("MountedDevices")
+            .raw(r"\DosDevices\D:", REG_BINARY, vec![0; 12])
+            .raw(r"\DosDevices\C:", REG_BINARY, vec![0; 12])
+            .raw(
+                r"\??\Volume{00000000-0000-0000-0000-000000000000}",
+                REG_BINARY,
+                vec![0; 12],
+            );
+        HiveBuilder::new().build(&root)
+    }
+
+    fn ntuser() -> Vec<u8> {
+        let mut root = TestKey::new("ROOT");
+        root.key(USER_SHELL_FOLDERS)
+            .expand_sz("Personal", r"%USERPROFILE%\Documents")
+            .expand_sz("My Music", r"D:\%USERNAME%\Music")
+            .expand_sz("My Video", r"\\fileserver\videos");
+        root.key(ENVIRONMENT)
+            .expand_sz("TEMP", r"%USERPROFILE%\AppData\Local\Temp");
+        HiveBuilder::new().build(&root)
+    }
+
+    #[test]
+    fn resolve_image() {
+        let temp_dir = TempDir::new("offline");
+        let root = temp_dir.path();
+        let config = root.join("windows").join("System32").join("CONFIG");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(config.join("SOFTWARE"), software()).unwrap();
+        fs::write(config.join("SYSTEM"), system()).unwrap();
+        let profile = root.join("Users").join("JSmith");
+        fs::create_dir_all(profile.join("Documents")).unwrap();
+        fs::write(profile.join("ntuser.dat"), ntuser()).unwrap();
+        // A corrupt hive skips only its own profile.
+        let system_profile = config.join("systemprofile");
+        fs::create_dir_all(&system_profile).unwrap();
+        fs::write(system_profile.join("NTUSER.DAT"), b"not a hive").unwrap();
+
+        let mut image = OfflineImage::open(root).unwrap();
+        assert_eq!(image.system_drive(), 'C');
+        assert_eq!(image.default_profile(), Some(r"C:\Users\Default"));
+        assert_eq!(image.drive_letters(), &['C', 'D']);
+        assert_eq!(image.environment().get("TEMP"), Some(r"C:\WINDOWS\TEMP"));
+        let sids = image
+            .profiles()
+            .iter()
+            .map(Profile::sid)
+            .collect::<Vec<_>>();
+        assert_eq!(sids, ["S-1-5-18", "S-1-5-21-1-2-3-1001"]);
+        assert_eq!(
+            image.profiles()[0].path(),
+            r"C:\WINDOWS\system32\config\systemprofile"
+        );
+        image.mount('d', "/mnt/data");
+
+        let users = image.users();
+        assert_eq!(users.len(), 1);
+        let user = &users[0];
+        assert_eq!(user.profile().sid(), "S-1-5-21-1-2-3-1001");
+
+        let windows = user.windows_paths();
+        assert_eq!(
+            windows.get(KnownFolder::Profile),
+            Some(Path::new(r"C:\Users\jsmith"))
+        );
+        assert_eq!(
+            windows.get(KnownFolder::Documents),
+            Some(Path::new(r"C:\Users\jsmith\Documents"))
+        );
+        assert_eq!(
+            windows.get(KnownFolder::PublicDocuments),
+            Some(Path::new(r"C:\Users\Public\Documents"))
+        );
+        assert_eq!(
+            windows.get(KnownFolder::SystemX86),
+            Some(Path::new(r"C:\WINDOWS\SysWOW64"))
+        );
+        assert_eq!(
+            windows.get(KnownFolder::Videos),
+            Some(Path::new(r"\\fileserver\videos"))
+        );
+
+        assert_eq!(user.resolve(KnownFolder::Profile), Some(profile.clone()));
+        assert_eq!(
+            user.resolve(KnownFolder::Documents),
+            Some(profile.join("Documents"))
+        );
+        assert_eq!(
+            user.resolve(KnownFolder::System),
+            Some(root.join("windows").join("System32"))
+        );
+        assert_eq!(
+            user.resolve(KnownFolder::Music),
+            Some(Path::new("/mnt/data").join("jsmith").join("Music"))
+        );
+        assert_eq!(user.resolve(KnownFolder::Videos), None);
+        assert_eq!(
+            windows.get(KnownFolder::Downloads),
+            Some(Path::new(r"C:\Users\jsmith\Downloads"))
+        );
+        assert_eq!(
+            user.resolve(KnownFolder::Downloads),
+            Some(profile.join("Downloads"))
+        );
+    }
+
+    #[test]
+    fn host_path_translation() {
+        let image = OfflineImage::from_hives(
+            "/mnt/windows",
+            &Hive::parse(software()).unwrap(),
+            &Hive::parse(system()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            image.host_path(r"\\?\c:\Users\.\Public\..\jsmith"),
+            Some(Path::new("/mnt/windows").join("Users").join("jsmith"))
+        );
+        assert_eq!(image.host_path(r"D:\Data"), None);
+        assert_eq!(image.host_path(r"\\server\share"), None);
+        assert_eq!(image.host_path("relative"), None);
+    }
+}
@@ -0,0 +1,186 @@
+// src/parsing_name.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shell namespace parsing names of virtual known folders.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::{Guid, KnownFolder};
+
+/// The shell namespace parsing name of a folder, like
+/// `::{645FF040-5081-101B-9F08-08002B30309D}` for the Recycle Bin.
+///
+/// Parsing names address folders that have no file system path. They can be
+/// passed to `explorer.exe` or prefixed with `shell:` to open the folder.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{Guid, KnownFolder};
+///
+/// let name = KnownFolder::RecycleBinFolder.parsing_name().unwrap();
+/// assert_eq!(name.as_str(), "::{645FF040-5081-101B-9F08-08002B30309D}");
+/// assert_eq!(name.to_shell_uri(), "shell:::{645FF040-5081-101B-9F08-08002B30309D}");
+/// assert_eq!(
+///     name.clsids().collect::<Vec<_>>(),
+///     [Guid::from_u128(0x645ff040_5081_101b_9f08_08002b30309d)],
+/// );
+/// ```
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ParsingName(Cow<'static, str>);
+
+impl ParsingName {
+    /// Construct a parsing name from its string form.
+    #[must_use]
+    pub fn new<S>(name: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self(name.into())
+    }
+
+    /// Return the string form of this parsing name.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return this parsing name as a `shell:` URI, which Explorer and
+    /// `ShellExecute` accept.
+    #[must_use]
+    pub fn to_shell_uri(&self) -> String {
+        format!("shell:{}", self.0)
+    }
+
+    /// Iterate over the CLSIDs of the `::{CLSID}` segments of this parsing
+    /// name, outermost first.
+    pub fn clsids(&self) -> impl Iterator<Item = Guid> + '_ {
+        self.0
+            .split('\\')
+            .filter_map(|segment| segment.strip_prefix("::")?.parse().ok())
+    }
+}
+
+impl fmt::Display for ParsingName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The location of a known folder: either a file system path or, for
+/// virtual folders, a shell namespace parsing name.
+///
+/// Returned by [`Resolver::locate`](crate::Resolver::locate).
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum FolderLocation {
+    /// A folder on the file system.
+    FileSystem(PathBuf),
+    /// A virtual folder in the shell namespace.
+    Virtual(ParsingName),
+}
+
+impl KnownFolder {
+    /// Return the shell namespace parsing name of this known folder if it is
+    /// a virtual folder.
+    ///
+    /// Virtual folders like [`ComputerFolder`] and [`RecycleBinFolder`] have
+    /// no file system path, so resolvers return [`None`] for them. Their
+    /// parsing name addresses them in the shell namespace instead. Returns
+    /// [`None`] for file system folders.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(
+    ///     KnownFolder::ComputerFolder.parsing_name().unwrap().as_str(),
+    ///     "::{20D04FE0-3AEA-1069-A2D8-08002B30309D}",
+    /// );
+    /// assert_eq!(KnownFolder::Downloads.parsing_name(), None);
+    /// ```
+    ///
+    /// [`ComputerFolder`]: Self::ComputerFolder
+    /// [`RecycleBinFolder`]: Self::RecycleBinFolder
+    #[must_use]
+    pub fn parsing_name(self) -> Option<ParsingName> {
+        let name = match self {
+            Self::AddNewPrograms => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{15EAE92E-F17A-4431-9F28-805E482DAFD4}"
+            }
+            Self::AppUpdates => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{7B81BE6A-CE2B-4676-A29E-EB907A5126C5}\::{D450A8A1-9568-45C7-9C0E-B4F9FB4537BD}"
+            }
+            Self::AppsFolder => "::{4234D49B-0245-4DF3-B780-3893943456E1}",
+            Self::ChangeRemovePrograms => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{7B81BE6A-CE2B-4676-A29E-EB907A5126C5}"
+            }
+            Self::ComputerFolder => "::{20D04FE0-3AEA-1069-A2D8-08002B30309D}",
+            Self::ConflictFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{9C73F5E5-7AE7-4E32-A8E8-8D23B85255BF}\::{E413D040-6788-4C22-957E-175D1C513A34}"
+            }
+            Self::ConnectionsFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{7007ACC7-3202-11D1-AAD2-00805FC1270E}"
+            }
+            Self::ControlPanelFolder => "::{26EE0668-A00A-44D7-9371-BEB064C98683}",
+            Self::Games => "::{ED228FDF-9EA8-4870-83B1-96B02CFE0D52}",
+            Self::HomeGroup => "::{B4FB3F98-C1EA-428D-A78A-D1F5659CBA93}",
+            Self::InternetFolder => "::{871C5380-42A0-1069-A2EA-08002B30309D}",
+            Self::NetworkFolder => "::{F02C1A0D-BE21-4350-88B0-7367FC96EF3C}",
+            Self::PrintersFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{2227A280-3AEA-1069-A2DE-08002B30309D}"
+            }
+            Self::RecycleBinFolder => "::{645FF040-5081-101B-9F08-08002B30309D}",
+            Self::SearchHome => "::{9343812E-1C37-4A49-A12E-4B2D810D956B}",
+            Self::SyncManagerFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{9C73F5E5-7AE7-4E32-A8E8-8D23B85255BF}"
+            }
+            Self::SyncResultsFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{9C73F5E5-7AE7-4E32-A8E8-8D23B85255BF}\::{BC48B32F-5910-47F5-8570-5074A8A5636A}"
+            }
+            Self::SyncSetupFolder => {
+                r"::{21EC2020-3AEA-1069-A2DD-08002B30309D}\::{9C73F5E5-7AE7-4E32-A8E8-8D23B85255BF}\::{F1390A9A-A3F4-4E5D-9C5F-98F3BD8D935C}"
+            }
+            Self::UsersFiles => "::{59031A47-3F72-44A7-89C5-5595FE6B30EE}",
+            Self::UsersLibraries => "::{031E4825-7B94-4DC3-B131-E946B44C8DD5}",
+            _ => return None,
+        };
+        Some(ParsingName::new(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_names_are_well_formed() {
+        for &folder in KnownFolder::ALL {
+            if let Some(name) = folder.parsing_name() {
+                let segments = name.as_str().split('\\').count();
+                assert_eq!(name.clsids().count(), segments, "{folder:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn nested_parsing_names() {
+        let name = KnownFolder::SyncSetupFolder.parsing_name().unwrap();
+        let clsids = name.clsids().collect::<Vec<_>>();
+        assert_eq!(clsids.len(), 3);
+        assert_eq!(
+            clsids[0],
+            Guid::from_u128(0x21ec2020_3aea_1069_a2dd_08002b30309d)
+        );
+        assert_eq!(ParsingName::new(String::from("C:\\")).clsids().count(), 0);
+    }
+}
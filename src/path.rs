@@ -0,0 +1,229 @@
+// src/path.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Helpers for paths that may be Windows paths on a non-Windows host.
+
+use std::path::{Path, PathBuf};
+
+use crate::text;
+
+/// Return `true` if `path` is spelled with Windows separators.
+///
+/// On Windows this is always true. Elsewhere, paths resolved from registry
+/// data are Windows paths stored as opaque strings, e.g. `C:\Users\jsmith`.
+fn is_windows_path(path: &Path) -> bool {
+    cfg!(windows) || path.to_str().map_or(false, |path| path.contains('\\'))
+}
+
+/// Join a relative path with `\` or `/` separators to `base`.
+///
+/// Components are joined with `\` if `base` is a Windows path and with the
+/// host separator otherwise.
+pub(crate) fn join(base: &Path, relative: &str) -> PathBuf {
+    let components = relative
+        .split(['\\', '/'])
+        .filter(|component| !component.is_empty());
+    if is_windows_path(base) {
+        if let Some(base) = base.to_str() {
+            let mut path = base.trim_end_matches(['\\', '/']).to_owned();
+            for component in components {
+                path.push('\\');
+                path.push_str(component);
+            }
+            return path.into();
+        }
+    }
+    let mut path = base.to_owned();
+    path.extend(components);
+    path
+}
+
+/// Return the parent directory of `path`.
+///
+/// Windows paths are split on both `\\` and `/` separators.
+pub(crate) fn parent(path: &Path) -> Option<PathBuf> {
+    if is_windows_path(path) {
+        if let Some(path) = path.to_str() {
+            let path = path.trim_end_matches(['\\', '/']);
+            let end = path.rfind(['\\', '/'])?;
+            return Some(path[..end].into());
+        }
+    }
+    path.parent().map(Path::to_path_buf)
+}
+
+/// Return `true` if `left` and `right` spell the same path, ignoring case,
+/// trailing separators, and the difference between `\\` and `/`.
+pub(crate) fn eq_ignore_case(left: &Path, right: &Path) -> bool {
+    match (left.to_str(), right.to_str()) {
+        (Some(left), Some(right)) => {
+            let left = left.trim_end_matches(['\\', '/']).replace('/', "\\");
+            let right = right.trim_end_matches(['\\', '/']).replace('/', "\\");
+            text::eq_ignore_case(&left, &right)
+        }
+        _ => left == right,
+    }
+}
+
+/// Return `true` if `c` separates components of a Windows path.
+pub(crate) fn is_separator(c: char) -> bool {
+    c == '\\' || c == '/'
+}
+
+/// Split a Windows path into components, normalizing spellings that Windows
+/// treats as the same path.
+///
+/// Both `\` and `/` separate components. The verbatim (`\\?\`), device
+/// (`\\.\`), and NT (`\??\`) prefixes are removed and `\\?\UNC\server\share`
+/// is treated like `\\server\share`. UNC paths start with a `\\` component
+/// followed by the server and share names. Empty and `.` components are
+/// dropped and `..` components are resolved lexically, never above the drive
+/// or share.
+pub(crate) fn windows_components(path: &str) -> Vec<&str> {
+    let bytes = path.as_bytes();
+    let sep = |index: usize| bytes.get(index).map_or(false, |&b| b == b'\\' || b == b'/');
+    let verbatim =
+        sep(0) && sep(3) && (&bytes[1..3] == b"??" || (sep(1) && matches!(bytes[2], b'?' | b'.')));
+    let mut rest = path;
+    let mut unc = false;
+    if verbatim {
+        rest = &path[4..];
+        let is_unc = rest
+            .get(..3)
+            .map_or(false, |prefix| prefix.eq_ignore_ascii_case("UNC"))
+            && rest[3..].starts_with(is_separator);
+        if is_unc {
+            unc = true;
+            rest = &rest[4..];
+        }
+    } else if sep(0) && sep(1) {
+        unc = true;
+        rest = &path[2..];
+    }
+    let mut components = Vec::new();
+    if unc {
+        components.push(r"\\");
+    }
+    for component in rest.split(is_separator) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                let root = if unc {
+                    3
+                } else {
+                    usize::from(
+                        components
+                            .first()
+                            .map_or(false, |first| first.ends_with(':')),
+                    )
+                };
+                if components.len() > root {
+                    components.pop();
+                }
+            }
+            _ => components.push(component),
+        }
+    }
+    components
+}
+
+/// Strip the directory `prefix` from the Windows path `path`, ignoring case
+/// and the difference between `\\` and `/`.
+///
+/// The prefix must end at a component boundary. The rest of `path` is
+/// returned as written, starting with its separator, or empty if `path` is
+/// `prefix` itself.
+pub(crate) fn strip_prefix_ignore_case<'a>(path: &'a str, prefix: &str) -> Option<&'a str> {
+    let mut chars = path.char_indices();
+    for expected in prefix.chars() {
+        let (_, actual) = chars.next()?;
+        let same = if is_separator(expected) {
+            is_separator(actual)
+        } else {
+            expected.to_uppercase().eq(actual.to_uppercase())
+        };
+        if !same {
+            return None;
+        }
+    }
+    let rest = chars.as_str();
+    if rest.is_empty() || rest.starts_with(is_separator) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_windows_path() {
+        assert_eq!(
+            join(Path::new(r"C:\Users\jsmith\"), r"Acme\Projects/"),
+            Path::new(r"C:\Users\jsmith\Acme\Projects")
+        );
+    }
+
+    #[test]
+    fn compare_windows_paths() {
+        let path = Path::new(r"C:\Users\jsmith\Music\desktop.ini");
+        let parent = parent(path).unwrap();
+        assert_eq!(parent, Path::new(r"C:\Users\jsmith\Music"));
+        assert!(eq_ignore_case(&parent, Path::new("c:/users/JSMITH/music/")));
+        assert!(!eq_ignore_case(&parent, Path::new(r"C:\Users\jsmith")));
+    }
+
+    #[test]
+    fn split_windows_paths() {
+        assert_eq!(
+            windows_components(r"C:\Users//jsmith\.\Music\..\Documents\"),
+            ["C:", "Users", "jsmith", "Documents"]
+        );
+        assert_eq!(
+            windows_components(r"\\?\C:\Users\jsmith"),
+            ["C:", "Users", "jsmith"]
+        );
+        assert_eq!(
+            windows_components(r"\\?\UNC\fs01\home\jsmith"),
+            windows_components(r"//fs01/home/jsmith")
+        );
+        assert_eq!(
+            windows_components(r"\\fs01\home\..\..\jsmith"),
+            [r"\\", "fs01", "home", "jsmith"]
+        );
+        assert_eq!(windows_components(r"C:\..\Windows"), ["C:", "Windows"]);
+    }
+
+    #[test]
+    fn strip_windows_prefix() {
+        let path = r"C:/users/JSMITH\AppData\Local";
+        assert_eq!(
+            strip_prefix_ignore_case(path, r"C:\Users\jsmith"),
+            Some(r"\AppData\Local")
+        );
+        assert_eq!(strip_prefix_ignore_case(path, path), Some(""));
+        assert_eq!(strip_prefix_ignore_case(path, r"C:\Users\js"), None);
+        assert_eq!(
+            strip_prefix_ignore_case(r"C:\Users", r"C:\Users\jsmith"),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn join_host_path() {
+        assert_eq!(
+            join(Path::new("/mnt/windows/Users/jsmith"), r"Acme\Projects"),
+            Path::new("/mnt/windows/Users/jsmith/Acme/Projects")
+        );
+    }
+}
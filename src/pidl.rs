@@ -0,0 +1,742 @@
+// src/pidl.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parser for serialized item ID lists (PIDLs).
+//!
+//! Shortcut files, jump lists, and registry MRU lists identify shell items
+//! with serialized `ITEMIDLIST` structures: a sequence of `SHITEMID`s, each
+//! prefixed with its size, terminated by an empty item. The format of each
+//! item is private to the shell folder that created it. [`IdList`] decodes the
+//! common item types of the shell's own folders and keeps the raw bytes of
+//! all others.
+//!
+//! A parsed list can recover the [`KnownFolder`] it is anchored at and the
+//! path relative to that folder, or the absolute file system path for lists
+//! rooted at a drive.
+
+use core::fmt;
+use std::error;
+use std::path::PathBuf;
+
+use crate::bytes::{slice_at, u16_at, u32_at};
+use crate::property_store::PropertyStore;
+use crate::text;
+use crate::{path, FolderId, Guid, KnownFolder, Resolver};
+
+/// The signature of property view items, which carry a known folder ID.
+const PROPERTY_VIEW_SIGNATURE: u32 = 0x23FE_BBEE;
+
+/// The signature of file entry extension blocks with the long file name.
+const EXTENSION_BLOCK_FILE_NAME: u32 = 0xBEEF_0004;
+
+// CLSIDs of shell folders that stand for known folders in item ID lists,
+// such as the folders shown under This PC.
+const CLSID_FOLDERS: &[(Guid, KnownFolder)] = &[
+    (
+        Guid::from_u128(0x018d5c66_4533_4307_9b53_224de2ed1fe6),
+        KnownFolder::OneDrive,
+    ),
+    (
+        Guid::from_u128(0x088e3905_0323_4b02_9826_5d99428e115f),
+        KnownFolder::Downloads,
+    ),
+    (
+        Guid::from_u128(0x0db7e03f_fc29_4dc6_9020_ff41b59e513a),
+        KnownFolder::Objects3D,
+    ),
+    (
+        Guid::from_u128(0x21ec2020_3aea_1069_a2dd_08002b30309d),
+        KnownFolder::ControlPanelFolder,
+    ),
+    (
+        Guid::from_u128(0x24ad3ad4_a569_4530_98e1_ab02f9417aa8),
+        KnownFolder::Pictures,
+    ),
+    (
+        Guid::from_u128(0x3dfdf296_dbec_4fb4_81d1_6a3438bcf4de),
+        KnownFolder::Music,
+    ),
+    (
+        Guid::from_u128(0x450d8fba_ad25_11d0_98a8_0800361b1103),
+        KnownFolder::Documents,
+    ),
+    (
+        Guid::from_u128(0xb4bfcc3a_db2c_424c_b029_7fe99a87c641),
+        KnownFolder::Desktop,
+    ),
+    (
+        Guid::from_u128(0xd3162b92_9365_467a_956b_92703aca08af),
+        KnownFolder::Documents,
+    ),
+    (
+        Guid::from_u128(0xf86fa3ab_70d2_4fc7_9c99_fcbf05467f3a),
+        KnownFolder::Videos,
+    ),
+];
+
+/// Return the known folder that the shell folder `clsid` stands for.
+pub(crate) fn clsid_known_folder(clsid: Guid) -> Option<KnownFolder> {
+    if let Some(&(_, known_folder)) = CLSID_FOLDERS.iter().find(|&&(id, _)| id == clsid) {
+        return Some(known_folder);
+    }
+    KnownFolder::ALL
+        .iter()
+        .copied()
+        .find(|known_folder| {
+            known_folder.parsing_name().map_or(false, |name| {
+                let mut clsids = name.clsids();
+                clsids.next() == Some(clsid) && clsids.next().is_none()
+            })
+        })
+        .or_else(|| KnownFolder::from_guid(clsid))
+}
+
+/// The kind of error that occurred while parsing an item ID list.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// An item extends past the end of the data.
+    Truncated,
+    /// An item has a size too small to hold its size field.
+    InvalidItemSize,
+}
+
+/// Error returned when parsing an item ID list fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the item ID list of the item that could not be
+    /// parsed.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Truncated => write!(f, "truncated item at offset {}", self.offset),
+            ParseErrorKind::InvalidItemSize => {
+                write!(f, "invalid item size at offset {}", self.offset)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// An extension block appended to a file entry item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtensionBlock {
+    signature: u32,
+    version: u16,
+    data: Vec<u8>,
+}
+
+impl ExtensionBlock {
+    /// The block signature, like `0xBEEF0004`.
+    #[must_use]
+    pub fn signature(&self) -> u32 {
+        self.signature
+    }
+
+    /// The block version.
+    #[must_use]
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// The raw bytes of the block, including its size, version, and
+    /// signature header.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Decode the long file name of a `0xBEEF0004` block.
+    fn long_name(&self) -> Option<String> {
+        if self.signature != EXTENSION_BLOCK_FILE_NAME {
+            return None;
+        }
+        let offset = match self.version {
+            9..=u16::MAX => 46,
+            8 => 42,
+            7 => 38,
+            3..=6 => 20,
+            _ => return None,
+        };
+        // The last two bytes hold the offset of the block within the item.
+        let name = self.data.get(offset..self.data.len().checked_sub(2)?)?;
+        let name = text::decode_utf16le_nul(name);
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+}
+
+/// A file or directory item of the file system folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    class_type: u8,
+    file_size: u32,
+    modified: u32,
+    attributes: u16,
+    primary_name: String,
+    long_name: Option<String>,
+    extension_blocks: Vec<ExtensionBlock>,
+}
+
+impl FileEntry {
+    fn parse(item: &[u8]) -> Option<Self> {
+        let class_type = *item.get(2)?;
+        let file_size = u32_at(item, 4)?;
+        let modified = u32_at(item, 8)?;
+        let attributes = u16_at(item, 12)?;
+        let name = item.get(14..)?;
+        let (primary_name, name_len) = if class_type & 0x04 == 0 {
+            let len = name.iter().position(|&b| b == 0)?;
+            // ANSI names are padded to an even size.
+            (text::decode_ansi(&name[..len]), (len + 2) & !1)
+        } else {
+            let units = name.chunks_exact(2).position(|pair| pair == [0, 0])?;
+            (text::decode_utf16le(&name[..units * 2]), units * 2 + 2)
+        };
+        let mut extension_blocks = Vec::new();
+        let mut offset = 14 + name_len;
+        while let (Some(size), Some(version), Some(signature)) = (
+            u16_at(item, offset),
+            u16_at(item, offset + 2),
+            u32_at(item, offset + 4),
+        ) {
+            let size = usize::from(size);
+            if size < 8 || signature & 0xFFFF_0000 != 0xBEEF_0000 {
+                break;
+            }
+            let data = match slice_at(item, offset, size) {
+                Some(data) => data.to_vec(),
+                None => break,
+            };
+            extension_blocks.push(ExtensionBlock {
+                signature,
+                version,
+                data,
+            });
+            offset += size;
+        }
+        let long_name = extension_blocks.iter().find_map(ExtensionBlock::long_name);
+        Some(Self {
+            class_type,
+            file_size,
+            modified,
+            attributes,
+            primary_name,
+            long_name,
+            extension_blocks,
+        })
+    }
+
+    /// Return `true` if this item is a directory.
+    #[must_use]
+    pub fn is_directory(&self) -> bool {
+        self.class_type & 0x01 != 0
+    }
+
+    /// The size of the file in bytes, truncated to 32 bits.
+    #[must_use]
+    pub fn file_size(&self) -> u32 {
+        self.file_size
+    }
+
+    /// The last modification time as an MS-DOS date in the low 16 bits and
+    /// an MS-DOS time in the high 16 bits.
+    #[must_use]
+    pub fn modified_dos_time(&self) -> u32 {
+        self.modified
+    }
+
+    /// The `FILE_ATTRIBUTE_*` flags of the item.
+    #[must_use]
+    pub fn attributes(&self) -> u16 {
+        self.attributes
+    }
+
+    /// The primary name of the item, which is the 8.3 short name on file
+    /// systems that generate them.
+    #[must_use]
+    pub fn primary_name(&self) -> &str {
+        &self.primary_name
+    }
+
+    /// The long name of the item from its extension blocks.
+    #[must_use]
+    pub fn long_name(&self) -> Option<&str> {
+        self.long_name.as_deref()
+    }
+
+    /// The name of the item: the long name if there is one and the primary
+    /// name otherwise.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.long_name().unwrap_or(&self.primary_name)
+    }
+
+    /// The extension blocks of the item.
+    #[must_use]
+    pub fn extension_blocks(&self) -> &[ExtensionBlock] {
+        &self.extension_blocks
+    }
+}
+
+/// An item of an item ID list.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+// `FileEntry` is the largest variant, but it is also the most common one:
+// most items of a list are file entries. Boxing it would cost an allocation
+// per item while only the few shell folder and drive items got smaller.
+#[allow(variant_size_differences)]
+pub enum ShellItem {
+    /// A shell folder at the root of the namespace, like This PC.
+    Root {
+        /// The sort index that orders root items in Explorer.
+        sort_index: u8,
+        /// The CLSID of the shell folder.
+        clsid: Guid,
+    },
+    /// A shell folder nested in another shell folder, like Documents under
+    /// This PC.
+    Clsid {
+        /// The CLSID of the shell folder.
+        clsid: Guid,
+    },
+    /// A drive, like `C:\`.
+    Volume {
+        /// The drive path.
+        name: String,
+    },
+    /// A file or directory.
+    FileEntry(FileEntry),
+    /// A property view item that identifies a known folder.
+    PropertyView {
+        /// The ID of the known folder, if the item carries one.
+        known_folder: Option<FolderId>,
+        /// The properties of the item.
+        properties: PropertyStore,
+    },
+    /// An item of a type this parser does not decode.
+    Unknown {
+        /// The class type indicator, the first byte of the item data.
+        class_type: u8,
+        /// The raw bytes of the item, excluding its size field.
+        data: Vec<u8>,
+    },
+}
+
+impl ShellItem {
+    /// Decode one `SHITEMID`, including its size field.
+    fn parse(item: &[u8]) -> Self {
+        let class_type = item.get(2).copied().unwrap_or_default();
+        let clsid = slice_at(item, 4, 16).and_then(Guid::from_slice_le);
+        let parsed = match (class_type, clsid) {
+            (0x1F, Some(clsid)) => Some(Self::Root {
+                sort_index: item[3],
+                clsid,
+            }),
+            (0x2E, Some(clsid)) => Some(Self::Clsid { clsid }),
+            (0x20..=0x2F, _) => item.get(3..).map(|name| Self::Volume {
+                name: text::decode_ansi_nul(name),
+            }),
+            (0x30..=0x3F, _) => FileEntry::parse(item).map(Self::FileEntry),
+            (0x00, _) if u32_at(item, 6) == Some(PROPERTY_VIEW_SIGNATURE) => {
+                Some(Self::parse_property_view(item))
+            }
+            _ => None,
+        };
+        parsed.unwrap_or_else(|| Self::Unknown {
+            class_type,
+            data: item.get(2..).unwrap_or_default().to_vec(),
+        })
+    }
+
+    fn parse_property_view(item: &[u8]) -> Self {
+        let id_size = u16_at(item, 12).map_or(0, usize::from);
+        let known_folder = if id_size == 16 {
+            slice_at(item, 14, 16)
+                .and_then(Guid::from_slice_le)
+                .map(FolderId::from_guid)
+        } else {
+            None
+        };
+        let store_offset = 14 + id_size;
+        let properties = u16_at(item, store_offset)
+            .and_then(|size| slice_at(item, store_offset + 2, usize::from(size)))
+            .and_then(|store| PropertyStore::parse(store).ok())
+            .unwrap_or_default();
+        Self::PropertyView {
+            known_folder,
+            properties,
+        }
+    }
+
+    /// Return the known folder this item stands for, if any.
+    ///
+    /// Shell folder items like This PC or the Downloads folder under This PC
+    /// and property view items identify known folders.
+    #[must_use]
+    pub fn known_folder(&self) -> Option<FolderId> {
+        match self {
+            Self::Root { clsid, .. } | Self::Clsid { clsid } => {
+                clsid_known_folder(*clsid).map(FolderId::Known)
+            }
+            Self::PropertyView { known_folder, .. } => *known_folder,
+            _ => None,
+        }
+    }
+
+    /// Return the file or directory name of a file entry item.
+    #[must_use]
+    pub fn file_name(&self) -> Option<&str> {
+        match self {
+            Self::FileEntry(entry) => Some(entry.name()),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed item ID list.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::pidl::IdList;
+/// use known_folders::{FolderId, Guid, KnownFolder};
+///
+/// // This PC\Downloads\report.txt
+/// let this_pc = Guid::from_u128(0x20d04fe0_3aea_1069_a2d8_08002b30309d);
+/// let downloads = Guid::from_u128(0x088e3905_0323_4b02_9826_5d99428e115f);
+/// let mut data = Vec::new();
+/// data.extend_from_slice(&[20, 0, 0x1F, 0x50]);
+/// data.extend_from_slice(&this_pc.to_bytes_le());
+/// data.extend_from_slice(&[20, 0, 0x2E, 0x00]);
+/// data.extend_from_slice(&downloads.to_bytes_le());
+/// data.extend_from_slice(&[26, 0, 0x32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x20, 0]);
+/// data.extend_from_slice(b"report.txt\0\0");
+/// data.extend_from_slice(&[0, 0]);
+///
+/// let list = IdList::parse(&data)?;
+/// assert_eq!(list.items().len(), 3);
+/// assert_eq!(
+///     list.known_folder(),
+///     Some((FolderId::Known(KnownFolder::Downloads), "report.txt".to_owned())),
+/// );
+/// # Ok::<(), known_folders::pidl::ParseError>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct IdList {
+    items: Vec<ShellItem>,
+    offsets: Vec<usize>,
+}
+
+impl IdList {
+    /// Parse a serialized item ID list.
+    ///
+    /// Parsing stops at the terminating empty item or at the end of the
+    /// data. Items of unknown types are preserved as
+    /// [`ShellItem::Unknown`].
+    ///
+    /// # Errors
+    ///
+    /// If an item's size field is invalid or an item extends past the end of
+    /// the data, an error is returned.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let mut list = Self::default();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let size = u16_at(bytes, offset).ok_or(ParseError {
+                kind: ParseErrorKind::Truncated,
+                offset,
+            })?;
+            match size {
+                0 => break,
+                1 => {
+                    return Err(ParseError {
+                        kind: ParseErrorKind::InvalidItemSize,
+                        offset,
+                    })
+                }
+                _ => {}
+            }
+            let item = slice_at(bytes, offset, usize::from(size)).ok_or(ParseError {
+                kind: ParseErrorKind::Truncated,
+                offset,
+            })?;
+            list.items.push(ShellItem::parse(item));
+            list.offsets.push(offset);
+            offset += item.len();
+        }
+        Ok(list)
+    }
+
+    /// The items of this list, outermost first.
+    #[must_use]
+    pub fn items(&self) -> &[ShellItem] {
+        &self.items
+    }
+
+    /// Return `true` if this list has no items, which identifies the
+    /// desktop.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Return the items starting with the item at byte offset `offset` in
+    /// the serialized list.
+    ///
+    /// Returns [`None`] if no item starts at `offset`.
+    #[must_use]
+    pub fn items_from_offset(&self, offset: usize) -> Option<&[ShellItem]> {
+        let index = self.offsets.iter().position(|&start| start == offset)?;
+        Some(&self.items[index..])
+    }
+
+    /// Return the innermost known folder this list passes through and the
+    /// `\`-separated path of the file entries below it.
+    ///
+    /// Returns [`None`] if no item of the list identifies a known folder, or
+    /// if a drive item follows the innermost known folder, like a file in
+    /// `This PC\C:\`, whose path is not relative to the known folder.
+    #[must_use]
+    pub fn known_folder(&self) -> Option<(FolderId, String)> {
+        let (index, known_folder) = self
+            .items
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, item)| item.known_folder().map(|id| (index, id)))?;
+        let rest = &self.items[index + 1..];
+        if rest
+            .iter()
+            .any(|item| matches!(item, ShellItem::Volume { .. }))
+        {
+            return None;
+        }
+        Some((known_folder, relative_path(rest)))
+    }
+
+    /// Return the absolute file system path of a list that passes through a
+    /// drive item, like `C:\Users\jsmith\report.txt`.
+    #[must_use]
+    pub fn path(&self) -> Option<String> {
+        let index = self
+            .items
+            .iter()
+            .rposition(|item| matches!(item, ShellItem::Volume { .. }))?;
+        let drive = match &self.items[index] {
+            ShellItem::Volume { name } => name.trim_end_matches('\\'),
+            _ => return None,
+        };
+        let relative = relative_path(&self.items[index + 1..]);
+        Some(format!(r"{drive}\{relative}"))
+    }
+
+    /// Resolve the file system path this list refers to.
+    ///
+    /// Lists anchored at a known folder are resolved relative to the
+    /// folder's path as located by `resolver`. Otherwise the absolute
+    /// [`path`](Self::path) of the list is returned.
+    #[must_use]
+    pub fn resolve<R>(&self, resolver: &R) -> Option<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        if let Some((known_folder, relative)) = self.known_folder() {
+            if let Some(folder) = resolver.resolve_id(known_folder) {
+                return Some(path::join(&folder, &relative));
+            }
+        }
+        self.path().map(PathBuf::from)
+    }
+}
+
+/// Join the names of the file entries among `items` with `\`.
+fn relative_path(items: &[ShellItem]) -> String {
+    let mut path = String::new();
+    for name in items.iter().filter_map(ShellItem::file_name) {
+        if !path.is_empty() {
+            path.push('\\');
+        }
+        path.push_str(name);
+    }
+    path
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::Snapshot;
+
+    /// Serialize a shell item from its data, excluding the size field.
+    pub(crate) fn item(data: &[u8]) -> Vec<u8> {
+        let mut out = u16::try_from(data.len() + 2)
+            .unwrap()
+            .to_le_bytes()
+            .to_vec();
+        out.extend_from_slice(data);
+        out
+    }
+
+    pub(crate) fn root(clsid: Guid) -> Vec<u8> {
+        let mut data = vec![0x1F, 0x50];
+        data.extend_from_slice(&clsid.to_bytes_le());
+        item(&data)
+    }
+
+    pub(crate) fn file_entry(short_name: &str, long_name: &str, directory: bool) -> Vec<u8> {
+        let mut data = vec![if directory { 0x31 } else { 0x32 }, 0];
+        data.extend_from_slice(&1234_u32.to_le_bytes());
+        data.extend_from_slice(&0x5A21_4E6B_u32.to_le_bytes());
+        data.extend_from_slice(&0x10_u16.to_le_bytes());
+        data.extend_from_slice(short_name.as_bytes());
+        data.push(0);
+        if data.len() % 2 != 0 {
+            data.push(0);
+        }
+        let name = long_name
+            .encode_utf16()
+            .chain(Some(0))
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let block_offset = u16::try_from(data.len() + 2).unwrap();
+        let mut block = Vec::new();
+        block.extend_from_slice(&u16::try_from(46 + name.len() + 2).unwrap().to_le_bytes());
+        block.extend_from_slice(&9_u16.to_le_bytes());
+        block.extend_from_slice(&EXTENSION_BLOCK_FILE_NAME.to_le_bytes());
+        block.resize(46, 0);
+        block.extend_from_slice(&name);
+        block.extend_from_slice(&block_offset.to_le_bytes());
+        data.extend_from_slice(&block);
+        item(&data)
+    }
+
+    #[test]
+    fn parse_drive_path() {
+        let this_pc = Guid::from_u128(0x20d04fe0_3aea_1069_a2d8_08002b30309d);
+        let mut data = root(this_pc);
+        data.extend(item(b"\x2FC:\\\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"));
+        data.extend(file_entry("PROGRA~1", "Program Files", true));
+        data.extend(file_entry("Acme", "Acme", true));
+        data.extend_from_slice(&[0, 0]);
+        let list = IdList::parse(&data).unwrap();
+        assert_eq!(list.items().len(), 4);
+        assert_eq!(list.path().as_deref(), Some(r"C:\Program Files\Acme"));
+        match &list.items()[2] {
+            ShellItem::FileEntry(entry) => {
+                assert!(entry.is_directory());
+                assert_eq!(entry.primary_name(), "PROGRA~1");
+                assert_eq!(entry.long_name(), Some("Program Files"));
+                assert_eq!(entry.file_size(), 1234);
+                assert_eq!(entry.attributes(), 0x10);
+                assert_eq!(entry.extension_blocks().len(), 1);
+            }
+            item => panic!("unexpected item: {item:?}"),
+        }
+        assert_eq!(list.known_folder(), None);
+        assert_eq!(
+            list.resolve(&Snapshot::new()),
+            Some(PathBuf::from(r"C:\Program Files\Acme"))
+        );
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::ComputerFolder, r"D:\Elsewhere");
+        assert_eq!(
+            list.resolve(&snapshot),
+            Some(PathBuf::from(r"C:\Program Files\Acme"))
+        );
+    }
+
+    #[test]
+    fn parse_property_view_known_folder() {
+        let mut view = vec![0x00, 0x00, 0, 0];
+        view.extend_from_slice(&PROPERTY_VIEW_SIGNATURE.to_le_bytes());
+        view.extend_from_slice(&0_u16.to_le_bytes());
+        view.extend_from_slice(&16_u16.to_le_bytes());
+        view.extend_from_slice(&KnownFolder::Downloads.guid().to_bytes_le());
+        view.extend_from_slice(&0_u16.to_le_bytes());
+        let mut data = root(Guid::from_u128(0x59031a47_3f72_44a7_89c5_5595fe6b30ee));
+        data.extend(item(&view));
+        data.extend(file_entry("SETUP~1.EXE", "setup-x64.exe", false));
+        data.extend_from_slice(&[0, 0]);
+
+        let list = IdList::parse(&data).unwrap();
+        assert_eq!(
+            list.known_folder(),
+            Some((
+                FolderId::Known(KnownFolder::Downloads),
+                "setup-x64.exe".to_owned()
+            ))
+        );
+        assert_eq!(list.path(), None);
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Downloads, r"D:\Downloads");
+        assert_eq!(
+            list.resolve(&snapshot),
+            Some(PathBuf::from(r"D:\Downloads\setup-x64.exe"))
+        );
+        let offset = root(Guid::NIL).len();
+        assert_eq!(list.items_from_offset(offset).map(<[_]>::len), Some(2));
+        assert_eq!(list.items_from_offset(1), None);
+    }
+
+    #[test]
+    fn preserve_unknown_items() {
+        let mut data = item(&[0x74, 0x1A, 1, 2, 3]);
+        data.extend(item(&[]));
+        let list = IdList::parse(&data).unwrap();
+        assert_eq!(
+            list.items(),
+            [
+                ShellItem::Unknown {
+                    class_type: 0x74,
+                    data: vec![0x74, 0x1A, 1, 2, 3]
+                },
+                ShellItem::Unknown {
+                    class_type: 0,
+                    data: vec![]
+                }
+            ]
+        );
+        assert_eq!(list.known_folder(), None);
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert_eq!(
+            IdList::parse(&[10, 0, 0x1F]).unwrap_err().kind(),
+            ParseErrorKind::Truncated
+        );
+        assert_eq!(
+            IdList::parse(&[1, 0, 0]).unwrap_err().kind(),
+            ParseErrorKind::InvalidItemSize
+        );
+        assert!(IdList::parse(&[0, 0]).unwrap().is_empty());
+    }
+}
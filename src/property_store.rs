@@ -0,0 +1,440 @@
+// src/property_store.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parser for serialized property stores (MS-PROPSTORE).
+//!
+//! Shell items, shortcut files, and jump lists embed property stores that
+//! describe the item they refer to, keyed by property set GUID and property
+//! ID. [`PropertyStore`] decodes the common value types and keeps the raw
+//! bytes of all others.
+
+use core::fmt;
+use std::error;
+
+use crate::bytes::{i32_at, slice_at, u16_at, u32_at, u64_at};
+use crate::text;
+use crate::Guid;
+
+/// The `Version` field of a serialized property storage, `1SPS`.
+const VERSION: u32 = 0x5350_5331;
+
+/// The property set whose properties are identified by name instead of ID.
+const STRING_NAMED_PROPERTIES: Guid = Guid::from_u128(0xd5cdd505_2e9c_101b_9397_08002b2cf9ae);
+
+/// The kind of error that occurred while parsing a property store.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A storage or value extends past the end of the data.
+    Truncated,
+    /// A storage does not carry the `1SPS` version signature.
+    InvalidVersion,
+}
+
+/// Error returned when parsing a property store fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the property store where the error was detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Truncated => {
+                write!(f, "truncated property store at offset {}", self.offset)
+            }
+            ParseErrorKind::InvalidVersion => {
+                write!(
+                    f,
+                    "invalid property storage version at offset {}",
+                    self.offset
+                )
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A typed property value.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// `VT_EMPTY` or `VT_NULL`.
+    Empty,
+    /// `VT_BOOL`.
+    Bool(bool),
+    /// A signed integer: `VT_I1`, `VT_I2`, `VT_I4`, `VT_I8`, or `VT_INT`.
+    Int(i64),
+    /// An unsigned integer: `VT_UI1`, `VT_UI2`, `VT_UI4`, `VT_UI8`, or
+    /// `VT_UINT`.
+    UInt(u64),
+    /// A string: `VT_LPWSTR`, `VT_BSTR`, or `VT_LPSTR`.
+    String(String),
+    /// `VT_CLSID`.
+    Guid(Guid),
+    /// `VT_FILETIME`, in 100-nanosecond intervals since January 1, 1601 UTC.
+    FileTime(u64),
+    /// A value of another type, with its raw serialized bytes.
+    Other {
+        /// The `VARTYPE` of the value.
+        vartype: u16,
+        /// The serialized value, excluding the type header.
+        data: Vec<u8>,
+    },
+}
+
+impl Value {
+    /// Return the string value, if this is a string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Decode a `TypedPropertyValue` structure.
+    fn parse(buf: &[u8]) -> Option<Self> {
+        let vartype = u16_at(buf, 0)?;
+        let data = buf.get(4..)?;
+        let value = match vartype {
+            0x00 | 0x01 => Self::Empty,
+            0x0B => Self::Bool(u16_at(data, 0)? != 0),
+            0x10 => Self::Int(i64::from(i8::from_le_bytes([*data.first()?]))),
+            0x02 => Self::Int(i64::from(i16::from_le_bytes(
+                u16_at(data, 0)?.to_le_bytes(),
+            ))),
+            0x03 | 0x16 => Self::Int(i64::from(i32_at(data, 0)?)),
+            0x14 => Self::Int(i64::from_le_bytes(u64_at(data, 0)?.to_le_bytes())),
+            0x11 => Self::UInt(u64::from(*data.first()?)),
+            0x12 => Self::UInt(u64::from(u16_at(data, 0)?)),
+            0x13 | 0x17 => Self::UInt(u64::from(u32_at(data, 0)?)),
+            0x15 => Self::UInt(u64_at(data, 0)?),
+            0x1F => {
+                let units = usize::try_from(u32_at(data, 0)?).ok()?;
+                let bytes = slice_at(data, 4, units.checked_mul(2)?)?;
+                Self::String(text::decode_utf16le_nul(bytes))
+            }
+            0x08 => {
+                let len = usize::try_from(u32_at(data, 0)?).ok()?;
+                Self::String(text::decode_utf16le_nul(slice_at(data, 4, len)?))
+            }
+            0x1E => {
+                let len = usize::try_from(u32_at(data, 0)?).ok()?;
+                Self::String(text::decode_ansi_nul(slice_at(data, 4, len)?))
+            }
+            0x40 => Self::FileTime(u64_at(data, 0)?),
+            0x48 => Self::Guid(Guid::from_slice_le(slice_at(data, 0, 16)?)?),
+            _ => Self::Other {
+                vartype,
+                data: data.to_vec(),
+            },
+        };
+        Some(value)
+    }
+}
+
+/// The identifier of a property within its property set.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+// `Name` holds a 24-byte `String` next to the 4-byte `Id`. Boxing it would
+// halve the size of the enum, but `Name` is a public variant that callers
+// build and match on, so the plain `String` is kept for a simpler API.
+#[allow(variant_size_differences)]
+pub enum PropertyId {
+    /// A numeric property ID.
+    Id(u32),
+    /// A property name, used by the string-named property set.
+    Name(String),
+}
+
+/// A property of a [`PropertyStorage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Property {
+    id: PropertyId,
+    value: Value,
+}
+
+impl Property {
+    /// The identifier of this property.
+    #[must_use]
+    pub fn id(&self) -> &PropertyId {
+        &self.id
+    }
+
+    /// The value of this property.
+    #[must_use]
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+/// The properties of one property set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyStorage {
+    format_id: Guid,
+    properties: Vec<Property>,
+}
+
+impl PropertyStorage {
+    /// The property set GUID, the `fmtid` of the properties' keys.
+    #[must_use]
+    pub fn format_id(&self) -> Guid {
+        self.format_id
+    }
+
+    /// The properties of this storage in serialized order.
+    #[must_use]
+    pub fn properties(&self) -> &[Property] {
+        &self.properties
+    }
+
+    fn parse(buf: &[u8], base: usize) -> Result<Self, ParseError> {
+        let truncated = |offset| ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset: base + offset,
+        };
+        if u32_at(buf, 4).ok_or_else(|| truncated(4))? != VERSION {
+            return Err(ParseError {
+                kind: ParseErrorKind::InvalidVersion,
+                offset: base + 4,
+            });
+        }
+        let format_id = slice_at(buf, 8, 16)
+            .and_then(Guid::from_slice_le)
+            .ok_or_else(|| truncated(8))?;
+        let named = format_id == STRING_NAMED_PROPERTIES;
+        let mut properties = Vec::new();
+        let mut offset = 24;
+        loop {
+            let size = u32_at(buf, offset).ok_or_else(|| truncated(offset))?;
+            if size == 0 {
+                break;
+            }
+            let size = usize::try_from(size).map_err(|_| truncated(offset))?;
+            let value = slice_at(buf, offset, size).ok_or_else(|| truncated(offset))?;
+            let (id, value_offset) = if named {
+                let name_size = u32_at(value, 4).and_then(|n| usize::try_from(n).ok());
+                let name = name_size.and_then(|len| slice_at(value, 9, len));
+                let name = name.ok_or_else(|| truncated(offset))?;
+                (
+                    PropertyId::Name(text::decode_utf16le_nul(name)),
+                    9 + name.len(),
+                )
+            } else {
+                let id = u32_at(value, 4).ok_or_else(|| truncated(offset))?;
+                (PropertyId::Id(id), 9)
+            };
+            let value = value
+                .get(value_offset..)
+                .and_then(Value::parse)
+                .ok_or_else(|| truncated(offset + value_offset))?;
+            properties.push(Property { id, value });
+            offset += size;
+        }
+        Ok(Self {
+            format_id,
+            properties,
+        })
+    }
+}
+
+/// A serialized property store.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::property_store::{PropertyStore, Value};
+/// use known_folders::Guid;
+///
+/// // System.ItemNameDisplay = "Report"
+/// let fmtid = Guid::from_u128(0xb725f130_47ef_101a_a5f1_02608c9eebac);
+/// let mut storage = Vec::new();
+/// storage.extend_from_slice(b"1SPS");
+/// storage.extend_from_slice(&fmtid.to_bytes_le());
+/// let name = "Report\0".encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<_>>();
+/// storage.extend_from_slice(&(9 + 8 + name.len() as u32).to_le_bytes());
+/// storage.extend_from_slice(&10_u32.to_le_bytes());
+/// storage.push(0);
+/// storage.extend_from_slice(&[0x1F, 0, 0, 0]);
+/// storage.extend_from_slice(&7_u32.to_le_bytes());
+/// storage.extend_from_slice(&name);
+/// storage.extend_from_slice(&0_u32.to_le_bytes());
+///
+/// let mut data = Vec::new();
+/// data.extend_from_slice(&(storage.len() as u32 + 4).to_le_bytes());
+/// data.extend_from_slice(&storage);
+/// data.extend_from_slice(&0_u32.to_le_bytes());
+///
+/// let store = PropertyStore::parse(&data)?;
+/// assert_eq!(store.get(fmtid, 10).and_then(Value::as_str), Some("Report"));
+/// # Ok::<(), known_folders::property_store::ParseError>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct PropertyStore {
+    storages: Vec<PropertyStorage>,
+}
+
+impl PropertyStore {
+    /// Parse a sequence of serialized property storages terminated by an
+    /// empty storage.
+    ///
+    /// A missing terminator at the end of the data is tolerated.
+    ///
+    /// # Errors
+    ///
+    /// If a storage is truncated or has an invalid version, an error is
+    /// returned.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let mut storages = Vec::new();
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let truncated = ParseError {
+                kind: ParseErrorKind::Truncated,
+                offset,
+            };
+            let size = u32_at(bytes, offset).ok_or(truncated)?;
+            if size == 0 {
+                break;
+            }
+            let size = usize::try_from(size).map_err(|_| truncated)?;
+            let storage = slice_at(bytes, offset, size).ok_or(truncated)?;
+            storages.push(PropertyStorage::parse(storage, offset)?);
+            offset += size;
+        }
+        Ok(Self { storages })
+    }
+
+    /// The property storages of this store in serialized order.
+    #[must_use]
+    pub fn storages(&self) -> &[PropertyStorage] {
+        &self.storages
+    }
+
+    /// Return `true` if this store holds no properties.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.storages
+            .iter()
+            .all(|storage| storage.properties.is_empty())
+    }
+
+    /// Return the value of the property with key `(format_id, id)`.
+    #[must_use]
+    pub fn get(&self, format_id: Guid, id: u32) -> Option<&Value> {
+        self.storages
+            .iter()
+            .filter(|storage| storage.format_id == format_id)
+            .flat_map(|storage| storage.properties.iter())
+            .find(|property| property.id == PropertyId::Id(id))
+            .map(|property| &property.value)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn storage(format_id: Guid, values: &[(&[u8], &[u8])]) -> Vec<u8> {
+        let mut body = Vec::new();
+        for (key, value) in values {
+            let size = 4 + key.len() + 1 + value.len();
+            body.extend_from_slice(&u32::try_from(size).unwrap().to_le_bytes());
+            body.extend_from_slice(key);
+            body.push(0);
+            body.extend_from_slice(value);
+        }
+        body.extend_from_slice(&0_u32.to_le_bytes());
+        let mut out = Vec::new();
+        out.extend_from_slice(&u32::try_from(24 + body.len()).unwrap().to_le_bytes());
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        out.extend_from_slice(&format_id.to_bytes_le());
+        out.extend_from_slice(&body);
+        out
+    }
+
+    #[test]
+    fn parse_typed_values() {
+        let fmtid = Guid::from_u128(0x28636aa6_953d_11d2_b5d6_00c04fd918d0);
+        let clsid = Guid::from_u128(0x20d04fe0_3aea_1069_a2d8_08002b30309d);
+        let mut guid_value = vec![0x48, 0, 0, 0];
+        guid_value.extend_from_slice(&clsid.to_bytes_le());
+        let data = storage(
+            fmtid,
+            &[
+                (&1_u32.to_le_bytes(), &[0x13, 0, 0, 0, 42, 0, 0, 0]),
+                (&2_u32.to_le_bytes(), &[0x0B, 0, 0, 0, 0xFF, 0xFF, 0, 0]),
+                (&3_u32.to_le_bytes(), &guid_value),
+                (&4_u32.to_le_bytes(), &[0x42, 0, 0, 0, 1, 2]),
+            ],
+        );
+        let store = PropertyStore::parse(&data).unwrap();
+        assert_eq!(store.storages().len(), 1);
+        assert_eq!(store.get(fmtid, 1), Some(&Value::UInt(42)));
+        assert_eq!(store.get(fmtid, 2), Some(&Value::Bool(true)));
+        assert_eq!(store.get(fmtid, 3), Some(&Value::Guid(clsid)));
+        assert_eq!(
+            store.get(fmtid, 4),
+            Some(&Value::Other {
+                vartype: 0x42,
+                data: vec![1, 2]
+            })
+        );
+        assert_eq!(store.get(Guid::NIL, 1), None);
+    }
+
+    #[test]
+    fn parse_string_named_properties() {
+        let name = "Key\0"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let name_size = u32::try_from(name.len()).unwrap().to_le_bytes();
+        // The name follows the reserved byte and precedes the typed value.
+        let mut payload = name;
+        payload.extend_from_slice(&[0x13, 0, 0, 0, 7, 0, 0, 0]);
+        let data = storage(STRING_NAMED_PROPERTIES, &[(&name_size, &payload)]);
+        let store = PropertyStore::parse(&data).unwrap();
+        let property = &store.storages()[0].properties()[0];
+        assert_eq!(property.id(), &PropertyId::Name("Key".to_owned()));
+        assert_eq!(property.value(), &Value::UInt(7));
+    }
+
+    #[test]
+    fn reject_malformed() {
+        let mut data = storage(Guid::NIL, &[]);
+        data[4] = b'X';
+        assert_eq!(
+            PropertyStore::parse(&data).unwrap_err().kind(),
+            ParseErrorKind::InvalidVersion
+        );
+        let data = storage(Guid::NIL, &[(&1_u32.to_le_bytes(), &[0x13, 0, 0, 0, 1])]);
+        assert_eq!(
+            PropertyStore::parse(&data).unwrap_err().kind(),
+            ParseErrorKind::Truncated
+        );
+        assert!(PropertyStore::parse(&[]).unwrap().is_empty());
+    }
+}
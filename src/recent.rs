@@ -0,0 +1,575 @@
+// src/recent.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Recently opened documents from the [`Recent`] folder and its jump lists.
+//!
+//! The shell records each opened document as a shortcut in the Recent
+//! folder and as an entry in the jump list of the application that opened
+//! it. Jump lists are stored in the `AutomaticDestinations` and
+//! `CustomDestinations` subfolders, named after the `AppID` of their
+//! application: a hash of its `AppUserModelID`.
+//!
+//! [`items`] lists the recent documents, newest first, and attributes each
+//! one to the jump lists that reference it. [`jump_lists`] reads the jump
+//! lists themselves.
+//!
+//! [`Recent`]: KnownFolder::Recent
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::cfb::CompoundFile;
+use crate::property_store::Value;
+use crate::shell_link::{self, ShellLink};
+use crate::{path, Guid, KnownFolder, Resolver};
+
+/// The subfolder of the Recent folder with automatic jump lists.
+pub const AUTOMATIC_DESTINATIONS: &str = "AutomaticDestinations";
+
+/// The subfolder of the Recent folder with custom jump lists.
+pub const CUSTOM_DESTINATIONS: &str = "CustomDestinations";
+
+/// The property set of `PKEY_AppUserModel_ID`.
+const APP_USER_MODEL: Guid = Guid::from_u128(0x9f4c2855_9f79_4b39_a8d0_e1d42de1d5f3);
+
+/// The property ID of `PKEY_AppUserModel_ID`.
+const APP_USER_MODEL_ID: u32 = 5;
+
+/// The kind of a jump list file.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum JumpListKind {
+    /// An `.automaticDestinations-ms` file maintained by the shell.
+    Automatic,
+    /// A `.customDestinations-ms` file written by the application.
+    Custom,
+}
+
+/// A parsed jump list file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JumpList {
+    app_id: String,
+    kind: JumpListKind,
+    path: PathBuf,
+    links: Vec<ShellLink>,
+}
+
+impl JumpList {
+    /// Read the jump list file at `path`.
+    ///
+    /// Returns `Ok(None)` if the file name does not end in
+    /// `.automaticDestinations-ms` or `.customDestinations-ms`. Entries that
+    /// are not valid shell links are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, an error is returned.
+    pub fn open<P>(path: P) -> io::Result<Option<Self>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let name = path.file_name().and_then(|name| name.to_str());
+        let (app_id, kind) = match name.and_then(|name| name.split_once('.')) {
+            Some((app_id, extension))
+                if extension.eq_ignore_ascii_case("automaticDestinations-ms") =>
+            {
+                (app_id, JumpListKind::Automatic)
+            }
+            Some((app_id, extension))
+                if extension.eq_ignore_ascii_case("customDestinations-ms") =>
+            {
+                (app_id, JumpListKind::Custom)
+            }
+            _ => return Ok(None),
+        };
+        let bytes = fs::read(path)?;
+        let links = match kind {
+            JumpListKind::Automatic => automatic_links(&bytes),
+            JumpListKind::Custom => custom_links(&bytes),
+        };
+        Ok(Some(Self {
+            app_id: app_id.to_owned(),
+            kind,
+            path: path.to_owned(),
+            links,
+        }))
+    }
+
+    /// The `AppID` of the application the jump list belongs to, like
+    /// `9b9cdc69c1c24e2b`.
+    #[must_use]
+    pub fn app_id(&self) -> &str {
+        &self.app_id
+    }
+
+    /// The kind of the jump list.
+    #[must_use]
+    pub fn kind(&self) -> JumpListKind {
+        self.kind
+    }
+
+    /// The path of the jump list file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The entries of the jump list.
+    #[must_use]
+    pub fn links(&self) -> &[ShellLink] {
+        &self.links
+    }
+
+    /// The `AppUserModelID` of the application the jump list belongs to, if
+    /// one of its entries records it.
+    #[must_use]
+    pub fn app_user_model_id(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .filter_map(ShellLink::properties)
+            .filter_map(|properties| properties.get(APP_USER_MODEL, APP_USER_MODEL_ID))
+            .find_map(Value::as_str)
+    }
+}
+
+/// Decode the numbered streams of an automatic jump list, each of which is a
+/// shell link. Other streams, like the `DestList` stream that holds the MRU
+/// list, are skipped.
+///
+/// Streams are read one at a time, and no more bytes are read in total than
+/// the file holds.
+fn automatic_links(bytes: &[u8]) -> Vec<ShellLink> {
+    let file = match CompoundFile::parse(bytes) {
+        Some(file) => file,
+        None => return Vec::new(),
+    };
+    let mut budget = bytes.len();
+    file.streams()
+        .iter()
+        .filter(|stream| {
+            !stream.name.is_empty() && stream.name.chars().all(|c| c.is_ascii_hexdigit())
+        })
+        .filter_map(|stream| ShellLink::parse(&file.read(stream, &mut budget)).ok())
+        .collect()
+}
+
+/// Decode the shell links embedded in a custom jump list by scanning for
+/// shell link headers.
+fn custom_links(bytes: &[u8]) -> Vec<ShellLink> {
+    // The `HeaderSize` field, 0x4C, followed by the shell link CLSID.
+    let mut header = vec![0x4C, 0, 0, 0];
+    header.extend_from_slice(&shell_link::LINK_CLSID.to_bytes_le());
+    let mut links = Vec::new();
+    let mut offset = 0;
+    while let Some(found) = bytes[offset..]
+        .windows(header.len())
+        .position(|window| window == header.as_slice())
+    {
+        let start = offset + found;
+        // Resume the scan after the link so its extra data blocks are not
+        // taken for more links.
+        offset = match ShellLink::parse_prefix(&bytes[start..]) {
+            Ok((link, len)) => {
+                links.push(link);
+                start + len
+            }
+            Err(_) => start + header.len(),
+        };
+    }
+    links
+}
+
+/// A recently opened document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentItem {
+    name: String,
+    link_path: PathBuf,
+    modified: Option<SystemTime>,
+    target: Option<PathBuf>,
+    link: ShellLink,
+    app_ids: Vec<String>,
+    app_user_model_id: Option<String>,
+}
+
+impl RecentItem {
+    /// The name of the shortcut without the `.lnk` extension, usually the
+    /// file name of the document.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The path of the shortcut in the Recent folder.
+    #[must_use]
+    pub fn link_path(&self) -> &Path {
+        &self.link_path
+    }
+
+    /// The last modification time of the shortcut, which the shell updates
+    /// each time the document is opened.
+    #[must_use]
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    /// The resolved path of the document.
+    #[must_use]
+    pub fn target(&self) -> Option<&Path> {
+        self.target.as_deref()
+    }
+
+    /// The parsed shortcut.
+    #[must_use]
+    pub fn link(&self) -> &ShellLink {
+        &self.link
+    }
+
+    /// The `AppID`s of the jump lists that reference the document, which
+    /// identify the applications that opened it.
+    #[must_use]
+    pub fn app_ids(&self) -> &[String] {
+        &self.app_ids
+    }
+
+    /// The `AppUserModelID` of the first application that opened the
+    /// document, if its jump list records it.
+    #[must_use]
+    pub fn app_user_model_id(&self) -> Option<&str> {
+        self.app_user_model_id.as_deref()
+    }
+}
+
+/// Read the jump lists of the Recent folder `recent_dir`, automatic jump
+/// lists first, each ordered by file name.
+///
+/// Missing jump list folders are skipped, as are jump list files that
+/// cannot be read, for example because they were deleted while the folder
+/// was listed.
+///
+/// # Errors
+///
+/// If a jump list folder cannot be read, an error is returned.
+pub fn jump_lists(recent_dir: &Path) -> io::Result<Vec<JumpList>> {
+    let mut jump_lists = Vec::new();
+    for folder in &[AUTOMATIC_DESTINATIONS, CUSTOM_DESTINATIONS] {
+        let entries = match fs::read_dir(recent_dir.join(folder)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => continue,
+            Err(err) => return Err(err),
+        };
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect::<Vec<_>>();
+        paths.sort();
+        for path in paths {
+            if let Ok(jump_list) = JumpList::open(path) {
+                jump_lists.extend(jump_list);
+            }
+        }
+    }
+    Ok(jump_lists)
+}
+
+/// List the recent documents of the [`Recent`] folder as located by
+/// `resolver`.
+///
+/// Returns an empty list if the folder cannot be resolved or does not
+/// exist. See [`scan`] for details.
+///
+/// # Errors
+///
+/// If the folder exists but cannot be read, an error is returned.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::recent;
+/// use known_folders::SystemResolver;
+///
+/// for item in recent::items(&SystemResolver)? {
+///     println!("{:?} opened by {:?}", item.target(), item.app_ids());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// [`Recent`]: KnownFolder::Recent
+pub fn items<R>(resolver: &R) -> io::Result<Vec<RecentItem>>
+where
+    R: Resolver + ?Sized,
+{
+    match resolver.resolve(KnownFolder::Recent) {
+        Some(dir) => match scan(&dir, resolver) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            result => result,
+        },
+        None => Ok(Vec::new()),
+    }
+}
+
+/// List the recent documents of the Recent folder `recent_dir`, newest
+/// first.
+///
+/// Each `.lnk` file directly in `recent_dir` is a recent item. Shortcut
+/// targets anchored at a known folder are resolved with `resolver`. An item
+/// is attributed to every jump list with an entry that resolves to the same
+/// target. Shortcuts and jump lists that cannot be read or parsed, for
+/// example because they were deleted while the folder was scanned, are
+/// skipped.
+///
+/// # Errors
+///
+/// If `recent_dir` or one of its jump list folders cannot be read, an error
+/// is returned.
+pub fn scan<R>(recent_dir: &Path, resolver: &R) -> io::Result<Vec<RecentItem>>
+where
+    R: Resolver + ?Sized,
+{
+    let jump_lists = jump_lists(recent_dir)?
+        .into_iter()
+        .map(|jump_list| {
+            let targets = jump_list
+                .links()
+                .iter()
+                .filter_map(|link| link.resolve_target(resolver))
+                .collect::<Vec<_>>();
+            (jump_list, targets)
+        })
+        .collect::<Vec<_>>();
+    let mut items = Vec::new();
+    for entry in fs::read_dir(recent_dir)? {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let link_path = entry.path();
+        let is_link = link_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| extension.eq_ignore_ascii_case("lnk"));
+        let name = link_path.file_stem().and_then(|name| name.to_str());
+        let name = match (is_link, name) {
+            (true, Some(name)) => name.to_owned(),
+            _ => continue,
+        };
+        let link = match ShellLink::open(&link_path) {
+            Ok(link) => link,
+            Err(_) => continue,
+        };
+        let modified = match entry.metadata() {
+            Ok(metadata) => metadata.modified().ok(),
+            Err(_) => continue,
+        };
+        let target = link.resolve_target(resolver);
+        let owners = jump_lists
+            .iter()
+            .filter(|(_, targets)| {
+                target.as_deref().map_or(false, |target| {
+                    targets
+                        .iter()
+                        .any(|candidate| path::eq_ignore_case(candidate, target))
+                })
+            })
+            .map(|(jump_list, _)| jump_list)
+            .collect::<Vec<_>>();
+        items.push(RecentItem {
+            name,
+            link_path,
+            modified,
+            target,
+            app_ids: owners
+                .iter()
+                .map(|jump_list| jump_list.app_id().to_owned())
+                .collect(),
+            app_user_model_id: owners
+                .iter()
+                .find_map(|jump_list| jump_list.app_user_model_id())
+                .map(str::to_owned),
+            link,
+        });
+    }
+    sort_newest_first(&mut items);
+    Ok(items)
+}
+
+/// Sort `items` by modification time, newest first, and then by name.
+/// Items without a modification time sort last.
+fn sort_newest_first(items: &mut [RecentItem]) {
+    items.sort_by(|left, right| {
+        right
+            .modified
+            .cmp(&left.modified)
+            .then_with(|| left.name.cmp(&right.name))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::cfb;
+    use crate::shell_link::tests::{app_link, program_link};
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn list_recent_documents() {
+        let temp_dir = TempDir::new("recent");
+        let root = temp_dir.path();
+        let automatic = root.join(AUTOMATIC_DESTINATIONS);
+        let custom = root.join(CUSTOM_DESTINATIONS);
+        fs::create_dir_all(&automatic).unwrap();
+        fs::create_dir_all(&custom).unwrap();
+
+        let report = program_link(r"C:\Users\jsmith\Documents\report.docx", "", "");
+        let notes = program_link(r"C:\Users\jsmith\notes.txt", "", "");
+        let old = program_link(r"D:\old.txt", "", "");
+        for (name, link) in &[
+            ("old.txt.lnk", &old),
+            ("report.docx.lnk", &report),
+            ("notes.txt.lnk", &notes),
+        ] {
+            fs::write(root.join(name), link).unwrap();
+        }
+        fs::write(root.join("broken.lnk"), "").unwrap();
+        fs::write(root.join("desktop.ini"), "").unwrap();
+
+        let jump_list = cfb::tests::build(&[("DestList", b"\x04\0\0\0"), ("1", &report)]);
+        fs::write(
+            automatic.join("a7bd71699cd38d1c.automaticDestinations-ms"),
+            jump_list,
+        )
+        .unwrap();
+        let mut custom_list = b"\x02\0\0\0\x01\0\0\0".to_vec();
+        custom_list.extend_from_slice(&notes);
+        custom_list.extend_from_slice(&report);
+        custom_list.extend_from_slice(b"\xAB\xFB\xBF\xBA");
+        fs::write(
+            custom.join("9b9cdc69c1c24e2b.customDestinations-ms"),
+            custom_list,
+        )
+        .unwrap();
+        fs::write(custom.join("readme.txt"), "").unwrap();
+        // A jump list that cannot be read, like one deleted while the folder
+        // is listed, is skipped.
+        fs::create_dir(automatic.join("0000000000000000.automaticDestinations-ms")).unwrap();
+
+        let jump_lists = jump_lists(root).unwrap();
+        assert_eq!(
+            jump_lists
+                .iter()
+                .map(|jump_list| (
+                    jump_list.app_id(),
+                    jump_list.kind(),
+                    jump_list.links().len()
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("a7bd71699cd38d1c", JumpListKind::Automatic, 1),
+                ("9b9cdc69c1c24e2b", JumpListKind::Custom, 2),
+            ]
+        );
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Recent, root);
+        // Shortcuts written in quick succession can share a modification
+        // time, so compare by name; the order is tested separately.
+        let mut items = items(&snapshot).unwrap();
+        items.sort_by(|left, right| left.name().cmp(right.name()));
+        let summary = items
+            .iter()
+            .map(|item| (item.name(), item.app_ids().len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [("notes.txt", 1), ("old.txt", 0), ("report.docx", 2)]
+        );
+        assert_eq!(items[0].app_ids(), ["9b9cdc69c1c24e2b"]);
+        assert_eq!(
+            items[2].target(),
+            Some(Path::new(r"C:\Users\jsmith\Documents\report.docx"))
+        );
+        assert_eq!(items[2].app_user_model_id(), None);
+
+        snapshot.insert(KnownFolder::Recent, root.join("missing"));
+        assert!(super::items(&snapshot).unwrap().is_empty());
+    }
+
+    #[test]
+    fn custom_links_are_scanned_once() {
+        // A bare header without a terminal block takes the headers after it
+        // as extra data blocks; they are not parsed again as links.
+        let mut header = vec![0x4C, 0, 0, 0];
+        header.extend_from_slice(&shell_link::LINK_CLSID.to_bytes_le());
+        header.resize(0x4C, 0);
+        let bytes = header.repeat(20_000);
+        let links = custom_links(&bytes);
+        assert_eq!(links.len(), 2);
+        let blocks = links
+            .iter()
+            .map(|link| link.extra_data().len())
+            .sum::<usize>();
+        assert_eq!(blocks, 20_000 - 2);
+        assert!(
+            links
+                .iter()
+                .map(|link| link.extra_data().len())
+                .max()
+                .unwrap()
+                * 0x4C
+                <= 1 << 20
+        );
+    }
+
+    #[test]
+    fn newest_first() {
+        let item = |name: &str, modified: Option<u64>| RecentItem {
+            name: name.to_owned(),
+            link_path: PathBuf::from(format!("{name}.lnk")),
+            modified: modified.map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+            target: None,
+            link: ShellLink::parse(&program_link(r"C:\a.txt", "", "")).unwrap(),
+            app_ids: Vec::new(),
+            app_user_model_id: None,
+        };
+        let mut items = vec![
+            item("old.txt", Some(10)),
+            item("unknown.txt", None),
+            item("notes.txt", Some(30)),
+            item("b.txt", Some(20)),
+            item("a.txt", Some(20)),
+        ];
+        sort_newest_first(&mut items);
+        assert_eq!(
+            items.iter().map(RecentItem::name).collect::<Vec<_>>(),
+            ["notes.txt", "a.txt", "b.txt", "old.txt", "unknown.txt"]
+        );
+    }
+
+    #[test]
+    fn jump_list_app_user_model_id() {
+        let temp_dir = TempDir::new("jump-list");
+        let root = temp_dir.path();
+        fs::create_dir_all(root).unwrap();
+        let path = root.join("f01b4d95cf55d32a.automaticDestinations-ms");
+        let link = app_link(r"C:\Windows\explorer.exe", "Microsoft.Windows.Explorer");
+        fs::write(&path, cfb::tests::build(&[("1", &link)])).unwrap();
+        let jump_list = JumpList::open(&path).unwrap().unwrap();
+        assert_eq!(
+            jump_list.app_user_model_id(),
+            Some("Microsoft.Windows.Explorer")
+        );
+        assert_eq!(jump_list.path(), path);
+        assert_eq!(JumpList::open(root.join("notes.txt")).unwrap(), None);
+    }
+}
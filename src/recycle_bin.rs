@@ -0,0 +1,496 @@
+// src/recycle_bin.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reader for the contents of the Recycle Bin.
+//!
+//! [`RecycleBinFolder`] is a virtual folder that merges the `$Recycle.Bin`
+//! directories at the root of each volume. Every user has a subdirectory
+//! named after their SID, in which each deleted file or directory is stored
+//! as a pair: a `$R` payload, which is the deleted item renamed, and a `$I`
+//! file with the metadata of the item, like its original path and the time
+//! it was deleted.
+//!
+//! [`items`] lists the deleted items of a volume given the path of its root,
+//! like a mounted image, and [`Metadata`] parses a single `$I` file.
+//!
+//! [`RecycleBinFolder`]: crate::KnownFolder::RecycleBinFolder
+
+use core::fmt;
+use std::error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::bytes::{slice_at, u32_at, u64_at};
+use crate::text;
+
+/// The name of the Recycle Bin directory at the root of a volume.
+pub const DIRECTORY_NAME: &str = "$Recycle.Bin";
+
+/// The size of a version 1 `$I` file, whose path field holds `MAX_PATH`
+/// UTF-16 code units.
+const V1_SIZE: usize = 24 + 520;
+
+/// The kind of error that occurred while parsing a `$I` file.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A field extends past the end of the data.
+    Truncated,
+    /// The file has a version other than 1 or 2.
+    UnsupportedVersion,
+}
+
+/// Error returned when parsing a `$I` file fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the `$I` file where the error was detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Truncated => write!(f, "truncated $I file at offset {}", self.offset),
+            ParseErrorKind::UnsupportedVersion => f.write_str("unsupported $I file version"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error returned when reading a `$I` file fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+    /// The file is not a valid `$I` file.
+    Parse {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying parse error.
+        error: ParseError,
+    },
+}
+
+impl Error {
+    /// The path of the file that could not be read.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The metadata of a deleted item, parsed from a `$I` file.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::recycle_bin::Metadata;
+///
+/// let path = r"C:\Users\jsmith\report.txt";
+/// let mut data = Vec::new();
+/// data.extend_from_slice(&2_u64.to_le_bytes());
+/// data.extend_from_slice(&1024_u64.to_le_bytes());
+/// data.extend_from_slice(&0x01D9_230B_DEA9_F400_u64.to_le_bytes());
+/// data.extend_from_slice(&(path.len() as u32 + 1).to_le_bytes());
+/// data.extend(path.encode_utf16().chain(Some(0)).flat_map(u16::to_le_bytes));
+///
+/// let metadata = Metadata::parse(&data)?;
+/// assert_eq!(metadata.original_path(), path);
+/// assert_eq!(metadata.size(), 1024);
+/// # Ok::<(), known_folders::recycle_bin::ParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    version: u64,
+    size: u64,
+    deleted_at: u64,
+    original_path: String,
+}
+
+impl Metadata {
+    /// Parse the contents of a `$I` file.
+    ///
+    /// Version 1 files are written by Windows Vista through Windows 8.1 and
+    /// store the original path in a fixed `MAX_PATH` field. Version 2 files
+    /// are written by Windows 10 and later and store a length-prefixed path.
+    ///
+    /// # Errors
+    ///
+    /// If the version is not 1 or 2 or the data is truncated, an error is
+    /// returned.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let truncated = |offset| ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset,
+        };
+        let version = u64_at(bytes, 0).ok_or_else(|| truncated(0))?;
+        let size = u64_at(bytes, 8).ok_or_else(|| truncated(8))?;
+        let deleted_at = u64_at(bytes, 16).ok_or_else(|| truncated(16))?;
+        let path = match version {
+            1 => slice_at(bytes, 24, V1_SIZE - 24).ok_or_else(|| truncated(24))?,
+            2 => {
+                let len = u32_at(bytes, 24).ok_or_else(|| truncated(24))? as usize;
+                slice_at(bytes, 28, len.saturating_mul(2)).ok_or_else(|| truncated(28))?
+            }
+            _ => {
+                return Err(ParseError {
+                    kind: ParseErrorKind::UnsupportedVersion,
+                    offset: 0,
+                })
+            }
+        };
+        Ok(Self {
+            version,
+            size,
+            deleted_at,
+            original_path: text::decode_utf16le_nul(path),
+        })
+    }
+
+    /// Read and parse the `$I` file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a valid `$I` file, an error is
+    /// returned.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| Error::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        Self::parse(&bytes).map_err(|error| Error::Parse {
+            path: path.to_owned(),
+            error,
+        })
+    }
+
+    /// The format version of the `$I` file, 1 or 2.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// The size of the deleted item in bytes.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The time the item was deleted as a `FILETIME`.
+    #[must_use]
+    pub fn deleted_at(&self) -> u64 {
+        self.deleted_at
+    }
+
+    /// The path the item was deleted from, like `C:\Users\jsmith\report.txt`.
+    #[must_use]
+    pub fn original_path(&self) -> &str {
+        &self.original_path
+    }
+}
+
+/// A deleted item in the Recycle Bin of a volume.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    sid: String,
+    metadata_path: PathBuf,
+    payload_path: Option<PathBuf>,
+    metadata: Metadata,
+}
+
+impl Item {
+    /// The SID of the user who deleted the item, the name of the directory
+    /// the item is stored in.
+    #[must_use]
+    pub fn sid(&self) -> &str {
+        &self.sid
+    }
+
+    /// The path of the `$I` metadata file.
+    #[must_use]
+    pub fn metadata_path(&self) -> &Path {
+        &self.metadata_path
+    }
+
+    /// The path of the `$R` payload, which is a file or a directory.
+    ///
+    /// Returns [`None`] if the payload is missing, for example because it was
+    /// removed without the metadata.
+    #[must_use]
+    pub fn payload_path(&self) -> Option<&Path> {
+        self.payload_path.as_deref()
+    }
+
+    /// The metadata of the item.
+    #[must_use]
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
+/// List the deleted items in the Recycle Bin of the volume whose root is
+/// `volume_root`, like `C:\` or the mount point of a disk image.
+///
+/// Items are ordered by SID and file name. `$I` files that cannot be parsed
+/// are skipped, as are user directories that the current user is not
+/// permitted to read. A volume without a `$Recycle.Bin` directory has no
+/// items.
+///
+/// # Errors
+///
+/// If the Recycle Bin directory cannot be read, or a user directory cannot
+/// be read for a reason other than missing permissions, an error is
+/// returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use known_folders::recycle_bin;
+///
+/// for item in recycle_bin::items("/mnt/windows".as_ref())? {
+///     println!("{} -> {:?}", item.metadata().original_path(), item.payload_path());
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn items(volume_root: &Path) -> io::Result<Vec<Item>> {
+    let bin = match find_entry(volume_root, DIRECTORY_NAME)? {
+        Some(bin) => bin,
+        None => return Ok(Vec::new()),
+    };
+    let mut items = Vec::new();
+    for user in sorted_entries(&bin)? {
+        if !user.is_dir() {
+            continue;
+        }
+        let sid = match user.file_name().and_then(|name| name.to_str()) {
+            Some(sid) => sid.to_owned(),
+            None => continue,
+        };
+        // Other users' directories are usually not readable without
+        // elevation; skip them and list the items that can be read.
+        let entries = match sorted_entries(&user) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::PermissionDenied => continue,
+            Err(err) => return Err(err),
+        };
+        for metadata_path in entries {
+            let name = metadata_path.file_name().and_then(|name| name.to_str());
+            let suffix = match name.and_then(metadata_suffix) {
+                Some(suffix) => suffix.to_owned(),
+                None => continue,
+            };
+            let metadata = match Metadata::open(&metadata_path) {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+            let payload_path = find_entry(&user, &format!("$R{suffix}"))?;
+            items.push(Item {
+                sid: sid.clone(),
+                metadata_path,
+                payload_path,
+                metadata,
+            });
+        }
+    }
+    Ok(items)
+}
+
+/// Return the part of a `$I` file name that it shares with its `$R`
+/// payload, like `A1B2C3.txt` for `$IA1B2C3.txt`.
+fn metadata_suffix(name: &str) -> Option<&str> {
+    let prefix = name.get(..2)?;
+    if prefix.eq_ignore_ascii_case("$I") {
+        Some(&name[2..])
+    } else {
+        None
+    }
+}
+
+/// Return the entries of `dir` ordered by file name.
+fn sorted_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Find the entry of `dir` named `name`, ignoring case as NTFS does.
+fn find_entry(dir: &Path, name: &str) -> io::Result<Option<PathBuf>> {
+    let exact = dir.join(name);
+    if exact.exists() {
+        return Ok(Some(exact));
+    }
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let matches = entry
+            .file_name()
+            .to_str()
+            .map_or(false, |entry_name| text::eq_ignore_case(entry_name, name));
+        if matches {
+            return Ok(Some(entry.path()));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+
+    fn v1(path: &str, size: u64) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&1_u64.to_le_bytes());
+        data.extend_from_slice(&size.to_le_bytes());
+        data.extend_from_slice(&0x01D0_0000_0000_0000_u64.to_le_bytes());
+        data.extend(path.encode_utf16().flat_map(u16::to_le_bytes));
+        data.resize(V1_SIZE, 0);
+        data
+    }
+
+    fn v2(path: &str, size: u64) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&2_u64.to_le_bytes());
+        data.extend_from_slice(&size.to_le_bytes());
+        data.extend_from_slice(&0x01D9_0000_0000_0000_u64.to_le_bytes());
+        let units = path.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+        data.extend_from_slice(&u32::try_from(units.len()).unwrap().to_le_bytes());
+        data.extend(units.into_iter().flat_map(u16::to_le_bytes));
+        data
+    }
+
+    #[test]
+    fn parse_versions() {
+        let metadata = Metadata::parse(&v1(r"D:\old.doc", 12)).unwrap();
+        assert_eq!(metadata.version(), 1);
+        assert_eq!(metadata.original_path(), r"D:\old.doc");
+        assert_eq!(metadata.size(), 12);
+        assert_eq!(metadata.deleted_at(), 0x01D0_0000_0000_0000);
+
+        let metadata = Metadata::parse(&v2(r"C:\Users\jsmith\Desktop\notes", 0)).unwrap();
+        assert_eq!(metadata.version(), 2);
+        assert_eq!(metadata.original_path(), r"C:\Users\jsmith\Desktop\notes");
+
+        let err = Metadata::parse(&v1("x", 1)[..100]).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Truncated);
+        let mut data = v2("x", 1);
+        data[0] = 3;
+        let err = Metadata::parse(&data).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnsupportedVersion);
+    }
+
+    #[test]
+    fn pair_metadata_with_payloads() {
+        let temp_dir = TempDir::new("recycle-bin");
+        let root = temp_dir.path();
+        let user = root.join("$RECYCLE.BIN").join("S-1-5-21-1000");
+        fs::create_dir_all(user.join("$RB2C7Q1")).unwrap();
+        fs::write(
+            user.join("$IB2C7Q1"),
+            v2(r"C:\Users\jsmith\Desktop\notes", 0),
+        )
+        .unwrap();
+        fs::write(
+            user.join("$IA1B2C3.txt"),
+            v2(r"C:\Users\jsmith\report.txt", 5),
+        )
+        .unwrap();
+        fs::write(user.join("$RA1B2C3.txt"), "hello").unwrap();
+        fs::write(user.join("$IZZZZZZ.txt"), v1(r"C:\orphan.txt", 1)).unwrap();
+        fs::write(user.join("$ICORRUPT"), "bad").unwrap();
+        fs::write(user.join("desktop.ini"), "").unwrap();
+
+        let items = items(root).unwrap();
+        let summary = items
+            .iter()
+            .map(|item| {
+                (
+                    item.sid(),
+                    item.metadata().original_path(),
+                    item.payload_path().map(Path::is_dir),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                ("S-1-5-21-1000", r"C:\Users\jsmith\report.txt", Some(false)),
+                (
+                    "S-1-5-21-1000",
+                    r"C:\Users\jsmith\Desktop\notes",
+                    Some(true)
+                ),
+                ("S-1-5-21-1000", r"C:\orphan.txt", None),
+            ]
+        );
+        assert_eq!(
+            fs::read(items[0].payload_path().unwrap()).unwrap(),
+            b"hello"
+        );
+        assert!(items[0].metadata_path().ends_with("$IA1B2C3.txt"));
+        assert!(super::items(&root.join("S-1-5-21-1000"))
+            .unwrap()
+            .is_empty());
+    }
+}
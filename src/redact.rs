@@ -0,0 +1,411 @@
+// src/redact.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Redaction of folder paths in free-form text.
+
+use std::borrow::Cow;
+
+use crate::env::UNEXPAND_VARIABLES;
+use crate::path::is_separator;
+use crate::{Environment, KnownFolder, Resolver};
+
+/// A node of the folder trie. Edges are labeled with case-folded characters
+/// and runs of separators are a single `\` edge.
+#[derive(Default, Debug, Clone)]
+struct Node {
+    children: Vec<(char, usize)>,
+    placeholder: Option<usize>,
+}
+
+impl Node {
+    fn child(&self, c: char) -> Option<usize> {
+        self.children
+            .iter()
+            .find(|&&(label, _)| label == c)
+            .map(|&(_, child)| child)
+    }
+}
+
+/// Return `true` if `c` can be part of a file name next to a redacted folder
+/// without starting a new path or component.
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+/// Return `true` if `c` cannot appear in a Windows file name and so ends
+/// any path it follows.
+fn is_delimiter(c: char) -> bool {
+    c.is_control() || matches!(c, '"' | '<' | '>' | '|' | '?' | '*' | ':')
+}
+
+/// Return `true` if a folder that matched right before `rest` ends at a
+/// component boundary rather than inside a longer name like `jsmith.old` or
+/// `John Smith`.
+fn is_boundary(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    let first = match chars.next() {
+        Some(first) => first,
+        None => return true,
+    };
+    if is_separator(first) || is_delimiter(first) {
+        return true;
+    }
+    // Text that runs on to a separator is the rest of a longer name.
+    let runs_on = rest
+        .chars()
+        .find(|&c| is_separator(c) || is_delimiter(c))
+        .map_or(false, is_separator);
+    if runs_on {
+        return false;
+    }
+    if first.is_whitespace() {
+        return true;
+    }
+    // Punctuation like a full stop ends the path if nothing follows it.
+    !is_name_char(first)
+        && chars
+            .next()
+            .map_or(true, |next| next.is_whitespace() || is_delimiter(next))
+}
+
+/// Replaces folder paths inside arbitrary text with placeholders.
+///
+/// A redactor holds a set of folders, usually the resolved known folders of
+/// a user, and rewrites every occurrence of them in text like logs and crash
+/// reports, so the text no longer reveals user names or other details of the
+/// machine it came from.
+///
+/// Folders are matched with Windows path semantics: case is ignored, `\` and
+/// `/` are interchangeable, and runs of separators match a single separator,
+/// so escaped paths like `C:\\Users\\jsmith` in JSON are found too. Folders
+/// only match whole path components, so a folder `C:\Users\jsmith` is not
+/// found in `C:\Users\jsmith2` or `C:\Users\jsmith.old`. Because names may
+/// contain spaces, a folder followed by text that runs on to a separator,
+/// like a folder `C:\Users\John` in `C:\Users\John Smith\notes.txt`, is
+/// taken to be part of a longer name and is not redacted. When folders are
+/// nested, the longest match wins and the rest of the path is kept as
+/// written.
+///
+/// Text is scanned once with a trie of the folders, so the cost of redaction
+/// grows with the length of the text and not with the number of folders.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{KnownFolder, Redactor, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+/// snapshot.insert(KnownFolder::Downloads, r"C:\Users\jsmith\Downloads");
+///
+/// let redactor = Redactor::known_folders(&snapshot);
+/// assert_eq!(
+///     redactor.redact(r#"failed to open "c:/users/JSMITH/Downloads/setup.exe""#),
+///     r#"failed to open "<Downloads>/setup.exe""#,
+/// );
+///
+/// let redactor = Redactor::environment_variables(&snapshot);
+/// assert_eq!(
+///     redactor.redact(r"wrote C:\Users\jsmith\Downloads\report.txt"),
+///     r"wrote %USERPROFILE%\Downloads\report.txt",
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    nodes: Vec<Node>,
+    placeholders: Vec<String>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Redactor {
+    /// Construct a redactor that redacts nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+            placeholders: Vec::new(),
+        }
+    }
+
+    /// Construct a redactor that replaces every known folder located by
+    /// `resolver` with its name in angle brackets, like `<Profile>` or
+    /// `<LocalAppData>`.
+    ///
+    /// If several known folders have the same path, the first in
+    /// [`KnownFolder::ALL`] is used.
+    #[must_use]
+    pub fn known_folders<R>(resolver: &R) -> Self
+    where
+        R: Resolver + ?Sized,
+    {
+        let mut redactor = Self::new();
+        // Later insertions replace earlier ones, so insert in reverse to let
+        // the first folder with a path win.
+        for &known_folder in KnownFolder::ALL.iter().rev() {
+            let folder = resolver.resolve(known_folder);
+            if let Some(folder) = folder.as_ref().and_then(|folder| folder.to_str()) {
+                redactor.insert(folder, format!("<{known_folder:?}>"));
+            }
+        }
+        redactor
+    }
+
+    /// Construct a redactor that replaces the known folders located by
+    /// `resolver` with the environment variables that name them, like
+    /// `%USERPROFILE%` or `%LOCALAPPDATA%`.
+    ///
+    /// The variables are those substituted by [`Environment::unexpand`],
+    /// with the same precedence, except for `%SystemDrive%`: drive roots
+    /// are not redacted. Folders without a variable of their own are
+    /// redacted relative to an enclosing folder, like
+    /// `%USERPROFILE%\Documents`.
+    #[must_use]
+    pub fn environment_variables<R>(resolver: &R) -> Self
+    where
+        R: Resolver + ?Sized,
+    {
+        let env = Environment::from_known_folders(resolver);
+        let mut redactor = Self::new();
+        for &name in UNEXPAND_VARIABLES.iter().rev() {
+            if name == "SystemDrive" {
+                continue;
+            }
+            if let Some(value) = env.get(name) {
+                redactor.insert(value, format!("%{name}%"));
+            }
+        }
+        redactor
+    }
+
+    /// Redact occurrences of `folder` with `placeholder`.
+    ///
+    /// If `folder` was already inserted, its placeholder is replaced. Empty
+    /// folders are ignored.
+    pub fn insert<P>(&mut self, folder: &str, placeholder: P)
+    where
+        P: Into<String>,
+    {
+        let folder = folder.trim_end_matches(is_separator);
+        if folder.is_empty() {
+            return;
+        }
+        let mut node = 0;
+        let mut previous_separator = false;
+        for c in folder.chars() {
+            let separator = is_separator(c);
+            if separator && previous_separator {
+                continue;
+            }
+            previous_separator = separator;
+            let labels = if separator { '\\' } else { c };
+            for label in labels.to_lowercase() {
+                node = if let Some(child) = self.nodes[node].child(label) {
+                    child
+                } else {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.push((label, child));
+                    child
+                };
+            }
+        }
+        if let Some(index) = self.nodes[node].placeholder {
+            self.placeholders[index] = placeholder.into();
+        } else {
+            self.placeholders.push(placeholder.into());
+            self.nodes[node].placeholder = Some(self.placeholders.len() - 1);
+        }
+    }
+
+    /// Return `true` if this redactor has no folders to redact.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.placeholders.is_empty()
+    }
+
+    /// Find the longest folder at the start of `text`, returning the length
+    /// of the match in bytes and its placeholder.
+    fn longest_match(&self, text: &str) -> Option<(usize, usize)> {
+        let mut node = 0;
+        let mut found = None;
+        let mut previous_separator = false;
+        for (index, c) in text.char_indices() {
+            let separator = is_separator(c);
+            if separator && previous_separator {
+                continue;
+            }
+            if let Some(placeholder) = self.nodes[node].placeholder {
+                if is_boundary(&text[index..]) {
+                    found = Some((index, placeholder));
+                }
+            }
+            previous_separator = separator;
+            let label = if separator { '\\' } else { c };
+            for label in label.to_lowercase() {
+                node = match self.nodes[node].child(label) {
+                    Some(child) => child,
+                    None => return found,
+                };
+            }
+        }
+        if let Some(placeholder) = self.nodes[node].placeholder {
+            found = Some((text.len(), placeholder));
+        }
+        found
+    }
+
+    /// Replace every occurrence of a folder in `text` with its placeholder.
+    ///
+    /// Returns `text` unchanged without copying it if no folder occurs in
+    /// it.
+    #[must_use]
+    pub fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let root = &self.nodes[0];
+        let mut out = String::new();
+        let mut copied = 0;
+        let mut position = 0;
+        let mut previous = None;
+        while let Some(c) = text[position..].chars().next() {
+            // Folders start a path, not the middle of a name or component.
+            let can_start = match previous {
+                None => true,
+                Some(previous) if is_separator(c) => {
+                    !is_separator(previous) && !is_name_char(previous)
+                }
+                Some(previous) => !is_name_char(previous),
+            };
+            let first = if is_separator(c) {
+                '\\'
+            } else {
+                c.to_lowercase().next().unwrap_or(c)
+            };
+            if can_start && root.child(first).is_some() {
+                if let Some((len, placeholder)) = self.longest_match(&text[position..]) {
+                    out.push_str(&text[copied..position]);
+                    out.push_str(&self.placeholders[placeholder]);
+                    position += len;
+                    copied = position;
+                    previous = text[..position].chars().next_back();
+                    continue;
+                }
+            }
+            previous = Some(c);
+            position += c.len_utf8();
+        }
+        if copied == 0 {
+            return Cow::Borrowed(text);
+        }
+        out.push_str(&text[copied..]);
+        Cow::Owned(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Snapshot;
+
+    fn snapshot() -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        snapshot.insert(KnownFolder::LocalAppData, r"C:\Users\jsmith\AppData\Local");
+        snapshot.insert(KnownFolder::ProgramData, r"C:\ProgramData");
+        snapshot.insert(KnownFolder::Windows, r"C:\Windows");
+        snapshot.insert(KnownFolder::Music, r"\\fs01\home\jsmith\Music");
+        snapshot
+    }
+
+    #[test]
+    fn redact_known_folders() {
+        let redactor = Redactor::known_folders(&snapshot());
+        let log = concat!(
+            r"loading C:\Users\jsmith\AppData\Local\Acme\cache.db",
+            "\n",
+            r#"{"path":"C:\\Users\\jsmith\\Documents\\q3.xlsx"}"#,
+            "\n",
+            r"file:///c:/USERS/jsmith/Desktop/notes.txt",
+            "\n",
+            r"\\FS01\home\jsmith\Music\song.flac",
+            "\n",
+            r"C:\Users\jsmith2\x and D:\Users\jsmith\x and X:\C:\Users\jsmith.",
+        );
+        assert_eq!(
+            redactor.redact(log),
+            concat!(
+                r"loading <LocalAppData>\Acme\cache.db",
+                "\n",
+                r#"{"path":"<Documents>\\q3.xlsx"}"#,
+                "\n",
+                r"file:///<Profile>/Desktop/notes.txt",
+                "\n",
+                r"<Music>\song.flac",
+                "\n",
+                r"C:\Users\jsmith2\x and D:\Users\jsmith\x and X:\<Profile>.",
+            )
+        );
+        assert_eq!(redactor.redact(r"C:\Users\jsmith"), "<Profile>");
+        assert_eq!(
+            redactor.redact(r"moved C:\Users\jsmith.old\x to C:\Users\jsmith, done"),
+            r"moved C:\Users\jsmith.old\x to <Profile>, done"
+        );
+        assert!(matches!(
+            redactor.redact("nothing to see"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn redact_with_environment_variables() {
+        let redactor = Redactor::environment_variables(&snapshot());
+        assert_eq!(
+            redactor.redact(r"C:\Users\jsmith\Documents\a.txt;C:\ProgramData\b;C:\Windows\c"),
+            r"%USERPROFILE%\Documents\a.txt;%ALLUSERSPROFILE%\b;%SystemRoot%\c"
+        );
+        assert_eq!(redactor.redact(r"C:\Temp"), r"C:\Temp");
+    }
+
+    #[test]
+    fn names_with_spaces_are_not_split() {
+        let mut redactor = Redactor::new();
+        redactor.insert(r"C:\Users\John", "<Profile>");
+        assert_eq!(
+            redactor.redact(r"C:\Users\John Smith\notes.txt"),
+            r"C:\Users\John Smith\notes.txt"
+        );
+        assert_eq!(
+            redactor.redact(r"C:\Users\John is logged in to C:\Logs"),
+            r"<Profile> is logged in to C:\Logs"
+        );
+        assert_eq!(
+            redactor.redact("\"C:\\Users\\John\" (C:\\Users\\John)\nC:\\Users\\John"),
+            "\"<Profile>\" (<Profile>)\n<Profile>"
+        );
+    }
+
+    #[test]
+    fn redact_large_text() {
+        let mut redactor = Redactor::new();
+        assert!(redactor.is_empty());
+        assert!(matches!(redactor.redact(r"C:\Users"), Cow::Borrowed(_)));
+        redactor.insert(r"C:\Users\Jürgen\", "<Profile>");
+        assert!(!redactor.is_empty());
+        let line = "2023-04-01 12:00:00 INFO opened C:\\USERS\\JÜRGEN\\file.txt in 12ms\n";
+        let log = line.repeat(20_000);
+        let redacted = redactor.redact(&log);
+        assert_eq!(redacted.matches("<Profile>\\file.txt").count(), 20_000);
+        assert!(!redacted.contains("RGEN"));
+    }
+}
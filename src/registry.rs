@@ -0,0 +1,219 @@
+// src/registry.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Offline access to Windows registry data.
+//!
+//! Known folder locations are stored in the registry. This module contains
+//! platform-independent readers for registry data captured from a Windows
+//! system, which allows known folders to be resolved without calling into
+//! the Known Folders API.
+//!
+//! - [`export`] parses the text `.reg` files written by `reg export` and
+//!   `regedit`.
+//! - [`hive`] reads binary registry hive files such as `NTUSER.DAT`.
+
+use crate::text;
+
+pub mod export;
+pub mod hive;
+#[cfg(test)]
+pub(crate) mod test_hive;
+
+/// Registry value type `REG_NONE`.
+pub const REG_NONE: u32 = 0;
+/// Registry value type `REG_SZ`.
+pub const REG_SZ: u32 = 1;
+/// Registry value type `REG_EXPAND_SZ`.
+pub const REG_EXPAND_SZ: u32 = 2;
+/// Registry value type `REG_BINARY`.
+pub const REG_BINARY: u32 = 3;
+/// Registry value type `REG_DWORD`.
+pub const REG_DWORD: u32 = 4;
+/// Registry value type `REG_DWORD_BIG_ENDIAN`.
+pub const REG_DWORD_BIG_ENDIAN: u32 = 5;
+/// Registry value type `REG_LINK`.
+pub const REG_LINK: u32 = 6;
+/// Registry value type `REG_MULTI_SZ`.
+pub const REG_MULTI_SZ: u32 = 7;
+/// Registry value type `REG_QWORD`.
+pub const REG_QWORD: u32 = 11;
+
+/// Typed registry value data.
+///
+/// String data is decoded from UTF-16LE, the encoding Windows uses for
+/// registry strings. Trailing NUL terminators are removed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A `REG_SZ` string.
+    String(String),
+    /// A `REG_EXPAND_SZ` string containing unexpanded references to
+    /// environment variables like `%USERPROFILE%`.
+    ExpandString(String),
+    /// `REG_BINARY` data.
+    Binary(Vec<u8>),
+    /// A `REG_DWORD` number.
+    Dword(u32),
+    /// A `REG_DWORD_BIG_ENDIAN` number.
+    DwordBigEndian(u32),
+    /// A `REG_LINK` symbolic link target.
+    Link(String),
+    /// A `REG_MULTI_SZ` list of strings.
+    MultiString(Vec<String>),
+    /// A `REG_QWORD` number.
+    Qword(u64),
+    /// Data of any other type, or data that is malformed for its type.
+    Other {
+        /// The registry value type.
+        kind: u32,
+        /// The raw value data.
+        data: Vec<u8>,
+    },
+}
+
+impl Value {
+    /// Decode raw registry value data of the given type.
+    ///
+    /// Numeric data with an unexpected length is returned as
+    /// [`Value::Other`].
+    #[must_use]
+    pub fn from_raw(kind: u32, data: &[u8]) -> Self {
+        match kind {
+            REG_SZ => Self::String(text::decode_utf16le_nul(data)),
+            REG_EXPAND_SZ => Self::ExpandString(text::decode_utf16le_nul(data)),
+            REG_LINK => Self::Link(text::decode_utf16le_nul(data)),
+            REG_BINARY => Self::Binary(data.to_vec()),
+            REG_MULTI_SZ => Self::MultiString(split_multi_string(&text::decode_utf16le(data))),
+            REG_DWORD | REG_DWORD_BIG_ENDIAN if data.len() == 4 => {
+                let bytes = [data[0], data[1], data[2], data[3]];
+                if kind == REG_DWORD {
+                    Self::Dword(u32::from_le_bytes(bytes))
+                } else {
+                    Self::DwordBigEndian(u32::from_be_bytes(bytes))
+                }
+            }
+            REG_QWORD if data.len() == 8 => {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(data);
+                Self::Qword(u64::from_le_bytes(bytes))
+            }
+            _ => Self::Other {
+                kind,
+                data: data.to_vec(),
+            },
+        }
+    }
+
+    /// Return the registry value type of this data, e.g. [`REG_SZ`].
+    #[must_use]
+    pub fn kind(&self) -> u32 {
+        match self {
+            Self::String(_) => REG_SZ,
+            Self::ExpandString(_) => REG_EXPAND_SZ,
+            Self::Binary(_) => REG_BINARY,
+            Self::Dword(_) => REG_DWORD,
+            Self::DwordBigEndian(_) => REG_DWORD_BIG_ENDIAN,
+            Self::Link(_) => REG_LINK,
+            Self::MultiString(_) => REG_MULTI_SZ,
+            Self::Qword(_) => REG_QWORD,
+            Self::Other { kind, .. } => *kind,
+        }
+    }
+
+    /// Return the string data of `REG_SZ`, `REG_EXPAND_SZ`, and `REG_LINK`
+    /// values.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) | Self::ExpandString(s) | Self::Link(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Return the numeric data of `REG_DWORD` and `REG_DWORD_BIG_ENDIAN`
+    /// values.
+    #[must_use]
+    pub fn as_u32(&self) -> Option<u32> {
+        match *self {
+            Self::Dword(n) | Self::DwordBigEndian(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Return the numeric data of `REG_QWORD`, `REG_DWORD`, and
+    /// `REG_DWORD_BIG_ENDIAN` values.
+    #[must_use]
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::Qword(n) => Some(n),
+            Self::Dword(n) | Self::DwordBigEndian(n) => Some(u64::from(n)),
+            _ => None,
+        }
+    }
+}
+
+/// Split the decoded contents of a `REG_MULTI_SZ` value into its strings.
+///
+/// The list is terminated by an empty string; anything after the terminator
+/// is ignored.
+pub(crate) fn split_multi_string(data: &str) -> Vec<String> {
+    data.split('\0')
+        .take_while(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Return `true` if the registry key path `path` ends with the key path
+/// `suffix`, comparing whole path components and ignoring case.
+pub(crate) fn key_path_ends_with(path: &str, suffix: &str) -> bool {
+    let suffix = suffix.trim_matches('\\');
+    let mut path = path.trim_end_matches('\\').rsplit('\\');
+    suffix
+        .rsplit('\\')
+        .all(|component| matches!(path.next(), Some(c) if text::eq_ignore_case(c, component)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_raw_values() {
+        assert_eq!(
+            Value::from_raw(REG_EXPAND_SZ, b"%\0X\0%\0\0\0"),
+            Value::ExpandString("%X%".to_owned())
+        );
+        assert_eq!(
+            Value::from_raw(REG_MULTI_SZ, b"a\0\0\0b\0\0\0\0\0"),
+            Value::MultiString(vec!["a".to_owned(), "b".to_owned()])
+        );
+        assert_eq!(Value::from_raw(REG_DWORD, &[1, 0, 0, 0]), Value::Dword(1));
+        assert_eq!(
+            Value::from_raw(REG_DWORD_BIG_ENDIAN, &[0, 0, 0, 1]),
+            Value::DwordBigEndian(1)
+        );
+        assert_eq!(
+            Value::from_raw(REG_DWORD, &[1, 0]),
+            Value::Other {
+                kind: REG_DWORD,
+                data: vec![1, 0]
+            }
+        );
+    }
+
+    #[test]
+    fn key_path_suffix_matches_components() {
+        let path = r"HKEY_USERS\S-1-5-21-1\Software\Microsoft";
+        assert!(key_path_ends_with(path, r"software\microsoft"));
+        assert!(key_path_ends_with(path, r"\Microsoft\"));
+        assert!(!key_path_ends_with(path, r"ware\Microsoft"));
+        assert!(!key_path_ends_with(r"Microsoft", r"Software\Microsoft"));
+    }
+}
@@ -0,0 +1,582 @@
+// src/registry/export.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parser for text registry exports.
+//!
+//! `reg export` and `regedit` write registry keys to `.reg` files in one of two
+//! formats:
+//!
+//! - `Windows Registry Editor Version 5.00`, the default, which is encoded as
+//!   UTF-16LE with a byte order mark and stores `hex(2):` and `hex(7):` string
+//!   data as UTF-16LE.
+//! - `REGEDIT4`, which is encoded in the ANSI code page and stores string
+//!   data in the ANSI code page.
+//!
+//! Both formats are supported. See [`RegFile`] for details.
+
+use core::fmt;
+use std::error::Error;
+
+use crate::registry::{self, Value, REG_EXPAND_SZ, REG_LINK, REG_MULTI_SZ, REG_SZ};
+use crate::text;
+
+const REGEDIT5_HEADER: &str = "Windows Registry Editor Version 5.00";
+const REGEDIT4_HEADER: &str = "REGEDIT4";
+
+/// The format version of a `.reg` file.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Format {
+    /// The legacy `REGEDIT4` format with ANSI-encoded string data.
+    Regedit4,
+    /// The `Windows Registry Editor Version 5.00` format with UTF-16LE
+    /// string data.
+    Regedit5,
+}
+
+/// A parsed `.reg` file.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::registry::export::RegFile;
+/// use known_folders::registry::Value;
+///
+/// let reg = br#"Windows Registry Editor Version 5.00
+///
+/// [HKEY_CURRENT_USER\Software\Example]
+/// "Name"="value"
+/// "Count"=dword:0000002a
+/// "#;
+///
+/// let file = RegFile::parse(reg)?;
+/// let key = file.key(r"HKEY_CURRENT_USER\Software\Example").unwrap();
+/// assert_eq!(key.value("name"), Some(&Value::String("value".to_owned())));
+/// assert_eq!(key.value("Count"), Some(&Value::Dword(42)));
+/// # Ok::<(), known_folders::registry::export::ParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegFile {
+    format: Format,
+    keys: Vec<Key>,
+}
+
+impl RegFile {
+    /// Parse the raw bytes of a `.reg` file.
+    ///
+    /// The encoding is detected from the byte order mark. Files without a
+    /// byte order mark are decoded as UTF-8 if valid and as ANSI otherwise.
+    ///
+    /// Sections for the same key are merged: values in later sections replace
+    /// values with the same name in earlier sections.
+    ///
+    /// # Errors
+    ///
+    /// If the file does not begin with a `.reg` header or contains a line
+    /// that is not a comment, key header, or value assignment, an error is
+    /// returned that identifies the offending line.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_str(&text::decode_text_file(bytes))
+    }
+
+    /// Parse the already-decoded contents of a `.reg` file.
+    ///
+    /// # Errors
+    ///
+    /// See [`RegFile::parse`].
+    pub fn parse_str(contents: &str) -> Result<Self, ParseError> {
+        let mut lines = LogicalLines::new(contents);
+        let format = loop {
+            let (line_number, line) = lines
+                .next()
+                .ok_or_else(|| ParseError::new(1, ParseErrorKind::MissingHeader))?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            match line {
+                REGEDIT5_HEADER => break Format::Regedit5,
+                REGEDIT4_HEADER => break Format::Regedit4,
+                _ => return Err(ParseError::new(line_number, ParseErrorKind::MissingHeader)),
+            }
+        };
+
+        let mut keys = Vec::<Key>::new();
+        let mut current = None::<usize>;
+        for (line_number, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            if let Some(header) = line.strip_prefix('[') {
+                let end = header
+                    .rfind(']')
+                    .ok_or_else(|| ParseError::new(line_number, ParseErrorKind::InvalidKey))?;
+                let header = &header[..end];
+                let (path, deleted) = match header.strip_prefix('-') {
+                    Some(path) => (path, true),
+                    None => (header, false),
+                };
+                if path.is_empty() {
+                    return Err(ParseError::new(line_number, ParseErrorKind::InvalidKey));
+                }
+                let existing = keys.iter().position(|key| {
+                    key.deleted == deleted && text::eq_ignore_case(&key.path, path)
+                });
+                current = Some(existing.unwrap_or_else(|| {
+                    keys.push(Key {
+                        path: path.to_owned(),
+                        deleted,
+                        entries: Vec::new(),
+                    });
+                    keys.len() - 1
+                }));
+                continue;
+            }
+            let key = current
+                .and_then(|idx| keys.get_mut(idx))
+                .ok_or_else(|| ParseError::new(line_number, ParseErrorKind::ValueOutsideKey))?;
+            let entry = parse_value_line(line, format)
+                .map_err(|kind| ParseError::new(line_number, kind))?;
+            match key
+                .entries
+                .iter_mut()
+                .find(|existing| text::eq_ignore_case(&existing.name, &entry.name))
+            {
+                Some(existing) => *existing = entry,
+                None => key.entries.push(entry),
+            }
+        }
+        Ok(Self { format, keys })
+    }
+
+    /// Return the format version declared in the file header.
+    #[must_use]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Iterate over the key sections in this file, in the order they first
+    /// appear.
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.keys.iter()
+    }
+
+    /// Retrieve the key with the given full path, ignoring case.
+    ///
+    /// Key deletions (`[-HKEY_...]` sections) are not returned.
+    #[must_use]
+    pub fn key(&self, path: &str) -> Option<&Key> {
+        let path = path.trim_matches('\\');
+        self.keys
+            .iter()
+            .find(|key| !key.deleted && text::eq_ignore_case(&key.path, path))
+    }
+
+    /// Iterate over the keys whose path ends with the relative key path
+    /// `subkey`, comparing whole path components and ignoring case.
+    ///
+    /// This is useful for locating per-user keys regardless of whether the
+    /// export was taken from `HKEY_CURRENT_USER` or from a user's hive under
+    /// `HKEY_USERS`. Key deletions are not returned.
+    pub fn keys_ending_with<'a>(&'a self, subkey: &'a str) -> impl Iterator<Item = &'a Key> + 'a {
+        self.keys
+            .iter()
+            .filter(move |key| !key.deleted && registry::key_path_ends_with(&key.path, subkey))
+    }
+}
+
+/// A key section in a `.reg` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    path: String,
+    deleted: bool,
+    entries: Vec<Entry>,
+}
+
+impl Key {
+    /// The full path of this key, e.g. `HKEY_CURRENT_USER\Software`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Return `true` if this section deletes the key (`[-HKEY_...]`).
+    #[must_use]
+    pub fn is_deleted(&self) -> bool {
+        self.deleted
+    }
+
+    /// Iterate over the value entries of this key in file order.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Retrieve the data of the value `name`, ignoring case.
+    ///
+    /// The default value of the key is named by the empty string. Values that
+    /// this file deletes are not returned.
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&Value> {
+        self.entries
+            .iter()
+            .find(|entry| text::eq_ignore_case(&entry.name, name))
+            .and_then(Entry::data)
+    }
+
+    /// Iterate over the `(name, data)` pairs of the values this key sets,
+    /// skipping value deletions.
+    pub fn values(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| entry.data().map(|data| (entry.name(), data)))
+    }
+}
+
+/// A value line in a `.reg` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    name: String,
+    data: Option<Value>,
+}
+
+impl Entry {
+    /// The name of the value. The default value (`@`) is named by the empty
+    /// string.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value data, or [`None`] if this entry deletes the value
+    /// (`"Name"=-`).
+    #[must_use]
+    pub fn data(&self) -> Option<&Value> {
+        self.data.as_ref()
+    }
+
+    /// Return `true` if this entry deletes the value.
+    #[must_use]
+    pub fn is_deletion(&self) -> bool {
+        self.data.is_none()
+    }
+}
+
+/// The kind of error that occurred while parsing a `.reg` file.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The file does not begin with a `REGEDIT4` or `Windows Registry Editor
+    /// Version 5.00` header.
+    MissingHeader,
+    /// A key header is malformed.
+    InvalidKey,
+    /// A value appears before the first key header.
+    ValueOutsideKey,
+    /// A value name is malformed.
+    InvalidValueName,
+    /// Value data is malformed.
+    InvalidData,
+}
+
+/// Error returned when parsing a `.reg` file fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(line: usize, kind: ParseErrorKind) -> Self {
+        Self { line, kind }
+    }
+
+    /// The 1-based line number where the error occurred.
+    #[must_use]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::MissingHeader => "missing .reg file header",
+            ParseErrorKind::InvalidKey => "invalid key header",
+            ParseErrorKind::ValueOutsideKey => "value appears outside of a key",
+            ParseErrorKind::InvalidValueName => "invalid value name",
+            ParseErrorKind::InvalidData => "invalid value data",
+        };
+        write!(f, "{message} on line {}", self.line)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Iterator over the logical lines of a `.reg` file, joining lines that end
+/// with a `\` continuation marker.
+///
+/// Yields the 1-based line number where each logical line starts.
+struct LogicalLines<'a> {
+    lines: core::iter::Enumerate<core::str::Lines<'a>>,
+}
+
+impl<'a> LogicalLines<'a> {
+    fn new(contents: &'a str) -> Self {
+        Self {
+            lines: contents.lines().enumerate(),
+        }
+    }
+}
+
+impl Iterator for LogicalLines<'_> {
+    type Item = (usize, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, first) = self.lines.next()?;
+        let mut line = first.trim_end().to_owned();
+        while line.ends_with('\\') && !line.trim_start().starts_with('[') {
+            line.pop();
+            match self.lines.next() {
+                Some((_, next)) => line.push_str(next.trim()),
+                None => break,
+            }
+        }
+        Some((idx + 1, line))
+    }
+}
+
+fn parse_value_line(line: &str, format: Format) -> Result<Entry, ParseErrorKind> {
+    let (name, rest) = if let Some(rest) = line.strip_prefix('@') {
+        (String::new(), rest)
+    } else if let Some(rest) = line.strip_prefix('"') {
+        parse_quoted(rest).ok_or(ParseErrorKind::InvalidValueName)?
+    } else {
+        return Err(ParseErrorKind::InvalidValueName);
+    };
+    let data = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or(ParseErrorKind::InvalidValueName)?
+        .trim();
+    let data = parse_data(data, format)?;
+    Ok(Entry { name, data })
+}
+
+/// Parse a quoted string whose opening quote has already been consumed.
+///
+/// Returns the unescaped string and the remainder of the input after the
+/// closing quote.
+fn parse_quoted(input: &str) -> Option<(String, &str)> {
+    let mut out = String::new();
+    let mut chars = input.char_indices();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((out, &input[idx + 1..])),
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('\\' | '"'))) => out.push(escaped),
+                Some((_, other)) => {
+                    out.push('\\');
+                    out.push(other);
+                }
+                None => return None,
+            },
+            _ => out.push(ch),
+        }
+    }
+    None
+}
+
+fn parse_data(data: &str, format: Format) -> Result<Option<Value>, ParseErrorKind> {
+    if data == "-" {
+        return Ok(None);
+    }
+    if let Some(rest) = data.strip_prefix('"') {
+        let (s, rest) = parse_quoted(rest).ok_or(ParseErrorKind::InvalidData)?;
+        if !rest.trim().is_empty() {
+            return Err(ParseErrorKind::InvalidData);
+        }
+        return Ok(Some(Value::String(s)));
+    }
+    if let Some(hex) = strip_prefix_ignore_case(data, "dword:") {
+        if hex.is_empty() || hex.len() > 8 {
+            return Err(ParseErrorKind::InvalidData);
+        }
+        let n = u32::from_str_radix(hex, 16).map_err(|_| ParseErrorKind::InvalidData)?;
+        return Ok(Some(Value::Dword(n)));
+    }
+    let rest = strip_prefix_ignore_case(data, "hex").ok_or(ParseErrorKind::InvalidData)?;
+    let (kind, bytes) = if let Some(bytes) = rest.strip_prefix(':') {
+        (registry::REG_BINARY, bytes)
+    } else {
+        let rest = rest.strip_prefix('(').ok_or(ParseErrorKind::InvalidData)?;
+        let (kind, bytes) = rest.split_once("):").ok_or(ParseErrorKind::InvalidData)?;
+        let kind = u32::from_str_radix(kind, 16).map_err(|_| ParseErrorKind::InvalidData)?;
+        (kind, bytes)
+    };
+    let bytes = parse_hex_bytes(bytes)?;
+    let value = match (format, kind) {
+        (Format::Regedit4, REG_SZ) => Value::String(text::decode_ansi_nul(&bytes)),
+        (Format::Regedit4, REG_EXPAND_SZ) => Value::ExpandString(text::decode_ansi_nul(&bytes)),
+        (Format::Regedit4, REG_LINK) => Value::Link(text::decode_ansi_nul(&bytes)),
+        (Format::Regedit4, REG_MULTI_SZ) => {
+            Value::MultiString(registry::split_multi_string(&text::decode_ansi(&bytes)))
+        }
+        _ => Value::from_raw(kind, &bytes),
+    };
+    Ok(Some(value))
+}
+
+fn parse_hex_bytes(input: &str) -> Result<Vec<u8>, ParseErrorKind> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+    input
+        .split(',')
+        .map(|byte| {
+            let byte = byte.trim();
+            if byte.is_empty() || byte.len() > 2 {
+                return Err(ParseErrorKind::InvalidData);
+            }
+            u8::from_str_radix(byte, 16).map_err(|_| ParseErrorKind::InvalidData)
+        })
+        .collect()
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16_bom(s: &str) -> Vec<u8> {
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(s.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    const USER_SHELL_FOLDERS: &str = r#"Windows Registry Editor Version 5.00
+
+[HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders]
+"AppData"=hex(2):25,00,55,00,53,00,45,00,52,00,50,00,52,00,4f,00,46,00,49,00,\
+  4c,00,45,00,25,00,5c,00,41,00,70,00,70,00,44,00,61,00,74,00,61,00,5c,00,52,\
+  00,6f,00,61,00,6d,00,69,00,6e,00,67,00,00,00
+"Personal"="D:\\Redirected\\Documents"
+"{374DE290-123F-4565-9164-39C4925E467B}"=hex(2):25,00,55,00,53,00,45,00,52,00,\
+  50,00,52,00,4f,00,46,00,49,00,4c,00,45,00,25,00,5c,00,44,00,6f,00,77,00,6e,\
+  00,6c,00,6f,00,61,00,64,00,73,00,00,00
+"#;
+
+    #[test]
+    fn parse_utf16_export_with_continuations() {
+        let file = RegFile::parse(&utf16_bom(USER_SHELL_FOLDERS)).unwrap();
+        assert_eq!(file.format(), Format::Regedit5);
+        let key = file
+            .keys_ending_with(r"Explorer\User Shell Folders")
+            .next()
+            .unwrap();
+        assert_eq!(
+            key.value("AppData"),
+            Some(&Value::ExpandString(
+                r"%USERPROFILE%\AppData\Roaming".to_owned()
+            ))
+        );
+        assert_eq!(
+            key.value("personal"),
+            Some(&Value::String(r"D:\Redirected\Documents".to_owned()))
+        );
+        assert_eq!(
+            key.value("{374DE290-123F-4565-9164-39C4925E467B}"),
+            Some(&Value::ExpandString(r"%USERPROFILE%\Downloads".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_regedit4_ansi_strings() {
+        let mut bytes = b"REGEDIT4\r\n\r\n[HKEY_CURRENT_USER\\Software\\Example]\r\n".to_vec();
+        bytes.extend_from_slice(b"\"Expand\"=hex(2):25,54,45,4d,50,25,5c,e9,00\r\n");
+        bytes.extend_from_slice(b"\"Multi\"=hex(7):61,00,62,00,00\r\n");
+        bytes.extend_from_slice(b"\"Caf\xe9\"=\"cr\xe8me\"\r\n");
+        let file = RegFile::parse(&bytes).unwrap();
+        assert_eq!(file.format(), Format::Regedit4);
+        let key = file.key(r"hkey_current_user\software\example").unwrap();
+        assert_eq!(
+            key.value("Expand"),
+            Some(&Value::ExpandString("%TEMP%\\\u{e9}".to_owned()))
+        );
+        assert_eq!(
+            key.value("Multi"),
+            Some(&Value::MultiString(vec!["a".to_owned(), "b".to_owned()]))
+        );
+        assert_eq!(
+            key.value("Caf\u{e9}"),
+            Some(&Value::String("cr\u{e8}me".to_owned()))
+        );
+    }
+
+    #[test]
+    fn parse_deletions_defaults_and_merging() {
+        let contents = r#"Windows Registry Editor Version 5.00
+
+; comment
+[-HKEY_CURRENT_USER\Software\Gone]
+
+[HKEY_CURRENT_USER\Software\Example]
+@="default"
+"Removed"=-
+"Escaped"="say \"hi\" C:\\"
+"Qword"=hex(b):01,00,00,00,00,00,00,00
+
+[HKEY_CURRENT_USER\Software\EXAMPLE]
+"Escaped"=dword:00000001
+"#;
+        let file = RegFile::parse_str(contents).unwrap();
+        assert_eq!(file.keys().count(), 2);
+        assert!(file.key(r"HKEY_CURRENT_USER\Software\Gone").is_none());
+        assert!(file.keys().next().unwrap().is_deleted());
+
+        let key = file.key(r"HKEY_CURRENT_USER\Software\Example").unwrap();
+        assert_eq!(key.value(""), Some(&Value::String("default".to_owned())));
+        assert_eq!(key.value("Removed"), None);
+        assert!(key.entries().any(Entry::is_deletion));
+        assert_eq!(key.value("Escaped"), Some(&Value::Dword(1)));
+        assert_eq!(key.value("Qword"), Some(&Value::Qword(1)));
+        assert_eq!(key.values().count(), 3);
+    }
+
+    #[test]
+    fn parse_errors_report_line() {
+        let err = RegFile::parse_str("not a reg file").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::MissingHeader);
+
+        let err = RegFile::parse_str("REGEDIT4\n\"x\"=\"y\"\n").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::ValueOutsideKey);
+        assert_eq!(err.line(), 2);
+
+        let err =
+            RegFile::parse_str("REGEDIT4\n[HKEY_CURRENT_USER]\n\n\"x\"=dword:xyz\n").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidData);
+        assert_eq!(err.line(), 4);
+        assert_eq!(err.to_string(), "invalid value data on line 4");
+    }
+}
@@ -0,0 +1,699 @@
+// src/registry/hive.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Read-only parser for binary registry hive files.
+//!
+//! Windows persists each registry hive in a file in the `regf` format, for
+//! example `NTUSER.DAT` in each user's profile directory and `SOFTWARE` and
+//! `SYSTEM` in `%SystemRoot%\System32\config`. This module reads these files
+//! directly, which makes it possible to inspect the registry of a Windows
+//! installation that is not running, such as a mounted disk image.
+//!
+//! The parser supports the cell types needed to walk keys and read values:
+//! key nodes (`nk`), value keys (`vk`), the `lf`, `lh`, `li`, and `ri` subkey
+//! lists, and big data (`db`) records. Security and class name cells are
+//! ignored.
+//!
+//! # Dirty hives
+//!
+//! A hive whose primary and secondary sequence numbers differ was not cleanly
+//! written and has pending changes in its transaction logs (`.LOG1` and
+//! `.LOG2`). Transaction logs are not replayed. Dirty hives are still read,
+//! which yields the state of the hive as of its last clean flush. Use
+//! [`Hive::is_dirty`] to detect this condition.
+//!
+//! The format is documented in the [Windows registry file format
+//! specification].
+//!
+//! [Windows registry file format specification]: https://github.com/msuhanov/regf/blob/master/Windows%20registry%20file%20format%20specification.md
+
+use core::fmt;
+use std::error::Error;
+
+use crate::bytes;
+use crate::registry::Value;
+use crate::text;
+
+const BASE_BLOCK_SIZE: usize = 4096;
+const HBIN_HEADER_SIZE: usize = 32;
+const BIG_DATA_SEGMENT_SIZE: usize = 16344;
+const INLINE_DATA_FLAG: u32 = 0x8000_0000;
+const KEY_COMP_NAME: u16 = 0x0020;
+const VALUE_COMP_NAME: u16 = 0x0001;
+
+/// The kind of error that occurred while reading a registry hive.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The file does not begin with a `regf` base block.
+    InvalidSignature,
+    /// The file is a `regf` file of an unsupported type or format, such as a
+    /// transaction log.
+    Unsupported,
+    /// The file ends before the hive bins begin.
+    Truncated,
+    /// A cell offset points outside the hive bins or at a malformed cell.
+    InvalidCell,
+    /// A cell has an unexpected signature or layout for its position in the
+    /// key tree.
+    UnexpectedCell,
+}
+
+/// Error returned when reading a registry hive fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    fn new(kind: ParseErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+
+    fn cell(kind: ParseErrorKind, cell_offset: u32) -> Self {
+        let offset = usize::try_from(cell_offset)
+            .ok()
+            .and_then(|offset| offset.checked_add(BASE_BLOCK_SIZE))
+            .unwrap_or(usize::MAX);
+        Self::new(kind, offset)
+    }
+
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The offset in the hive file where the error was detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self.kind {
+            ParseErrorKind::InvalidSignature => "not a registry hive file",
+            ParseErrorKind::Unsupported => "unsupported registry hive file type",
+            ParseErrorKind::Truncated => "registry hive file is truncated",
+            ParseErrorKind::InvalidCell => "invalid cell in registry hive",
+            ParseErrorKind::UnexpectedCell => "unexpected cell type in registry hive",
+        };
+        write!(f, "{message} at offset {:#x}", self.offset)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A registry hive file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use known_folders::registry::hive::Hive;
+///
+/// let data = std::fs::read("/mnt/windows/Users/jsmith/NTUSER.DAT")?;
+/// let hive = Hive::parse(data)?;
+/// let key = hive.open_key(r"Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders")?;
+/// if let Some(key) = key {
+///     for entry in key.values()? {
+///         println!("{} = {:?}", entry.name(), entry.data());
+///     }
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Hive {
+    data: Vec<u8>,
+    root: u32,
+    dirty: bool,
+    checksum_valid: bool,
+    version: (u32, u32),
+    last_written: u64,
+}
+
+impl fmt::Debug for Hive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hive")
+            .field("len", &self.data.len())
+            .field("root", &self.root)
+            .field("dirty", &self.dirty)
+            .field("checksum_valid", &self.checksum_valid)
+            .field("version", &self.version)
+            .field("last_written", &self.last_written)
+            .finish()
+    }
+}
+
+impl Hive {
+    /// Parse the contents of a registry hive file.
+    ///
+    /// The base block is validated eagerly. Cells are decoded lazily as keys
+    /// and values are accessed.
+    ///
+    /// Hives with mismatched sequence numbers or an invalid base block
+    /// checksum are accepted; see [`Hive::is_dirty`] and
+    /// [`Hive::is_checksum_valid`].
+    ///
+    /// # Errors
+    ///
+    /// If `data` does not begin with a `regf` primary hive base block, or is
+    /// too short to contain the root key, an error is returned.
+    pub fn parse(data: Vec<u8>) -> Result<Self, ParseError> {
+        if data.get(..4) != Some(b"regf".as_ref()) {
+            return Err(ParseError::new(ParseErrorKind::InvalidSignature, 0));
+        }
+        if data.len() < BASE_BLOCK_SIZE + HBIN_HEADER_SIZE {
+            return Err(ParseError::new(ParseErrorKind::Truncated, data.len()));
+        }
+        let field = |offset| bytes::u32_at(&data, offset).unwrap_or_default();
+        let primary_sequence = field(4);
+        let secondary_sequence = field(8);
+        let last_written = bytes::u64_at(&data, 12).unwrap_or_default();
+        let version = (field(20), field(24));
+        let file_type = field(28);
+        let file_format = field(32);
+        let root = field(36);
+        let checksum = field(508);
+
+        // File type 0 is a primary hive file. Other types are transaction
+        // logs, which do not contain a key tree.
+        if file_type != 0 || file_format != 1 || version.0 != 1 {
+            return Err(ParseError::new(ParseErrorKind::Unsupported, 20));
+        }
+        if data.get(BASE_BLOCK_SIZE..BASE_BLOCK_SIZE + 4) != Some(b"hbin".as_ref()) {
+            return Err(ParseError::new(
+                ParseErrorKind::InvalidSignature,
+                BASE_BLOCK_SIZE,
+            ));
+        }
+
+        let hive = Self {
+            checksum_valid: base_block_checksum(&data) == checksum,
+            data,
+            root,
+            dirty: primary_sequence != secondary_sequence,
+            version,
+            last_written,
+        };
+        hive.root_key()?;
+        Ok(hive)
+    }
+
+    /// Return `true` if the hive was not cleanly written.
+    ///
+    /// A dirty hive has pending changes in its transaction logs which have not
+    /// been applied. The data in the hive file is still consistent as of the
+    /// last time it was flushed.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Return `true` if the checksum of the base block is valid.
+    #[must_use]
+    pub fn is_checksum_valid(&self) -> bool {
+        self.checksum_valid
+    }
+
+    /// The `(major, minor)` format version of the hive, e.g. `(1, 5)`.
+    #[must_use]
+    pub fn version(&self) -> (u32, u32) {
+        self.version
+    }
+
+    /// The time the hive was last written as a Windows `FILETIME`: the number
+    /// of 100-nanosecond intervals since January 1, 1601 UTC.
+    #[must_use]
+    pub fn last_written(&self) -> u64 {
+        self.last_written
+    }
+
+    /// Return the root key of the hive.
+    ///
+    /// # Errors
+    ///
+    /// If the root cell is not a valid key node, an error is returned.
+    pub fn root_key(&self) -> Result<Key<'_>, ParseError> {
+        Key::new(self, self.root)
+    }
+
+    /// Open the key at `path` relative to the root key.
+    ///
+    /// Path components are separated by `\` and compared ignoring case. The
+    /// empty path names the root key.
+    ///
+    /// Returns `Ok(None)` if the key does not exist.
+    ///
+    /// # Errors
+    ///
+    /// If a malformed cell is encountered while walking the key tree, an error
+    /// is returned.
+    pub fn open_key(&self, path: &str) -> Result<Option<Key<'_>>, ParseError> {
+        let mut key = self.root_key()?;
+        for component in path.split('\\').filter(|c| !c.is_empty()) {
+            match key.subkey(component)? {
+                Some(subkey) => key = subkey,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(key))
+    }
+
+    /// Return the data of the allocated cell at `offset`, relative to the
+    /// start of the hive bins.
+    fn cell(&self, offset: u32) -> Result<&[u8], ParseError> {
+        let invalid = || ParseError::cell(ParseErrorKind::InvalidCell, offset);
+        let start = usize::try_from(offset)
+            .ok()
+            .and_then(|offset| offset.checked_add(BASE_BLOCK_SIZE))
+            .ok_or_else(invalid)?;
+        let size = bytes::i32_at(&self.data, start).ok_or_else(invalid)?;
+        // Allocated cells have a negative size. Cells in dirty hives may be
+        // marked free while still being referenced, so accept both.
+        let size = usize::try_from(size.unsigned_abs()).map_err(|_| invalid())?;
+        if size < 4 {
+            return Err(invalid());
+        }
+        bytes::slice_at(&self.data, start + 4, size - 4).ok_or_else(invalid)
+    }
+
+    fn signed_cell(&self, offset: u32, signature: [u8; 2]) -> Result<&[u8], ParseError> {
+        let cell = self.cell(offset)?;
+        if cell.get(..2) == Some(signature.as_ref()) {
+            Ok(cell)
+        } else {
+            Err(ParseError::cell(ParseErrorKind::UnexpectedCell, offset))
+        }
+    }
+
+    /// Collect the key node offsets referenced by the subkey list at
+    /// `offset`.
+    fn subkey_offsets(
+        &self,
+        offset: u32,
+        out: &mut Vec<u32>,
+        nested: bool,
+    ) -> Result<(), ParseError> {
+        let cell = self.cell(offset)?;
+        let unexpected = || ParseError::cell(ParseErrorKind::UnexpectedCell, offset);
+        let signature = cell.get(..2).ok_or_else(unexpected)?;
+        let count = usize::from(bytes::u16_at(cell, 2).ok_or_else(unexpected)?);
+        let stride = match signature {
+            b"lf" | b"lh" => 8,
+            b"li" | b"ri" => 4,
+            _ => return Err(unexpected()),
+        };
+        for idx in 0..count {
+            let element = bytes::u32_at(cell, 4 + idx * stride).ok_or_else(unexpected)?;
+            if signature == b"ri" {
+                // Index roots may only reference leaves.
+                if nested {
+                    return Err(unexpected());
+                }
+                self.subkey_offsets(element, out, true)?;
+            } else {
+                out.push(element);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compute the base block checksum: the XOR of the first 127 `u32` fields,
+/// with `0` and `u32::MAX` remapped.
+fn base_block_checksum(data: &[u8]) -> u32 {
+    let checksum = (0..127)
+        .filter_map(|idx| bytes::u32_at(data, idx * 4))
+        .fold(0, |acc, n| acc ^ n);
+    match checksum {
+        u32::MAX => u32::MAX - 1,
+        0 => 1,
+        n => n,
+    }
+}
+
+fn decode_name(bytes: &[u8], compressed: bool) -> String {
+    if compressed {
+        text::decode_ansi(bytes)
+    } else {
+        text::decode_utf16le(bytes)
+    }
+}
+
+/// A key in a registry hive.
+#[derive(Clone, Copy)]
+pub struct Key<'a> {
+    hive: &'a Hive,
+    offset: u32,
+    cell: &'a [u8],
+}
+
+impl fmt::Debug for Key<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Key")
+            .field("name", &self.name())
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+impl<'a> Key<'a> {
+    const SUBKEY_COUNT: usize = 20;
+    const SUBKEY_LIST: usize = 28;
+    const VALUE_COUNT: usize = 36;
+    const VALUE_LIST: usize = 40;
+    const NAME_LEN: usize = 72;
+    const NAME: usize = 76;
+
+    fn new(hive: &'a Hive, offset: u32) -> Result<Self, ParseError> {
+        let cell = hive.signed_cell(offset, *b"nk")?;
+        if cell.len() < Self::NAME {
+            return Err(ParseError::cell(ParseErrorKind::InvalidCell, offset));
+        }
+        Ok(Self { hive, offset, cell })
+    }
+
+    fn field(&self, offset: usize) -> u32 {
+        // Bounds were checked in `Key::new`.
+        bytes::u32_at(self.cell, offset).unwrap_or_default()
+    }
+
+    /// The name of this key.
+    #[must_use]
+    pub fn name(&self) -> String {
+        let flags = bytes::u16_at(self.cell, 2).unwrap_or_default();
+        let len = usize::from(bytes::u16_at(self.cell, Self::NAME_LEN).unwrap_or_default());
+        let name = bytes::slice_at(self.cell, Self::NAME, len).unwrap_or_default();
+        decode_name(name, flags & KEY_COMP_NAME != 0)
+    }
+
+    /// The time this key was last written as a Windows `FILETIME`.
+    #[must_use]
+    pub fn last_written(&self) -> u64 {
+        bytes::u64_at(self.cell, 4).unwrap_or_default()
+    }
+
+    /// Return the subkeys of this key.
+    ///
+    /// # Errors
+    ///
+    /// If the subkey list or a subkey is malformed, an error is returned.
+    pub fn subkeys(&self) -> Result<Vec<Key<'a>>, ParseError> {
+        if self.field(Self::SUBKEY_COUNT) == 0 {
+            return Ok(Vec::new());
+        }
+        let mut offsets = Vec::new();
+        self.hive
+            .subkey_offsets(self.field(Self::SUBKEY_LIST), &mut offsets, false)?;
+        offsets
+            .into_iter()
+            .map(|offset| Key::new(self.hive, offset))
+            .collect()
+    }
+
+    /// Return the subkey of this key named `name`, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// If the subkey list or a subkey is malformed, an error is returned.
+    pub fn subkey(&self, name: &str) -> Result<Option<Key<'a>>, ParseError> {
+        Ok(self
+            .subkeys()?
+            .into_iter()
+            .find(|key| text::eq_ignore_case(&key.name(), name)))
+    }
+
+    /// Return the values of this key.
+    ///
+    /// # Errors
+    ///
+    /// If the value list or a value is malformed, an error is returned.
+    pub fn values(&self) -> Result<Vec<Entry>, ParseError> {
+        let count = usize::try_from(self.field(Self::VALUE_COUNT)).unwrap_or(usize::MAX);
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let list_offset = self.field(Self::VALUE_LIST);
+        let list = self.hive.cell(list_offset)?;
+        (0..count)
+            .map(|idx| {
+                let offset = idx
+                    .checked_mul(4)
+                    .and_then(|idx| bytes::u32_at(list, idx))
+                    .ok_or_else(|| ParseError::cell(ParseErrorKind::InvalidCell, list_offset))?;
+                self.read_value(offset)
+            })
+            .collect()
+    }
+
+    /// Return the data of the value named `name`, ignoring case.
+    ///
+    /// The default value of the key is named by the empty string.
+    ///
+    /// # Errors
+    ///
+    /// If the value list or a value is malformed, an error is returned.
+    pub fn value(&self, name: &str) -> Result<Option<Value>, ParseError> {
+        Ok(self
+            .values()?
+            .into_iter()
+            .find(|entry| text::eq_ignore_case(&entry.name, name))
+            .map(|entry| entry.data))
+    }
+
+    fn read_value(&self, offset: u32) -> Result<Entry, ParseError> {
+        let cell = self.hive.signed_cell(offset, *b"vk")?;
+        let invalid = || ParseError::cell(ParseErrorKind::InvalidCell, offset);
+        let name_len = usize::from(bytes::u16_at(cell, 2).ok_or_else(invalid)?);
+        let size = bytes::u32_at(cell, 4).ok_or_else(invalid)?;
+        let data_offset = bytes::u32_at(cell, 8).ok_or_else(invalid)?;
+        let kind = bytes::u32_at(cell, 12).ok_or_else(invalid)?;
+        let flags = bytes::u16_at(cell, 16).ok_or_else(invalid)?;
+        let name = bytes::slice_at(cell, 20, name_len).ok_or_else(invalid)?;
+        let name = decode_name(name, flags & VALUE_COMP_NAME != 0);
+
+        let data = if size & INLINE_DATA_FLAG != 0 {
+            let len = usize::try_from(size & !INLINE_DATA_FLAG).map_err(|_| invalid())?;
+            let inline = data_offset.to_le_bytes();
+            inline.get(..len).ok_or_else(invalid)?.to_vec()
+        } else {
+            let len = usize::try_from(size).map_err(|_| invalid())?;
+            self.read_data(data_offset, len)?
+        };
+        Ok(Entry {
+            name,
+            data: Value::from_raw(kind, &data),
+        })
+    }
+
+    fn read_data(&self, offset: u32, len: usize) -> Result<Vec<u8>, ParseError> {
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+        let cell = self.hive.cell(offset)?;
+        let invalid = || ParseError::cell(ParseErrorKind::InvalidCell, offset);
+        // Values larger than a single segment are stored as big data records
+        // in hives version 1.4 and later.
+        if len > BIG_DATA_SEGMENT_SIZE && self.hive.version.1 >= 4 && cell.get(..2) == Some(b"db") {
+            let segments = usize::from(bytes::u16_at(cell, 2).ok_or_else(invalid)?);
+            let list_offset = bytes::u32_at(cell, 4).ok_or_else(invalid)?;
+            let list = self.hive.cell(list_offset)?;
+            // The length is read from the file, so check that the segments
+            // can hold it before allocating.
+            if len > segments * BIG_DATA_SEGMENT_SIZE || len > self.hive.data.len() {
+                return Err(invalid());
+            }
+            let mut data = Vec::with_capacity(len);
+            for idx in 0..segments {
+                let segment_offset = bytes::u32_at(list, idx * 4)
+                    .ok_or_else(|| ParseError::cell(ParseErrorKind::InvalidCell, list_offset))?;
+                let segment = self.hive.cell(segment_offset)?;
+                let take = segment
+                    .len()
+                    .min(BIG_DATA_SEGMENT_SIZE)
+                    .min(len - data.len());
+                data.extend_from_slice(&segment[..take]);
+            }
+            if data.len() != len {
+                return Err(invalid());
+            }
+            return Ok(data);
+        }
+        Ok(cell.get(..len).ok_or_else(invalid)?.to_vec())
+    }
+}
+
+/// A value in a registry hive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    name: String,
+    data: Value,
+}
+
+impl Entry {
+    /// The name of the value. The default value of a key is named by the
+    /// empty string.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The value data.
+    #[must_use]
+    pub fn data(&self) -> &Value {
+        &self.data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::test_hive::{HiveBuilder, SubkeyList, TestKey};
+    use crate::registry::{REG_BINARY, REG_EXPAND_SZ};
+
+    fn fixture(list: SubkeyList) -> Vec<u8> {
+        let mut root = TestKey::new("ROOT");
+        root.key(r"Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders")
+            .expand_sz("Personal", r"%USERPROFILE%\Documents")
+            .sz("Desktop", r"D:\Desktop");
+        root.key("Environment")
+            .expand_sz("TEMP", r"%USERPROFILE%\AppData\Local\Temp")
+            .dword("Small", 7)
+            .raw("Big", REG_BINARY, vec![0xAB; 40_000])
+            .raw("", REG_EXPAND_SZ, Vec::new());
+        for name in [
+            "AppEvents",
+            "Console",
+            "Control Panel",
+            "Keyboard Layout",
+            "Printers",
+        ] {
+            root.key(name);
+        }
+        root.multi_sz("Multi", &["a", "b"]);
+        HiveBuilder::new().subkey_list(list).build(&root)
+    }
+
+    #[test]
+    fn read_keys_and_values() {
+        for list in [
+            SubkeyList::Lf,
+            SubkeyList::Lh,
+            SubkeyList::Li,
+            SubkeyList::Ri(2),
+        ] {
+            let hive = Hive::parse(fixture(list)).unwrap();
+            assert!(!hive.is_dirty());
+            assert!(hive.is_checksum_valid());
+            assert_eq!(hive.root_key().unwrap().name(), "ROOT");
+            assert_eq!(hive.root_key().unwrap().subkeys().unwrap().len(), 7);
+            assert_eq!(
+                hive.root_key().unwrap().value("multi").unwrap(),
+                Some(Value::MultiString(vec!["a".to_owned(), "b".to_owned()]))
+            );
+
+            let key = hive
+                .open_key(r"software\MICROSOFT\Windows\CurrentVersion\Explorer\User Shell Folders")
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                key.value("personal").unwrap(),
+                Some(Value::ExpandString(r"%USERPROFILE%\Documents".to_owned()))
+            );
+            assert_eq!(
+                key.value("Desktop").unwrap(),
+                Some(Value::String(r"D:\Desktop".to_owned()))
+            );
+            assert_eq!(key.value("Missing").unwrap(), None);
+            assert!(hive.open_key(r"Software\Missing").unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn read_inline_big_and_empty_data() {
+        let hive = Hive::parse(fixture(SubkeyList::Lh)).unwrap();
+        let env = hive.open_key("Environment").unwrap().unwrap();
+        assert_eq!(env.value("Small").unwrap(), Some(Value::Dword(7)));
+        assert_eq!(
+            env.value("Big").unwrap(),
+            Some(Value::Binary(vec![0xAB; 40_000]))
+        );
+        assert_eq!(
+            env.value("").unwrap(),
+            Some(Value::ExpandString(String::new()))
+        );
+        let names = env.values().unwrap();
+        assert_eq!(names.len(), 4);
+        assert_eq!(names[0].name(), "TEMP");
+    }
+
+    #[test]
+    fn reject_oversized_big_data() {
+        let mut data = fixture(SubkeyList::Lh);
+        // Find the value key cell of "Big" and inflate its data length far
+        // beyond its segments.
+        let vk = data
+            .windows(23)
+            .position(|window| window.starts_with(b"vk\x03\0") && &window[20..] == b"Big")
+            .unwrap();
+        data[vk + 4..vk + 8].copy_from_slice(&0x7FFF_0000_u32.to_le_bytes());
+        let hive = Hive::parse(data).unwrap();
+        let env = hive.open_key("Environment").unwrap().unwrap();
+        let err = env.value("Big").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidCell);
+    }
+
+    #[test]
+    fn dirty_hive_is_readable() {
+        let data = HiveBuilder::new()
+            .dirty(true)
+            .build(TestKey::new("ROOT").sz("Name", "Value"));
+        let hive = Hive::parse(data).unwrap();
+        assert!(hive.is_dirty());
+        assert_eq!(
+            hive.root_key().unwrap().value("Name").unwrap(),
+            Some(Value::String("Value".to_owned()))
+        );
+    }
+
+    #[test]
+    fn reject_invalid_files() {
+        let err = Hive::parse(b"not a hive".to_vec()).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidSignature);
+
+        let mut data = HiveBuilder::new().build(&TestKey::new("ROOT"));
+        data.truncate(100);
+        let err = Hive::parse(data).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Truncated);
+
+        let mut data = HiveBuilder::new().build(&TestKey::new("ROOT"));
+        // Point the root cell offset past the end of the file.
+        data[36..40].copy_from_slice(&0x00FF_FFFF_u32.to_le_bytes());
+        let err = Hive::parse(data).unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidCell);
+    }
+
+    #[test]
+    fn reject_corrupt_value_list() {
+        let mut data = HiveBuilder::new().build(TestKey::new("ROOT").sz("Name", "Value"));
+        let hive = Hive::parse(data.clone()).unwrap();
+        let root = hive.root_key().unwrap();
+        // Point the value list at the key node itself, whose first element
+        // is not a value key offset.
+        let value_list =
+            BASE_BLOCK_SIZE + usize::try_from(root.offset).unwrap() + 4 + Key::VALUE_LIST;
+        data[value_list..value_list + 4].copy_from_slice(&root.offset.to_le_bytes());
+        let hive = Hive::parse(data).unwrap();
+        assert!(hive.root_key().unwrap().values().is_err());
+    }
+}
@@ -0,0 +1,297 @@
+// src/registry/test_hive.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Builder for synthetic `regf` hive fixtures used in tests.
+//!
+//! Hives are written the way Windows lays them out: a 4 KiB base block
+//! followed by hive bins holding allocated cells with negative sizes.
+
+use crate::registry::{REG_DWORD, REG_EXPAND_SZ, REG_MULTI_SZ, REG_SZ};
+
+const BIG_DATA_SEGMENT_SIZE: usize = 16344;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TestKey {
+    name: String,
+    values: Vec<(String, u32, Vec<u8>)>,
+    subkeys: Vec<TestKey>,
+}
+
+fn utf16z(s: &str) -> Vec<u8> {
+    s.encode_utf16()
+        .chain(Some(0))
+        .flat_map(u16::to_le_bytes)
+        .collect()
+}
+
+impl TestKey {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            values: Vec::new(),
+            subkeys: Vec::new(),
+        }
+    }
+
+    /// Return the descendant key at `path`, creating missing keys.
+    pub(crate) fn key(&mut self, path: &str) -> &mut Self {
+        let mut key = self;
+        for component in path.split('\\').filter(|c| !c.is_empty()) {
+            let existing = key
+                .subkeys
+                .iter()
+                .position(|k| k.name.eq_ignore_ascii_case(component));
+            let idx = existing.unwrap_or_else(|| {
+                key.subkeys.push(Self::new(component));
+                key.subkeys.len() - 1
+            });
+            key = &mut key.subkeys[idx];
+        }
+        key
+    }
+
+    pub(crate) fn raw(&mut self, name: &str, kind: u32, data: Vec<u8>) -> &mut Self {
+        self.values.push((name.to_owned(), kind, data));
+        self
+    }
+
+    pub(crate) fn sz(&mut self, name: &str, value: &str) -> &mut Self {
+        self.raw(name, REG_SZ, utf16z(value))
+    }
+
+    pub(crate) fn expand_sz(&mut self, name: &str, value: &str) -> &mut Self {
+        self.raw(name, REG_EXPAND_SZ, utf16z(value))
+    }
+
+    pub(crate) fn multi_sz(&mut self, name: &str, values: &[&str]) -> &mut Self {
+        let mut data = values.iter().flat_map(|s| utf16z(s)).collect::<Vec<_>>();
+        data.extend_from_slice(&[0, 0]);
+        self.raw(name, REG_MULTI_SZ, data)
+    }
+
+    pub(crate) fn dword(&mut self, name: &str, value: u32) -> &mut Self {
+        self.raw(name, REG_DWORD, value.to_le_bytes().to_vec())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubkeyList {
+    Lf,
+    Lh,
+    Li,
+    /// An `ri` index root of `lh` leaves holding at most this many keys.
+    Ri(usize),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct HiveBuilder {
+    list: SubkeyList,
+    dirty: bool,
+    bins: Vec<u8>,
+}
+
+impl HiveBuilder {
+    pub(crate) fn new() -> Self {
+        Self {
+            list: SubkeyList::Lh,
+            dirty: false,
+            bins: Vec::new(),
+        }
+    }
+
+    pub(crate) fn subkey_list(mut self, list: SubkeyList) -> Self {
+        self.list = list;
+        self
+    }
+
+    pub(crate) fn dirty(mut self, dirty: bool) -> Self {
+        self.dirty = dirty;
+        self
+    }
+
+    pub(crate) fn build(mut self, root: &TestKey) -> Vec<u8> {
+        // Reserve the hive bin header; cell offsets are relative to it.
+        self.bins.resize(32, 0);
+        let root_offset = self.write_key(root, 0, true);
+        let padded = (self.bins.len() + 4095) / 4096 * 4096;
+        if padded > self.bins.len() {
+            // Mark the slack as a free cell.
+            let free = i32::try_from(padded - self.bins.len()).unwrap();
+            self.bins.extend_from_slice(&free.to_le_bytes());
+            self.bins.resize(padded, 0);
+        }
+        let bins_len = u32::try_from(self.bins.len()).unwrap();
+        self.bins[..4].copy_from_slice(b"hbin");
+        self.bins[8..12].copy_from_slice(&bins_len.to_le_bytes());
+
+        let mut base = vec![0_u8; 4096];
+        base[..4].copy_from_slice(b"regf");
+        base[4..8].copy_from_slice(&1_u32.to_le_bytes());
+        let secondary = u32::from(!self.dirty);
+        base[8..12].copy_from_slice(&secondary.to_le_bytes());
+        base[20..24].copy_from_slice(&1_u32.to_le_bytes());
+        base[24..28].copy_from_slice(&5_u32.to_le_bytes());
+        base[32..36].copy_from_slice(&1_u32.to_le_bytes());
+        base[36..40].copy_from_slice(&root_offset.to_le_bytes());
+        base[40..44].copy_from_slice(&bins_len.to_le_bytes());
+        let checksum = base[..508]
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .fold(0, |acc, n| acc ^ n);
+        base[508..512].copy_from_slice(&checksum.to_le_bytes());
+        base.extend_from_slice(&self.bins);
+        base
+    }
+
+    fn alloc(&mut self, data: &[u8]) -> u32 {
+        let offset = u32::try_from(self.bins.len()).unwrap();
+        let size = (data.len() + 4 + 7) / 8 * 8;
+        let signed = -i32::try_from(size).unwrap();
+        self.bins.extend_from_slice(&signed.to_le_bytes());
+        self.bins.extend_from_slice(data);
+        self.bins.resize(usize::try_from(offset).unwrap() + size, 0);
+        offset
+    }
+
+    fn write_key(&mut self, key: &TestKey, parent: u32, root: bool) -> u32 {
+        // Key nodes reference their parent, so reserve the node first.
+        let name = key.name.as_bytes();
+        let mut node = vec![0_u8; 76 + name.len()];
+        let node_offset = self.alloc(&node);
+
+        let mut children = key
+            .subkeys
+            .iter()
+            .map(|subkey| {
+                (
+                    subkey.name.to_uppercase(),
+                    self.write_key(subkey, node_offset, false),
+                )
+            })
+            .collect::<Vec<_>>();
+        children.sort();
+        let subkey_list = if children.is_empty() {
+            u32::MAX
+        } else {
+            self.write_subkey_list(&children)
+        };
+
+        let value_offsets = key
+            .values
+            .iter()
+            .map(|(name, kind, data)| self.write_value(name, *kind, data))
+            .collect::<Vec<_>>();
+        let value_list = if value_offsets.is_empty() {
+            u32::MAX
+        } else {
+            let list = value_offsets
+                .iter()
+                .flat_map(|offset| offset.to_le_bytes())
+                .collect::<Vec<_>>();
+            self.alloc(&list)
+        };
+
+        let flags: u16 = if root { 0x0024 } else { 0x0020 };
+        node[..2].copy_from_slice(b"nk");
+        node[2..4].copy_from_slice(&flags.to_le_bytes());
+        node[16..20].copy_from_slice(&parent.to_le_bytes());
+        node[20..24].copy_from_slice(&u32::try_from(children.len()).unwrap().to_le_bytes());
+        node[28..32].copy_from_slice(&subkey_list.to_le_bytes());
+        node[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+        node[36..40].copy_from_slice(&u32::try_from(key.values.len()).unwrap().to_le_bytes());
+        node[40..44].copy_from_slice(&value_list.to_le_bytes());
+        node[44..48].copy_from_slice(&u32::MAX.to_le_bytes());
+        node[48..52].copy_from_slice(&u32::MAX.to_le_bytes());
+        node[72..74].copy_from_slice(&u16::try_from(name.len()).unwrap().to_le_bytes());
+        node[76..].copy_from_slice(name);
+        let start = usize::try_from(node_offset).unwrap() + 4;
+        self.bins[start..start + node.len()].copy_from_slice(&node);
+        node_offset
+    }
+
+    fn write_leaf(&mut self, signature: [u8; 2], children: &[(String, u32)]) -> u32 {
+        let mut cell = signature.to_vec();
+        cell.extend_from_slice(&u16::try_from(children.len()).unwrap().to_le_bytes());
+        for (name, offset) in children {
+            cell.extend_from_slice(&offset.to_le_bytes());
+            match &signature {
+                b"lf" => {
+                    let mut hint = [0_u8; 4];
+                    for (dst, src) in hint.iter_mut().zip(name.bytes()) {
+                        *dst = src;
+                    }
+                    cell.extend_from_slice(&hint);
+                }
+                b"lh" => {
+                    let hash = name.bytes().fold(0_u32, |acc, b| {
+                        acc.wrapping_mul(37).wrapping_add(u32::from(b))
+                    });
+                    cell.extend_from_slice(&hash.to_le_bytes());
+                }
+                _ => {}
+            }
+        }
+        self.alloc(&cell)
+    }
+
+    fn write_subkey_list(&mut self, children: &[(String, u32)]) -> u32 {
+        match self.list {
+            SubkeyList::Lf => self.write_leaf(*b"lf", children),
+            SubkeyList::Lh => self.write_leaf(*b"lh", children),
+            SubkeyList::Li => self.write_leaf(*b"li", children),
+            SubkeyList::Ri(chunk) => {
+                let leaves = children
+                    .chunks(chunk)
+                    .map(|leaf| self.write_leaf(*b"lh", leaf))
+                    .collect::<Vec<_>>();
+                let mut cell = b"ri".to_vec();
+                cell.extend_from_slice(&u16::try_from(leaves.len()).unwrap().to_le_bytes());
+                for leaf in leaves {
+                    cell.extend_from_slice(&leaf.to_le_bytes());
+                }
+                self.alloc(&cell)
+            }
+        }
+    }
+
+    fn write_value(&mut self, name: &str, kind: u32, data: &[u8]) -> u32 {
+        let len = u32::try_from(data.len()).unwrap();
+        let (size, data_offset) = if data.len() <= 4 {
+            let mut inline = [0_u8; 4];
+            inline[..data.len()].copy_from_slice(data);
+            (len | 0x8000_0000, u32::from_le_bytes(inline))
+        } else if data.len() > BIG_DATA_SEGMENT_SIZE {
+            let segments = data
+                .chunks(BIG_DATA_SEGMENT_SIZE)
+                .map(|segment| self.alloc(segment))
+                .collect::<Vec<_>>();
+            let list = segments
+                .iter()
+                .flat_map(|offset| offset.to_le_bytes())
+                .collect::<Vec<_>>();
+            let list = self.alloc(&list);
+            let mut db = b"db".to_vec();
+            db.extend_from_slice(&u16::try_from(segments.len()).unwrap().to_le_bytes());
+            db.extend_from_slice(&list.to_le_bytes());
+            (len, self.alloc(&db))
+        } else {
+            (len, self.alloc(data))
+        };
+        let mut cell = b"vk".to_vec();
+        cell.extend_from_slice(&u16::try_from(name.len()).unwrap().to_le_bytes());
+        cell.extend_from_slice(&size.to_le_bytes());
+        cell.extend_from_slice(&data_offset.to_le_bytes());
+        cell.extend_from_slice(&kind.to_le_bytes());
+        cell.extend_from_slice(&1_u16.to_le_bytes());
+        cell.extend_from_slice(&[0, 0]);
+        cell.extend_from_slice(name.as_bytes());
+        self.alloc(&cell)
+    }
+}
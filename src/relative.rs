@@ -0,0 +1,169 @@
+// src/relative.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Reverse lookup of the known folder that contains a path.
+
+use std::path::Path;
+
+use crate::{path, text, KnownFolder, Resolver};
+
+/// Find the most specific known folder that contains `path`.
+///
+/// Returns the known folder and the remainder of `path` relative to it,
+/// joined with `\`. The remainder is empty if `path` is the known folder
+/// itself. Returns [`None`] if no known folder resolved by `resolver`
+/// contains `path`.
+///
+/// Paths are compared with Windows semantics on every platform: case is
+/// ignored, `\` and `/` are interchangeable, the `\\?\` prefix is ignored,
+/// `\\?\UNC\server\share` matches `\\server\share`, and `.` and `..`
+/// components are resolved lexically. When known folders are nested, like
+/// [`Documents`] inside [`Profile`], the deepest folder wins. Folders with
+/// the same path are tried in the order of [`KnownFolder::ALL`].
+///
+/// Every known folder is resolved on each call. To relativize many paths,
+/// [capture] a [`Snapshot`] of the resolver first.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{relativize, KnownFolder, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+/// snapshot.insert(KnownFolder::Downloads, r"C:\Users\jsmith\Downloads");
+///
+/// assert_eq!(
+///     relativize(r"c:\users\JSMITH\Downloads\setup.exe", &snapshot),
+///     Some((KnownFolder::Downloads, "setup.exe".to_owned())),
+/// );
+/// assert_eq!(
+///     relativize(r"\\?\C:\Users\jsmith\.vimrc", &snapshot),
+///     Some((KnownFolder::Profile, ".vimrc".to_owned())),
+/// );
+/// assert_eq!(relativize(r"C:\Windows\notepad.exe", &snapshot), None);
+/// ```
+///
+/// [`Documents`]: KnownFolder::Documents
+/// [`Profile`]: KnownFolder::Profile
+/// [`Snapshot`]: crate::Snapshot
+/// [capture]: crate::Snapshot::capture
+#[must_use]
+pub fn relativize<P, R>(path: P, resolver: &R) -> Option<(KnownFolder, String)>
+where
+    P: AsRef<Path>,
+    R: Resolver + ?Sized,
+{
+    let components = path::windows_components(path.as_ref().to_str()?);
+    let mut best: Option<(KnownFolder, usize)> = None;
+    for &known_folder in KnownFolder::ALL {
+        let folder = match resolver.resolve(known_folder) {
+            Some(folder) => folder,
+            None => continue,
+        };
+        let folder = match folder.to_str() {
+            Some(folder) => path::windows_components(folder),
+            None => continue,
+        };
+        let is_deeper = best.map_or(true, |(_, depth)| folder.len() > depth);
+        let contains = folder.len() <= components.len()
+            && folder
+                .iter()
+                .zip(&components)
+                .all(|(left, right)| text::eq_ignore_case(left, right));
+        if !folder.is_empty() && is_deeper && contains {
+            best = Some((known_folder, folder.len()));
+        }
+    }
+    best.map(|(known_folder, depth)| (known_folder, components[depth..].join("\\")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Snapshot;
+
+    fn snapshot() -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        snapshot.insert(
+            KnownFolder::RoamingAppData,
+            r"C:\Users\jsmith\AppData\Roaming",
+        );
+        snapshot.insert(
+            KnownFolder::Startup,
+            r"C:\Users\jsmith\AppData\Roaming\Microsoft\Windows\Start Menu\Programs\Startup",
+        );
+        snapshot.insert(KnownFolder::Music, r"\\fs01\home\jsmith\Music");
+        snapshot
+    }
+
+    #[test]
+    fn most_specific_folder_wins() {
+        let snapshot = snapshot();
+        assert_eq!(
+            relativize(r"C:\Users\jsmith\Documents\Reports\q3.xlsx", &snapshot),
+            Some((KnownFolder::Documents, r"Reports\q3.xlsx".to_owned()))
+        );
+        assert_eq!(
+            relativize(
+                r"C:\Users\jsmith\AppData\Roaming\Microsoft\Windows\Start Menu\Programs\Startup\sync.lnk",
+                &snapshot
+            ),
+            Some((KnownFolder::Startup, "sync.lnk".to_owned()))
+        );
+        assert_eq!(
+            relativize(
+                r"C:/Users/jsmith/AppData/Roaming/Code/settings.json",
+                &snapshot
+            ),
+            Some((
+                KnownFolder::RoamingAppData,
+                r"Code\settings.json".to_owned()
+            ))
+        );
+        assert_eq!(
+            relativize(r"C:\Users\jsmith\Documents\", &snapshot),
+            Some((KnownFolder::Documents, String::new()))
+        );
+        assert_eq!(
+            relativize(r"C:\Users\jsmith\Documents\..\Desktop\notes.txt", &snapshot),
+            Some((KnownFolder::Profile, r"Desktop\notes.txt".to_owned()))
+        );
+    }
+
+    #[test]
+    fn windows_path_semantics() {
+        let snapshot = snapshot();
+        assert_eq!(
+            relativize(r"\\?\c:\USERS\JSmith\documents\a.txt", &snapshot),
+            Some((KnownFolder::Documents, "a.txt".to_owned()))
+        );
+        assert_eq!(
+            relativize(r"\\?\UNC\FS01\home\jsmith\Music\song.flac", &snapshot),
+            Some((KnownFolder::Music, "song.flac".to_owned()))
+        );
+        assert_eq!(
+            relativize(r"//fs01/home/jsmith/Music", &snapshot),
+            Some((KnownFolder::Music, String::new()))
+        );
+        // A shared name prefix is not containment.
+        assert_eq!(relativize(r"C:\Users\jsmith2\a.txt", &snapshot), None);
+        assert_eq!(
+            relativize(r"C:\Users\jsmith\Documents2", &snapshot)
+                .unwrap()
+                .0,
+            KnownFolder::Profile
+        );
+        assert_eq!(relativize(r"D:\Users\jsmith", &snapshot), None);
+        assert_eq!(relativize(r"\\fs01\home", &snapshot), None);
+    }
+}
@@ -0,0 +1,266 @@
+// src/resolver.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::collections::btree_map::{self, BTreeMap};
+use std::iter::FromIterator;
+use std::path::{Path, PathBuf};
+
+use crate::{FolderId, FolderLocation, KnownFolder};
+
+/// A source of known folder locations.
+///
+/// On Windows, [`SystemResolver`] resolves known folders for the current user
+/// with the Known Folders API. Other resolvers compute known folder locations
+/// from registry exports, offline registry hives, or a fixed [`Snapshot`],
+/// which allows code built on top of this trait to run on any platform.
+///
+#[cfg_attr(windows, doc = "[`SystemResolver`]: crate::SystemResolver")]
+#[cfg_attr(
+    not(windows),
+    doc = "[`SystemResolver`]: https://learn.microsoft.com/en-us/windows/win32/shell/known-folders"
+)]
+pub trait Resolver {
+    /// Retrieve the full path of the given known folder.
+    ///
+    /// Returns [`None`] if the location of the known folder is not known to
+    /// this resolver.
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf>;
+
+    /// Retrieve the full path of the known folder with the given ID.
+    ///
+    /// The default implementation resolves built-in known folders with
+    /// [`Resolver::resolve`] and returns [`None`] for custom known folders.
+    /// Resolvers that can locate known folders registered by applications
+    /// override this method.
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        match id {
+            FolderId::Known(known_folder) => self.resolve(known_folder),
+            FolderId::Custom(_) => None,
+        }
+    }
+
+    /// Retrieve the location of the given known folder, falling back to the
+    /// shell namespace parsing name of virtual folders.
+    ///
+    /// Virtual folders like [`KnownFolder::RecycleBinFolder`] have no file
+    /// system path. Where [`Resolver::resolve`] returns [`None`] for them,
+    /// this method returns their [parsing name](KnownFolder::parsing_name).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{FolderLocation, KnownFolder, Resolver, Snapshot};
+    ///
+    /// let mut snapshot = Snapshot::new();
+    /// snapshot.insert(KnownFolder::Downloads, r"C:\Users\jsmith\Downloads");
+    ///
+    /// assert_eq!(
+    ///     snapshot.locate(KnownFolder::Downloads),
+    ///     Some(FolderLocation::FileSystem(r"C:\Users\jsmith\Downloads".into())),
+    /// );
+    /// assert_eq!(
+    ///     snapshot.locate(KnownFolder::ComputerFolder),
+    ///     KnownFolder::ComputerFolder.parsing_name().map(FolderLocation::Virtual),
+    /// );
+    /// assert_eq!(snapshot.locate(KnownFolder::Music), None);
+    /// ```
+    fn locate(&self, known_folder: KnownFolder) -> Option<FolderLocation> {
+        self.resolve(known_folder)
+            .map(FolderLocation::FileSystem)
+            .or_else(|| known_folder.parsing_name().map(FolderLocation::Virtual))
+    }
+}
+
+impl<R> Resolver for &R
+where
+    R: Resolver + ?Sized,
+{
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        (**self).resolve(known_folder)
+    }
+
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        (**self).resolve_id(id)
+    }
+
+    fn locate(&self, known_folder: KnownFolder) -> Option<FolderLocation> {
+        (**self).locate(known_folder)
+    }
+}
+
+/// A fixed mapping of known folders to paths.
+///
+/// Snapshots can be built by hand, captured from another [`Resolver`], or
+/// produced by the registry-backed resolvers in this crate.
+///
+/// Paths are stored as given. On non-Windows platforms a snapshot may hold
+/// Windows paths like `C:\Users\jsmith`, which are then opaque strings to the
+/// host [`Path`] APIs.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use known_folders::{KnownFolder, Resolver, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+///
+/// assert_eq!(
+///     snapshot.get(KnownFolder::Profile),
+///     Some(Path::new(r"C:\Users\jsmith")),
+/// );
+/// assert_eq!(snapshot.resolve(KnownFolder::Downloads), None);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    paths: BTreeMap<KnownFolder, PathBuf>,
+}
+
+impl Snapshot {
+    /// Construct a new, empty snapshot.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve every [`KnownFolder`] with `resolver` and record the results.
+    ///
+    /// Known folders the resolver cannot resolve are omitted.
+    #[must_use]
+    pub fn capture<R>(resolver: &R) -> Self
+    where
+        R: Resolver + ?Sized,
+    {
+        KnownFolder::ALL
+            .iter()
+            .filter_map(|&folder| resolver.resolve(folder).map(|path| (folder, path)))
+            .collect()
+    }
+
+    /// Record the path of `known_folder`, returning the previous path if one
+    /// was set.
+    pub fn insert<P>(&mut self, known_folder: KnownFolder, path: P) -> Option<PathBuf>
+    where
+        P: Into<PathBuf>,
+    {
+        self.paths.insert(known_folder, path.into())
+    }
+
+    /// Remove the path of `known_folder` from this snapshot.
+    pub fn remove(&mut self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.paths.remove(&known_folder)
+    }
+
+    /// Retrieve the path of `known_folder`.
+    #[must_use]
+    pub fn get(&self, known_folder: KnownFolder) -> Option<&Path> {
+        self.paths.get(&known_folder).map(PathBuf::as_path)
+    }
+
+    /// Return the number of known folders in this snapshot.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Return `true` if this snapshot contains no known folders.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Iterate over the known folders and paths in this snapshot in
+    /// [`KnownFolder`] declaration order.
+    #[must_use]
+    pub fn iter(&self) -> SnapshotIter<'_> {
+        SnapshotIter(self.paths.iter())
+    }
+}
+
+impl Resolver for Snapshot {
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.paths.get(&known_folder).cloned()
+    }
+}
+
+impl<P> FromIterator<(KnownFolder, P)> for Snapshot
+where
+    P: Into<PathBuf>,
+{
+    fn from_iter<I: IntoIterator<Item = (KnownFolder, P)>>(iter: I) -> Self {
+        let mut snapshot = Self::new();
+        snapshot.extend(iter);
+        snapshot
+    }
+}
+
+impl<P> Extend<(KnownFolder, P)> for Snapshot
+where
+    P: Into<PathBuf>,
+{
+    fn extend<I: IntoIterator<Item = (KnownFolder, P)>>(&mut self, iter: I) {
+        for (known_folder, path) in iter {
+            self.insert(known_folder, path);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Snapshot {
+    type Item = (KnownFolder, &'a Path);
+    type IntoIter = SnapshotIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`Snapshot`].
+///
+/// This struct is created by [`Snapshot::iter`].
+#[derive(Debug, Clone)]
+pub struct SnapshotIter<'a>(btree_map::Iter<'a, KnownFolder, PathBuf>);
+
+impl<'a> Iterator for SnapshotIter<'a> {
+    type Item = (KnownFolder, &'a Path);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|(&folder, path)| (folder, path.as_path()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SnapshotIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_copies_resolvable_folders() {
+        let mut source = Snapshot::new();
+        source.insert(KnownFolder::Profile, r"C:\Users\jsmith");
+        source.insert(KnownFolder::Desktop, r"C:\Users\jsmith\Desktop");
+
+        let captured = Snapshot::capture(&source);
+        assert_eq!(captured, source);
+
+        let entries = captured
+            .iter()
+            .map(|(folder, _)| folder)
+            .collect::<Vec<_>>();
+        assert_eq!(entries, [KnownFolder::Desktop, KnownFolder::Profile]);
+    }
+}
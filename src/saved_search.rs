@@ -0,0 +1,763 @@
+// src/saved_search.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Saved search files (`.search-ms`).
+//!
+//! The [`SavedSearches`] and [`SearchTemplates`] folders hold searches that
+//! Explorer can rerun, stored as XML `persistedQuery` documents. A saved
+//! search lists the folders it searches, often as known folder IDs or shell
+//! namespace CLSIDs, the query conditions, and how Explorer presents the
+//! results.
+//!
+//! [`SavedSearch`] parses saved search files and [`saved_searches`] reads the
+//! saved searches of the current user.
+//!
+//! [`SavedSearches`]: KnownFolder::SavedSearches
+//! [`SearchTemplates`]: KnownFolder::SearchTemplates
+
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::library::Location;
+use crate::pidl::clsid_known_folder;
+use crate::xml::{self, Element};
+use crate::{text, FolderId, Guid, KnownFolder, Resolver};
+
+/// The kind of error that occurred while parsing a saved search.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The file is not well-formed XML.
+    InvalidXml,
+    /// The root element is not `persistedQuery`.
+    NotASavedSearch,
+}
+
+/// Error returned when parsing a saved search fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the decoded document where the error was
+    /// detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::InvalidXml => write!(f, "invalid XML at offset {}", self.offset),
+            ParseErrorKind::NotASavedSearch => f.write_str("not a saved search"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error returned when reading a saved search file fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+    /// The file is not a valid saved search.
+    Parse {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying parse error.
+        error: ParseError,
+    },
+}
+
+impl Error {
+    /// The path of the file that could not be read.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// A folder included in or excluded from the scope of a saved search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScopeItem {
+    location: Location,
+    include: bool,
+    recursive: bool,
+}
+
+impl ScopeItem {
+    fn from_element(element: &Element) -> Option<Self> {
+        let include = match element.name.as_str() {
+            "include" => true,
+            "exclude" => false,
+            _ => return None,
+        };
+        let location = if let Some(guid) = element.attribute("knownFolder") {
+            Location::KnownFolder(FolderId::from_guid(guid.trim().parse().ok()?))
+        } else {
+            parse_scope_path(element.attribute("path")?.trim())
+        };
+        let recursive = !element.attribute("nonRecursive").map_or(false, |value| {
+            value.eq_ignore_ascii_case("true") || value == "1"
+        });
+        Some(Self {
+            location,
+            include,
+            recursive,
+        })
+    }
+
+    /// The location of the folder.
+    ///
+    /// Shell namespace locations like `::{20D04FE0-3AEA-1069-A2D8-08002B30309D}`
+    /// and `knownfolder:{GUID}` URLs are mapped to the known folder they
+    /// stand for.
+    #[must_use]
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    /// Return `true` if the folder is searched and `false` if it is excluded
+    /// from the search.
+    #[must_use]
+    pub fn is_included(&self) -> bool {
+        self.include
+    }
+
+    /// Return `true` if subfolders of the folder are searched too.
+    #[must_use]
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Resolve the location to a path.
+    ///
+    /// Known folder locations are resolved with `resolver`. File system
+    /// locations are returned as stored.
+    #[must_use]
+    pub fn resolve<R>(&self, resolver: &R) -> Option<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        match &self.location {
+            Location::KnownFolder(id) => resolver.resolve_id(*id),
+            Location::Path(path) => Some(path.into()),
+        }
+    }
+}
+
+/// Map a scope path to a known folder if it names one by GUID.
+fn parse_scope_path(path: &str) -> Location {
+    let known_folder = path
+        .get(..12)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("knownfolder:"))
+        .and_then(|_| path[12..].parse::<Guid>().ok());
+    if let Some(guid) = known_folder {
+        return Location::KnownFolder(FolderId::from_guid(guid));
+    }
+    let namespace = path
+        .get(..6)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("shell:"))
+        .map_or(path, |_| &path[6..]);
+    // Only single-segment namespace paths name a folder by CLSID alone.
+    let clsid = namespace
+        .strip_prefix("::")
+        .filter(|clsid| !clsid.contains('\\'))
+        .and_then(|clsid| clsid.parse::<Guid>().ok());
+    match clsid.and_then(clsid_known_folder) {
+        Some(known_folder) => Location::KnownFolder(FolderId::Known(known_folder)),
+        None => Location::Path(path.to_owned()),
+    }
+}
+
+/// A comparison of a property with a value, the leaf of a condition tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeafCondition {
+    property: String,
+    operator: String,
+    value: String,
+    value_type: Option<String>,
+}
+
+impl LeafCondition {
+    /// The canonical name of the compared property, like `System.FileName`.
+    #[must_use]
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// The comparison operator, like `wordmatch`, `eq`, or `gt`.
+    #[must_use]
+    pub fn operator(&self) -> &str {
+        &self.operator
+    }
+
+    /// The value the property is compared with.
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The structured query type of the value, like
+    /// `System.StructuredQueryType.String`.
+    #[must_use]
+    pub fn value_type(&self) -> Option<&str> {
+        self.value_type.as_deref()
+    }
+}
+
+/// A query condition of a saved search.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+// A `LeafCondition` is four strings, larger than the `Vec`s of the other
+// variants. Queries are mostly leaves, though, with a few `And` or `Or`
+// nodes between them, so boxing leaves would add an allocation to nearly
+// every condition to shrink only the rare inner nodes.
+#[allow(variant_size_differences)]
+pub enum Condition {
+    /// A comparison of a property with a value.
+    Leaf(LeafCondition),
+    /// All of the conditions hold.
+    And(Vec<Condition>),
+    /// Any of the conditions holds.
+    Or(Vec<Condition>),
+    /// None of the conditions holds.
+    Not(Vec<Condition>),
+}
+
+impl Condition {
+    fn from_element(element: &Element) -> Option<Self> {
+        if element.name != "condition" {
+            return None;
+        }
+        let children = || {
+            element
+                .children
+                .iter()
+                .filter_map(Self::from_element)
+                .collect()
+        };
+        match element.attribute("type")? {
+            "leafCondition" => Some(Self::Leaf(LeafCondition {
+                property: element.attribute("property")?.to_owned(),
+                operator: element.attribute("operator").unwrap_or_default().to_owned(),
+                value: element.attribute("value").unwrap_or_default().to_owned(),
+                value_type: element.attribute("valuetype").map(str::to_owned),
+            })),
+            "andCondition" => Some(Self::And(children())),
+            "orCondition" => Some(Self::Or(children())),
+            "notCondition" => Some(Self::Not(children())),
+            _ => None,
+        }
+    }
+}
+
+/// A column in the results view of a saved search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortColumn {
+    property: String,
+    descending: bool,
+}
+
+impl SortColumn {
+    /// The canonical name of the sorted property, like `System.DateModified`.
+    #[must_use]
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// Return `true` if results are sorted in descending order.
+    #[must_use]
+    pub fn is_descending(&self) -> bool {
+        self.descending
+    }
+}
+
+/// How Explorer presents the results of a saved search, from the `viewInfo`
+/// element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewInfo {
+    display_name: Option<String>,
+    view_mode: Option<String>,
+    icon_size: Option<u32>,
+    columns: Vec<String>,
+    sort: Vec<SortColumn>,
+}
+
+impl ViewInfo {
+    fn from_element(element: &Element) -> Self {
+        let columns = element
+            .child("visibleColumns")
+            .into_iter()
+            .flat_map(|columns| columns.children_named("column"))
+            .filter_map(|column| column.attribute("viewField"))
+            .map(str::to_owned)
+            .collect();
+        let sort = element
+            .child("sortList")
+            .into_iter()
+            .flat_map(|list| list.children_named("sort"))
+            .filter_map(|sort| {
+                Some(SortColumn {
+                    property: sort.attribute("viewField")?.to_owned(),
+                    descending: sort.attribute("direction").map_or(false, |direction| {
+                        direction.eq_ignore_ascii_case("descending")
+                    }),
+                })
+            })
+            .collect();
+        Self {
+            display_name: element.attribute("displayName").map(str::to_owned),
+            view_mode: element.attribute("viewMode").map(str::to_owned),
+            icon_size: element
+                .attribute("iconSize")
+                .and_then(|size| size.trim().parse().ok()),
+            columns,
+            sort,
+        }
+    }
+
+    /// The title of the search window.
+    #[must_use]
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// The view mode, like `details` or `icons`.
+    #[must_use]
+    pub fn view_mode(&self) -> Option<&str> {
+        self.view_mode.as_deref()
+    }
+
+    /// The icon size in pixels.
+    #[must_use]
+    pub fn icon_size(&self) -> Option<u32> {
+        self.icon_size
+    }
+
+    /// The canonical property names of the visible columns, in order.
+    #[must_use]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The sort order of the results.
+    #[must_use]
+    pub fn sort(&self) -> &[SortColumn] {
+        &self.sort
+    }
+}
+
+/// A parsed saved search file.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::library::Location;
+/// use known_folders::saved_search::{Condition, SavedSearch};
+/// use known_folders::{FolderId, KnownFolder};
+///
+/// let xml = r#"<?xml version="1.0"?>
+/// <persistedQuery version="1.0">
+///   <viewInfo viewMode="details" displayName="Spreadsheets"/>
+///   <query>
+///     <conditions>
+///       <condition type="leafCondition" property="System.FileExtension" operator="eq" value=".xlsx"/>
+///     </conditions>
+///     <scope>
+///       <include knownFolder="{FDD39AD0-238F-46AF-ADB4-6C85480369C7}"/>
+///     </scope>
+///   </query>
+/// </persistedQuery>"#;
+///
+/// let search = SavedSearch::parse_str(xml)?;
+/// assert_eq!(search.view().unwrap().display_name(), Some("Spreadsheets"));
+/// assert!(matches!(search.conditions(), [Condition::Leaf(_)]));
+/// assert_eq!(
+///     search.scope()[0].location(),
+///     &Location::KnownFolder(FolderId::Known(KnownFolder::Documents)),
+/// );
+/// # Ok::<(), known_folders::saved_search::ParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedSearch {
+    view: Option<ViewInfo>,
+    conditions: Vec<Condition>,
+    kinds: Vec<String>,
+    scope: Vec<ScopeItem>,
+}
+
+impl SavedSearch {
+    /// Parse the contents of a `.search-ms` file.
+    ///
+    /// The text may be UTF-8 or UTF-16 with a byte order mark.
+    ///
+    /// # Errors
+    ///
+    /// If the file is not a well-formed saved search, an error is returned.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_str(&text::decode_text_file(bytes))
+    }
+
+    /// Parse the text of a `.search-ms` file.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a well-formed saved search, an error is returned.
+    pub fn parse_str(contents: &str) -> Result<Self, ParseError> {
+        let root = xml::parse(contents).map_err(|offset| ParseError {
+            kind: ParseErrorKind::InvalidXml,
+            offset,
+        })?;
+        if root.name != "persistedQuery" {
+            return Err(ParseError {
+                kind: ParseErrorKind::NotASavedSearch,
+                offset: 0,
+            });
+        }
+        let query = root.child("query");
+        let conditions = query
+            .and_then(|query| query.child("conditions"))
+            .into_iter()
+            .flat_map(|conditions| conditions.children.iter())
+            .filter_map(Condition::from_element)
+            .collect();
+        let kinds = query
+            .and_then(|query| query.child("kindList"))
+            .into_iter()
+            .flat_map(|kinds| kinds.children_named("kind"))
+            .filter_map(|kind| kind.attribute("name"))
+            .map(str::to_owned)
+            .collect();
+        let scope = query
+            .and_then(|query| query.child("scope"))
+            .into_iter()
+            .flat_map(|scope| scope.children.iter())
+            .filter_map(ScopeItem::from_element)
+            .collect();
+        Ok(Self {
+            view: root.child("viewInfo").map(ViewInfo::from_element),
+            conditions,
+            kinds,
+            scope,
+        })
+    }
+
+    /// Read and parse the `.search-ms` file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a well-formed saved search, an
+    /// error is returned.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| Error::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        Self::parse(&bytes).map_err(|error| Error::Parse {
+            path: path.to_owned(),
+            error,
+        })
+    }
+
+    /// How Explorer presents the results.
+    #[must_use]
+    pub fn view(&self) -> Option<&ViewInfo> {
+        self.view.as_ref()
+    }
+
+    /// The top-level query conditions, which all must hold.
+    #[must_use]
+    pub fn conditions(&self) -> &[Condition] {
+        &self.conditions
+    }
+
+    /// The kinds of items searched for, like `item` or `folder`.
+    #[must_use]
+    pub fn kinds(&self) -> &[String] {
+        &self.kinds
+    }
+
+    /// The folders included in and excluded from the search.
+    #[must_use]
+    pub fn scope(&self) -> &[ScopeItem] {
+        &self.scope
+    }
+
+    /// Resolve the folders the search includes.
+    ///
+    /// Locations that cannot be resolved, like virtual folders, are skipped.
+    #[must_use]
+    pub fn included_folders<R>(&self, resolver: &R) -> Vec<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        self.scope
+            .iter()
+            .filter(|item| item.is_included())
+            .filter_map(|item| item.resolve(resolver))
+            .collect()
+    }
+}
+
+/// Read the saved searches in the [`SavedSearches`] folder as located by
+/// `resolver`, ordered by file name.
+///
+/// Returns an empty list if the folder cannot be resolved or does not
+/// exist. `.search-ms` files that cannot be read or parsed are skipped.
+///
+/// # Errors
+///
+/// If the folder cannot be read, an error is returned.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::saved_search::saved_searches;
+/// use known_folders::SystemResolver;
+///
+/// for (path, search) in saved_searches(&SystemResolver)? {
+///     println!("{}: {:?}", path.display(), search.included_folders(&SystemResolver));
+/// }
+/// # Ok::<(), known_folders::saved_search::Error>(())
+/// ```
+///
+/// [`SavedSearches`]: KnownFolder::SavedSearches
+pub fn saved_searches<R>(resolver: &R) -> Result<Vec<(PathBuf, SavedSearch)>, Error>
+where
+    R: Resolver + ?Sized,
+{
+    let dir = match resolver.resolve(KnownFolder::SavedSearches) {
+        Some(dir) => dir,
+        None => return Ok(Vec::new()),
+    };
+    let io_error = |error| Error::Io {
+        path: dir.clone(),
+        error,
+    };
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(io_error(err)),
+    };
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry.map_err(io_error)?.path();
+        let is_search = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| {
+                extension.eq_ignore_ascii_case("search-ms")
+            });
+        if is_search {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    let searches = paths
+        .into_iter()
+        .filter_map(|path| {
+            let search = SavedSearch::open(&path).ok()?;
+            Some((path, search))
+        })
+        .collect();
+    Ok(searches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    const SEARCH: &str = r#"<?xml version="1.0"?>
+<persistedQuery version="1.0">
+  <viewInfo viewMode="details" iconSize="16" stackIconSize="0" displayName="Large reports" autoListFlags="0">
+    <visibleColumns>
+      <column viewField="System.ItemNameDisplay"/>
+      <column viewField="System.Size"/>
+    </visibleColumns>
+    <sortList>
+      <sort viewField="System.Size" direction="descending"/>
+    </sortList>
+  </viewInfo>
+  <query>
+    <conditions>
+      <condition type="andCondition">
+        <condition type="leafCondition" property="System.FileName" operator="wordmatch" propertyType="string" value="report" valuetype="System.StructuredQueryType.String" localeName="en-US">
+          <attributes/>
+        </condition>
+        <condition type="notCondition">
+          <condition type="leafCondition" property="System.Size" operator="lt" value="1048576"/>
+        </condition>
+      </condition>
+    </conditions>
+    <kindList>
+      <kind name="item"/>
+    </kindList>
+    <scope>
+      <include knownFolder="{FDD39AD0-238F-46AF-ADB4-6C85480369C7}"/>
+      <include path="::{20D04FE0-3AEA-1069-A2D8-08002B30309D}"/>
+      <include path="knownfolder:{374DE290-123F-4565-9164-39C4925E467B}" nonRecursive="true"/>
+      <exclude path="D:\Archive"/>
+    </scope>
+  </query>
+</persistedQuery>"#;
+
+    #[test]
+    fn parse_saved_search() {
+        let search = SavedSearch::parse_str(SEARCH).unwrap();
+        let view = search.view().unwrap();
+        assert_eq!(view.display_name(), Some("Large reports"));
+        assert_eq!(view.view_mode(), Some("details"));
+        assert_eq!(view.icon_size(), Some(16));
+        assert_eq!(view.columns(), ["System.ItemNameDisplay", "System.Size"]);
+        assert_eq!(view.sort()[0].property(), "System.Size");
+        assert!(view.sort()[0].is_descending());
+        assert_eq!(search.kinds(), ["item"]);
+
+        let children = match search.conditions() {
+            [Condition::And(children)] => children,
+            conditions => panic!("unexpected conditions: {conditions:?}"),
+        };
+        match &children[0] {
+            Condition::Leaf(leaf) => {
+                assert_eq!(leaf.property(), "System.FileName");
+                assert_eq!(leaf.operator(), "wordmatch");
+                assert_eq!(leaf.value(), "report");
+                assert_eq!(leaf.value_type(), Some("System.StructuredQueryType.String"));
+            }
+            condition => panic!("unexpected condition: {condition:?}"),
+        }
+        assert!(matches!(&children[1], Condition::Not(inner) if inner.len() == 1));
+
+        let scope = search
+            .scope()
+            .iter()
+            .map(|item| {
+                (
+                    item.location().clone(),
+                    item.is_included(),
+                    item.is_recursive(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            scope,
+            [
+                (
+                    Location::KnownFolder(FolderId::Known(KnownFolder::Documents)),
+                    true,
+                    true
+                ),
+                (
+                    Location::KnownFolder(FolderId::Known(KnownFolder::ComputerFolder)),
+                    true,
+                    true
+                ),
+                (
+                    Location::KnownFolder(FolderId::Known(KnownFolder::Downloads)),
+                    true,
+                    false
+                ),
+                (Location::Path(r"D:\Archive".to_owned()), false, true),
+            ]
+        );
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Documents, r"C:\Users\jsmith\Documents");
+        assert_eq!(
+            search.included_folders(&snapshot),
+            [PathBuf::from(r"C:\Users\jsmith\Documents")]
+        );
+    }
+
+    #[test]
+    fn read_saved_searches() {
+        let temp_dir = TempDir::new("saved-search");
+        let root = temp_dir.path();
+        fs::create_dir_all(root).unwrap();
+        let mut utf16 = vec![0xFF, 0xFE];
+        utf16.extend(SEARCH.encode_utf16().flat_map(u16::to_le_bytes));
+        fs::write(root.join("reports.search-ms"), utf16).unwrap();
+        fs::write(root.join("desktop.ini"), "").unwrap();
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::SavedSearches, root);
+        let searches = saved_searches(&snapshot).unwrap();
+        assert_eq!(searches.len(), 1);
+        assert_eq!(searches[0].1.scope().len(), 4);
+
+        let broken = root.join("broken.search-ms");
+        fs::write(&broken, "<libraryDescription/>").unwrap();
+        fs::write(root.join("empty.search-ms"), "").unwrap();
+        let searches = saved_searches(&snapshot).unwrap();
+        assert_eq!(searches.len(), 1);
+        assert!(searches[0].0.ends_with("reports.search-ms"));
+
+        let err = SavedSearch::open(&broken).unwrap_err();
+        assert_eq!(err.path(), broken);
+        assert!(matches!(
+            err,
+            Error::Parse { error, .. } if error.kind() == ParseErrorKind::NotASavedSearch
+        ));
+        assert!(SavedSearch::parse_str("<persistedQuery>").is_err());
+
+        snapshot.insert(KnownFolder::SavedSearches, root.join("missing"));
+        assert!(saved_searches(&snapshot).unwrap().is_empty());
+    }
+}
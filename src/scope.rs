@@ -0,0 +1,179 @@
+// src/scope.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Per-user and all-users counterparts of known folders.
+
+use std::path::PathBuf;
+
+use crate::{path, KnownFolder, Resolver};
+
+/// Whether a folder belongs to the current user or is shared by all users of
+/// the machine.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Scope {
+    /// The folder belongs to the current user, like [`KnownFolder::Startup`].
+    PerUser,
+    /// The folder is shared by all users, like
+    /// [`KnownFolder::CommonStartup`].
+    AllUsers,
+}
+
+// Pairs of per-user and all-users known folders.
+const PAIRS: &[(KnownFolder, KnownFolder)] = &[
+    (KnownFolder::AccountPictures, KnownFolder::PublicUserTiles),
+    (KnownFolder::AdminTools, KnownFolder::CommonAdminTools),
+    (KnownFolder::Desktop, KnownFolder::PublicDesktop),
+    (KnownFolder::Documents, KnownFolder::PublicDocuments),
+    (KnownFolder::Downloads, KnownFolder::PublicDownloads),
+    (KnownFolder::GameTasks, KnownFolder::PublicGameTasks),
+    (KnownFolder::Libraries, KnownFolder::PublicLibraries),
+    (KnownFolder::Music, KnownFolder::PublicMusic),
+    (KnownFolder::Pictures, KnownFolder::PublicPictures),
+    (KnownFolder::Programs, KnownFolder::CommonPrograms),
+    (KnownFolder::Ringtones, KnownFolder::PublicRingtones),
+    (KnownFolder::StartMenu, KnownFolder::CommonStartMenu),
+    (KnownFolder::Startup, KnownFolder::CommonStartup),
+    (KnownFolder::Templates, KnownFolder::CommonTemplates),
+    (KnownFolder::UserProgramFiles, KnownFolder::ProgramFiles),
+    (
+        KnownFolder::UserProgramFilesCommon,
+        KnownFolder::ProgramFilesCommon,
+    ),
+    (KnownFolder::Videos, KnownFolder::PublicVideos),
+];
+
+impl KnownFolder {
+    /// Return the all-users known folder that corresponds to this per-user
+    /// known folder.
+    ///
+    /// Returns [`None`] if this is not a per-user folder with an all-users
+    /// counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::Programs.common_counterpart(), Some(KnownFolder::CommonPrograms));
+    /// assert_eq!(KnownFolder::Documents.common_counterpart(), Some(KnownFolder::PublicDocuments));
+    /// assert_eq!(KnownFolder::CommonPrograms.common_counterpart(), None);
+    /// ```
+    #[must_use]
+    pub fn common_counterpart(self) -> Option<Self> {
+        PAIRS
+            .iter()
+            .find(|&&(per_user, _)| per_user == self)
+            .map(|&(_, common)| common)
+    }
+
+    /// Return the per-user known folder that corresponds to this all-users
+    /// known folder.
+    ///
+    /// Returns [`None`] if this is not an all-users folder with a per-user
+    /// counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::CommonStartup.per_user_counterpart(), Some(KnownFolder::Startup));
+    /// assert_eq!(KnownFolder::Startup.per_user_counterpart(), None);
+    /// ```
+    #[must_use]
+    pub fn per_user_counterpart(self) -> Option<Self> {
+        PAIRS
+            .iter()
+            .find(|&&(_, common)| common == self)
+            .map(|&(per_user, _)| per_user)
+    }
+}
+
+/// Resolve both the per-user and the all-users scope of a known folder.
+///
+/// `known_folder` may name either scope. The returned search list holds the
+/// resolved per-user folder first, followed by the all-users folder, which is
+/// the order in which Explorer merges the two. Folders that cannot be
+/// resolved are skipped and a folder is listed once if both scopes resolve to
+/// the same path. Folders without a counterpart resolve to a list of at most
+/// one path.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+/// use known_folders::{search_paths, KnownFolder, Snapshot};
+///
+/// let mut snapshot = Snapshot::new();
+/// snapshot.insert(KnownFolder::Programs, r"C:\Users\jsmith\Start Menu\Programs");
+/// snapshot.insert(KnownFolder::CommonPrograms, r"C:\ProgramData\Start Menu\Programs");
+///
+/// assert_eq!(
+///     search_paths(&snapshot, KnownFolder::CommonPrograms),
+///     [
+///         PathBuf::from(r"C:\Users\jsmith\Start Menu\Programs"),
+///         PathBuf::from(r"C:\ProgramData\Start Menu\Programs"),
+///     ],
+/// );
+/// ```
+pub fn search_paths<R>(resolver: &R, known_folder: KnownFolder) -> Vec<PathBuf>
+where
+    R: Resolver + ?Sized,
+{
+    let (per_user, common) = match known_folder.per_user_counterpart() {
+        Some(per_user) => (per_user, Some(known_folder)),
+        None => (known_folder, known_folder.common_counterpart()),
+    };
+    let mut paths = Vec::with_capacity(2);
+    for folder in Some(per_user).into_iter().chain(common) {
+        if let Some(resolved) = resolver.resolve(folder) {
+            if !paths
+                .iter()
+                .any(|existing: &PathBuf| path::eq_ignore_case(existing, &resolved))
+            {
+                paths.push(resolved);
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Snapshot;
+
+    #[test]
+    fn pairs_are_consistent() {
+        for &folder in KnownFolder::ALL {
+            if let Some(common) = folder.common_counterpart() {
+                assert_eq!(common.per_user_counterpart(), Some(folder));
+                assert_eq!(folder.per_user_counterpart(), None, "{folder:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn search_paths_skip_missing_and_duplicate_scopes() {
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Startup, r"C:\Startup");
+        snapshot.insert(KnownFolder::Templates, r"C:\Templates");
+        snapshot.insert(KnownFolder::CommonTemplates, r"c:\templates\");
+        snapshot.insert(KnownFolder::Fonts, r"C:\Windows\Fonts");
+
+        assert_eq!(
+            search_paths(&snapshot, KnownFolder::CommonStartup),
+            [PathBuf::from(r"C:\Startup")]
+        );
+        assert_eq!(search_paths(&snapshot, KnownFolder::Templates).len(), 1);
+        assert_eq!(search_paths(&snapshot, KnownFolder::Fonts).len(), 1);
+        assert!(search_paths(&snapshot, KnownFolder::Music).is_empty());
+    }
+}
@@ -0,0 +1,540 @@
+// src/shell_folders.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Known folder locations stored under the `User Shell Folders` and `Shell
+//! Folders` registry keys.
+//!
+//! Windows records the location of redirectable known folders in the
+//! registry under [`USER_SHELL_FOLDERS`], relative to `HKEY_CURRENT_USER` for
+//! per-user folders and `HKEY_LOCAL_MACHINE` for common folders. Values are
+//! named either by the folder's **KNOWNFOLDERID** GUID or by a legacy name
+//! inherited from the `CSIDL` era, such as `Personal` or `My Pictures`.
+//!
+//! These keys can be read from a `.reg` export with [`ShellFolders`] or from
+//! a user's offline `NTUSER.DAT` hive with [`HiveResolver`].
+
+use std::path::PathBuf;
+
+use crate::registry::export::{Key, RegFile};
+use crate::registry::hive::{self, Hive};
+use crate::registry::Value;
+use crate::text;
+use crate::{Environment, FolderId, Guid, KnownFolder, Resolver, Snapshot};
+
+/// The registry key path, relative to a hive root, of the `User Shell
+/// Folders` key.
+///
+/// Values under this key are usually `REG_EXPAND_SZ` and reference
+/// environment variables such as `%USERPROFILE%`.
+pub const USER_SHELL_FOLDERS: &str =
+    r"Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders";
+
+/// The registry key path, relative to a hive root, of the legacy `Shell
+/// Folders` key.
+///
+/// Values under this key are fully expanded `REG_SZ` paths that Windows keeps
+/// for compatibility with applications that read the registry directly.
+pub const SHELL_FOLDERS: &str = r"Software\Microsoft\Windows\CurrentVersion\Explorer\Shell Folders";
+
+/// The registry key path, relative to the root of a user's hive, of the
+/// user's environment variables.
+pub const ENVIRONMENT: &str = "Environment";
+
+/// Legacy value names used under the `Shell Folders` keys, which predate
+/// Known Folder IDs.
+const LEGACY_NAMES: &[(&str, KnownFolder)] = &[
+    ("Administrative Tools", KnownFolder::AdminTools),
+    ("AppData", KnownFolder::RoamingAppData),
+    ("Cache", KnownFolder::InternetCache),
+    ("CD Burning", KnownFolder::CDBurning),
+    ("Common Administrative Tools", KnownFolder::CommonAdminTools),
+    ("Common AppData", KnownFolder::ProgramData),
+    ("Common Desktop", KnownFolder::PublicDesktop),
+    ("Common Documents", KnownFolder::PublicDocuments),
+    ("Common Programs", KnownFolder::CommonPrograms),
+    ("Common Start Menu", KnownFolder::CommonStartMenu),
+    ("Common Startup", KnownFolder::CommonStartup),
+    ("Common Templates", KnownFolder::CommonTemplates),
+    ("CommonMusic", KnownFolder::PublicMusic),
+    ("CommonPictures", KnownFolder::PublicPictures),
+    ("CommonVideo", KnownFolder::PublicVideos),
+    ("Cookies", KnownFolder::Cookies),
+    ("Desktop", KnownFolder::Desktop),
+    ("Favorites", KnownFolder::Favorites),
+    ("Fonts", KnownFolder::Fonts),
+    ("History", KnownFolder::History),
+    ("Local AppData", KnownFolder::LocalAppData),
+    ("My Music", KnownFolder::Music),
+    ("My Pictures", KnownFolder::Pictures),
+    ("My Video", KnownFolder::Videos),
+    ("NetHood", KnownFolder::NetHood),
+    ("OEM Links", KnownFolder::CommonOEMLinks),
+    ("Personal", KnownFolder::Documents),
+    ("PrintHood", KnownFolder::PrintHood),
+    ("Programs", KnownFolder::Programs),
+    ("Recent", KnownFolder::Recent),
+    ("SendTo", KnownFolder::SendTo),
+    ("Start Menu", KnownFolder::StartMenu),
+    ("Startup", KnownFolder::Startup),
+    ("Templates", KnownFolder::Templates),
+];
+
+/// Map a value name under the `User Shell Folders` or `Shell Folders` keys
+/// to the known folder it locates.
+///
+/// Names may be a braced **KNOWNFOLDERID** GUID or a legacy name. Matching
+/// ignores case, like the registry does.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::shell_folders::known_folder_for_value_name;
+/// use known_folders::KnownFolder;
+///
+/// assert_eq!(
+///     known_folder_for_value_name("Personal"),
+///     Some(KnownFolder::Documents),
+/// );
+/// assert_eq!(
+///     known_folder_for_value_name("{374DE290-123F-4565-9164-39C4925E467B}"),
+///     Some(KnownFolder::Downloads),
+/// );
+/// assert_eq!(known_folder_for_value_name("!Do not use this registry key"), None);
+/// ```
+#[must_use]
+pub fn known_folder_for_value_name(name: &str) -> Option<KnownFolder> {
+    folder_id_for_value_name(name).and_then(FolderId::known_folder)
+}
+
+/// Map a value name under the `User Shell Folders` or `Shell Folders` keys
+/// to the ID of the known folder it locates.
+///
+/// Unlike [`known_folder_for_value_name`], GUIDs of known folders registered
+/// by applications are recognized as [`FolderId::Custom`].
+#[must_use]
+pub fn folder_id_for_value_name(name: &str) -> Option<FolderId> {
+    if let Ok(guid) = name.parse::<Guid>() {
+        return Some(FolderId::from_guid(guid));
+    }
+    LEGACY_NAMES
+        .iter()
+        .find(|(legacy, _)| text::eq_ignore_case(legacy, name))
+        .map(|&(_, folder)| FolderId::Known(folder))
+}
+
+/// A single folder location value under a `Shell Folders` key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellFolder {
+    name: String,
+    id: Option<FolderId>,
+    path: String,
+    expandable: bool,
+}
+
+impl ShellFolder {
+    /// The registry value name.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The known folder this value locates, or [`None`] if the value name is
+    /// not recognized.
+    #[must_use]
+    pub fn known_folder(&self) -> Option<KnownFolder> {
+        self.id.and_then(FolderId::known_folder)
+    }
+
+    /// The ID of the known folder this value locates, or [`None`] if the
+    /// value name is not recognized.
+    #[must_use]
+    pub fn folder_id(&self) -> Option<FolderId> {
+        self.id
+    }
+
+    /// The path as stored in the registry, without environment variable
+    /// expansion.
+    #[must_use]
+    pub fn raw_path(&self) -> &str {
+        &self.path
+    }
+
+    /// Return `true` if the value is a `REG_EXPAND_SZ` that should have its
+    /// environment variable references expanded.
+    #[must_use]
+    pub fn is_expandable(&self) -> bool {
+        self.expandable
+    }
+
+    /// Return the path with environment variable references expanded
+    /// against `env`.
+    ///
+    /// `REG_SZ` values are returned as stored.
+    #[must_use]
+    pub fn expanded_path(&self, env: &Environment) -> String {
+        if self.expandable {
+            env.expand(&self.path)
+        } else {
+            self.path.clone()
+        }
+    }
+}
+
+/// The folder locations stored under a `User Shell Folders` or `Shell
+/// Folders` registry key.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use known_folders::registry::export::RegFile;
+/// use known_folders::shell_folders::ShellFolders;
+/// use known_folders::{Environment, KnownFolder};
+///
+/// let reg = br#"Windows Registry Editor Version 5.00
+///
+/// [HKEY_CURRENT_USER\Software\Microsoft\Windows\CurrentVersion\Explorer\User Shell Folders]
+/// "Personal"="\\\\fileserver\\home$\\jsmith\\Documents"
+/// "Desktop"=hex(2):25,00,55,00,53,00,45,00,52,00,50,00,52,00,4f,00,46,00,49,00,\
+///   4c,00,45,00,25,00,5c,00,44,00,65,00,73,00,6b,00,74,00,6f,00,70,00,00,00
+/// "#;
+///
+/// let file = RegFile::parse(reg)?;
+/// let folders = ShellFolders::from_reg_file(&file).unwrap();
+///
+/// let env = [("USERPROFILE", r"C:\Users\jsmith")].iter().copied().collect::<Environment>();
+/// let snapshot = folders.to_snapshot(&env);
+/// assert_eq!(
+///     snapshot.get(KnownFolder::Documents),
+///     Some(Path::new(r"\\fileserver\home$\jsmith\Documents")),
+/// );
+/// assert_eq!(
+///     snapshot.get(KnownFolder::Desktop),
+///     Some(Path::new(r"C:\Users\jsmith\Desktop")),
+/// );
+/// # Ok::<(), known_folders::registry::export::ParseError>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct ShellFolders {
+    folders: Vec<ShellFolder>,
+}
+
+impl ShellFolders {
+    /// Build from the `(name, data)` pairs of a `Shell Folders` key.
+    ///
+    /// Values that are not `REG_SZ` or `REG_EXPAND_SZ` strings are ignored.
+    #[must_use]
+    pub fn from_values<'a, I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, &'a Value)>,
+    {
+        let folders = values
+            .into_iter()
+            .filter_map(|(name, data)| {
+                let (path, expandable) = match data {
+                    Value::String(path) => (path, false),
+                    Value::ExpandString(path) => (path, true),
+                    _ => return None,
+                };
+                Some(ShellFolder {
+                    name: name.to_owned(),
+                    id: folder_id_for_value_name(name),
+                    path: path.clone(),
+                    expandable,
+                })
+            })
+            .collect();
+        Self { folders }
+    }
+
+    /// Build from a key section of a `.reg` file.
+    #[must_use]
+    pub fn from_reg_key(key: &Key) -> Self {
+        Self::from_values(key.values())
+    }
+
+    /// Build from a key in a registry hive.
+    ///
+    /// # Errors
+    ///
+    /// If the key's values are malformed, an error is returned.
+    pub fn from_hive_key(key: &hive::Key<'_>) -> Result<Self, hive::ParseError> {
+        let values = key.values()?;
+        Ok(Self::from_values(
+            values.iter().map(|entry| (entry.name(), entry.data())),
+        ))
+    }
+
+    /// Locate the `User Shell Folders` key in a `.reg` file and build from
+    /// its values.
+    ///
+    /// The key is found by its path relative to the hive root, so exports
+    /// taken from `HKEY_CURRENT_USER`, `HKEY_LOCAL_MACHINE`, or a user's hive
+    /// under `HKEY_USERS` are all supported. If the export contains more than
+    /// one matching key, the first is used.
+    ///
+    /// Returns [`None`] if the file does not contain the key.
+    #[must_use]
+    pub fn from_reg_file(file: &RegFile) -> Option<Self> {
+        file.keys_ending_with(USER_SHELL_FOLDERS)
+            .next()
+            .map(Self::from_reg_key)
+    }
+
+    /// Iterate over all folder values, including those whose names are not
+    /// recognized.
+    pub fn iter(&self) -> impl Iterator<Item = &ShellFolder> {
+        self.folders.iter()
+    }
+
+    /// Retrieve the value that locates `known_folder`.
+    #[must_use]
+    pub fn get(&self, known_folder: KnownFolder) -> Option<&ShellFolder> {
+        self.folders
+            .iter()
+            .find(|folder| folder.known_folder() == Some(known_folder))
+    }
+
+    /// Retrieve the value that locates the known folder with ID `id`.
+    #[must_use]
+    pub fn get_by_id(&self, id: FolderId) -> Option<&ShellFolder> {
+        self.folders.iter().find(|folder| folder.id == Some(id))
+    }
+
+    /// Resolve every recognized folder value, expanding environment variable
+    /// references against `env`.
+    #[must_use]
+    pub fn to_snapshot(&self, env: &Environment) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        for folder in self.folders.iter().rev() {
+            if let Some(known_folder) = folder.known_folder() {
+                snapshot.insert(known_folder, folder.expanded_path(env));
+            }
+        }
+        snapshot
+    }
+}
+
+/// A [`Resolver`] for the known folders of a user, read from the user's
+/// offline registry hive (`NTUSER.DAT`).
+///
+/// Folder locations are read from the [`USER_SHELL_FOLDERS`] key and expanded
+/// against the user's environment. Folders that are missing from `User Shell
+/// Folders` fall back to the legacy [`SHELL_FOLDERS`] key.
+///
+/// The user's environment is the base environment given to
+/// [`HiveResolver::from_hive`] overlaid with the variables stored under the
+/// [`ENVIRONMENT`] key of the hive. Expandable values of that key are
+/// expanded after all of its plain values are set. `NTUSER.DAT` does not
+/// record the location of the user's profile, so the base environment
+/// should define at least `USERPROFILE`, and typically also `SystemDrive`,
+/// `SystemRoot`, `ProgramData`, and `PUBLIC`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use known_folders::registry::hive::Hive;
+/// use known_folders::shell_folders::HiveResolver;
+/// use known_folders::{Environment, KnownFolder, Resolver};
+///
+/// let hive = Hive::parse(std::fs::read("/mnt/windows/Users/jsmith/NTUSER.DAT")?)?;
+/// let env = [("USERPROFILE", r"C:\Users\jsmith")].iter().copied().collect::<Environment>();
+/// let resolver = HiveResolver::from_hive(&hive, &env)?;
+/// let documents = resolver.resolve(KnownFolder::Documents);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct HiveResolver {
+    user_shell_folders: ShellFolders,
+    shell_folders: ShellFolders,
+    environment: Environment,
+}
+
+impl HiveResolver {
+    /// Read the folder locations and environment of the user whose hive is
+    /// `hive`.
+    ///
+    /// Missing keys are treated as empty.
+    ///
+    /// # Errors
+    ///
+    /// If the hive is malformed, an error is returned.
+    pub fn from_hive(hive: &Hive, env: &Environment) -> Result<Self, hive::ParseError> {
+        let read = |path| -> Result<ShellFolders, hive::ParseError> {
+            match hive.open_key(path)? {
+                Some(key) => ShellFolders::from_hive_key(&key),
+                None => Ok(ShellFolders::default()),
+            }
+        };
+        let user_shell_folders = read(USER_SHELL_FOLDERS)?;
+        let shell_folders = read(SHELL_FOLDERS)?;
+
+        let mut environment = env.clone();
+        if let Some(key) = hive.open_key(ENVIRONMENT)? {
+            // Expand references against all plain values of the key so the
+            // result does not depend on the order of the values in the hive.
+            let mut expand = Vec::new();
+            for entry in key.values()? {
+                match entry.data() {
+                    Value::String(value) => {
+                        environment.insert(entry.name(), value.clone());
+                    }
+                    Value::ExpandString(value) => {
+                        expand.push((entry.name().to_owned(), value.clone()));
+                    }
+                    _ => {}
+                }
+            }
+            let expanded = expand
+                .into_iter()
+                .map(|(name, value)| (name, environment.expand(&value)))
+                .collect::<Vec<_>>();
+            environment.extend(expanded);
+        }
+        Ok(Self {
+            user_shell_folders,
+            shell_folders,
+            environment,
+        })
+    }
+
+    /// The values of the `User Shell Folders` key.
+    #[must_use]
+    pub fn user_shell_folders(&self) -> &ShellFolders {
+        &self.user_shell_folders
+    }
+
+    /// The values of the legacy `Shell Folders` key.
+    #[must_use]
+    pub fn shell_folders(&self) -> &ShellFolders {
+        &self.shell_folders
+    }
+
+    /// The user's environment used to expand folder locations.
+    #[must_use]
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+}
+
+impl Resolver for HiveResolver {
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        self.resolve_id(FolderId::Known(known_folder))
+    }
+
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        self.user_shell_folders
+            .get_by_id(id)
+            .or_else(|| self.shell_folders.get_by_id(id))
+            .map(|folder| folder.expanded_path(&self.environment).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::registry::test_hive::{HiveBuilder, TestKey};
+
+    #[test]
+    fn legacy_names_are_unique() {
+        for (idx, (name, _)) in LEGACY_NAMES.iter().enumerate() {
+            assert!(
+                LEGACY_NAMES[idx + 1..]
+                    .iter()
+                    .all(|(other, _)| !text::eq_ignore_case(name, other)),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn value_names_map_to_known_folders() {
+        assert_eq!(
+            known_folder_for_value_name("my pictures"),
+            Some(KnownFolder::Pictures)
+        );
+        assert_eq!(
+            known_folder_for_value_name("Local AppData"),
+            Some(KnownFolder::LocalAppData)
+        );
+        assert_eq!(
+            known_folder_for_value_name("{F42EE2D3-909F-4907-8871-4C22FC0BF756}"),
+            Some(KnownFolder::LocalDocuments)
+        );
+        assert_eq!(
+            known_folder_for_value_name("{00000000-0000-0000-0000-000000000001}"),
+            None
+        );
+    }
+
+    #[test]
+    fn first_value_for_a_folder_wins() {
+        let values = [
+            ("Personal", Value::String(r"D:\Docs".to_owned())),
+            (
+                "{FDD39AD0-238F-46AF-ADB4-6C85480369C7}",
+                Value::String(r"E:\Docs".to_owned()),
+            ),
+            ("Unknown", Value::String(r"F:\".to_owned())),
+            ("Desktop", Value::Dword(0)),
+        ];
+        let folders = ShellFolders::from_values(values.iter().map(|(n, v)| (*n, v)));
+        assert_eq!(folders.iter().count(), 3);
+        assert_eq!(
+            folders.get(KnownFolder::Documents).unwrap().raw_path(),
+            r"D:\Docs"
+        );
+        let snapshot = folders.to_snapshot(&Environment::new());
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(
+            snapshot.get(KnownFolder::Documents),
+            Some(Path::new(r"D:\Docs"))
+        );
+    }
+
+    #[test]
+    fn resolve_from_hive() {
+        let mut root = TestKey::new("ROOT");
+        root.key(USER_SHELL_FOLDERS)
+            .expand_sz("Personal", r"%USERPROFILE%\Documents")
+            .expand_sz("{374DE290-123F-4565-9164-39C4925E467B}", r"%DOWNLOADS%");
+        root.key(SHELL_FOLDERS)
+            .sz("Personal", r"C:\Stale\Documents")
+            .sz("Desktop", r"C:\Users\jsmith\Desktop");
+        // The user's profile overrides the base environment for every value,
+        // regardless of the order of the values in the key.
+        root.key(ENVIRONMENT)
+            .expand_sz("DOWNLOADS", r"%USERPROFILE%\Downloads")
+            .sz("USERPROFILE", r"C:\Users\jsmith");
+        let hive = Hive::parse(HiveBuilder::new().build(&root)).unwrap();
+
+        let env = [("USERPROFILE", r"C:\Users\Default")]
+            .iter()
+            .copied()
+            .collect::<Environment>();
+        let resolver = HiveResolver::from_hive(&hive, &env).unwrap();
+        let snapshot = Snapshot::capture(&resolver);
+        assert_eq!(
+            snapshot.get(KnownFolder::Documents),
+            Some(Path::new(r"C:\Users\jsmith\Documents"))
+        );
+        assert_eq!(
+            snapshot.get(KnownFolder::Downloads),
+            Some(Path::new(r"C:\Users\jsmith\Downloads"))
+        );
+        assert_eq!(
+            snapshot.get(KnownFolder::Desktop),
+            Some(Path::new(r"C:\Users\jsmith\Desktop"))
+        );
+        assert_eq!(snapshot.len(), 3);
+    }
+}
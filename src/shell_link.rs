@@ -0,0 +1,1071 @@
+// src/shell_link.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parser for shell link (`.lnk`) files (MS-SHLLINK).
+//!
+//! The Start Menu, Startup, `SendTo`, and Recent folders are populated with
+//! shortcuts. A shortcut identifies its target with an item ID list, a
+//! `LinkInfo` structure with the local or network path of the target, and
+//! optional extra data blocks, including one that names the known folder the
+//! target lives in. [`ShellLink`] decodes all of them and resolves the
+//! target, which makes it possible to inspect shortcuts without calling into
+//! Windows.
+
+use core::fmt;
+use std::error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::bytes::{i32_at, slice_at, u16_at, u32_at, u64_at};
+use crate::pidl::{self, IdList, ShellItem};
+use crate::property_store::PropertyStore;
+use crate::{path, text, FolderId, Guid, Resolver};
+
+/// The size of the `ShellLinkHeader` structure.
+const HEADER_SIZE: usize = 0x4C;
+
+/// The class identifier of shell links, `{00021401-0000-0000-C000-000000000046}`.
+pub(crate) const LINK_CLSID: Guid = Guid::from_u128(0x00021401_0000_0000_c000_000000000046);
+
+const HAS_LINK_TARGET_ID_LIST: u32 = 0x0000_0001;
+const HAS_LINK_INFO: u32 = 0x0000_0002;
+const HAS_NAME: u32 = 0x0000_0004;
+const HAS_RELATIVE_PATH: u32 = 0x0000_0008;
+const HAS_WORKING_DIR: u32 = 0x0000_0010;
+const HAS_ARGUMENTS: u32 = 0x0000_0020;
+const HAS_ICON_LOCATION: u32 = 0x0000_0040;
+const IS_UNICODE: u32 = 0x0000_0080;
+const FORCE_NO_LINK_INFO: u32 = 0x0000_0100;
+
+const VOLUME_ID_AND_LOCAL_BASE_PATH: u32 = 0x0000_0001;
+const COMMON_NETWORK_RELATIVE_LINK_AND_PATH_SUFFIX: u32 = 0x0000_0002;
+
+const ENVIRONMENT_VARIABLE_DATA_BLOCK: u32 = 0xA000_0001;
+const ICON_ENVIRONMENT_DATA_BLOCK: u32 = 0xA000_0007;
+const PROPERTY_STORE_DATA_BLOCK: u32 = 0xA000_0009;
+const KNOWN_FOLDER_DATA_BLOCK: u32 = 0xA000_000B;
+
+/// The size of the `TerminalBlock` that ends the extra data.
+const TERMINAL_BLOCK_SIZE: usize = 4;
+
+/// The most extra data kept for one link. Real links carry a few kilobytes;
+/// blocks past this limit are ignored.
+const MAX_EXTRA_DATA_SIZE: usize = 1 << 20;
+
+/// The kind of error that occurred while parsing a shell link.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A structure extends past the end of the data.
+    Truncated,
+    /// The data does not start with a shell link header.
+    InvalidHeader,
+    /// The `LinkTargetIDList` is not a valid item ID list.
+    InvalidIdList,
+}
+
+/// Error returned when parsing a shell link fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    offset: usize,
+}
+
+impl ParseError {
+    /// The kind of error that occurred.
+    #[must_use]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The byte offset in the shell link where the error was detected.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Truncated => {
+                write!(f, "truncated shell link at offset {}", self.offset)
+            }
+            ParseErrorKind::InvalidHeader => f.write_str("not a shell link"),
+            ParseErrorKind::InvalidIdList => {
+                write!(f, "invalid item ID list at offset {}", self.offset)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Error returned when reading a shell link file fails.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be read.
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying I/O error.
+        error: io::Error,
+    },
+    /// The file is not a valid shell link.
+    Parse {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying parse error.
+        error: ParseError,
+    },
+}
+
+impl Error {
+    /// The path of the file that could not be read.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Io { path, .. } | Self::Parse { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, error } => write!(f, "failed to read {}: {error}", path.display()),
+            Self::Parse { path, error } => {
+                write!(f, "failed to parse {}: {error}", path.display())
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io { error, .. } => Some(error),
+            Self::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+/// The volume a link target was stored on, from the `VolumeID` structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeId {
+    drive_type: u32,
+    serial_number: u32,
+    label: String,
+}
+
+impl VolumeId {
+    /// The `DRIVE_*` type of the volume, like `3` for `DRIVE_FIXED`.
+    #[must_use]
+    pub fn drive_type(&self) -> u32 {
+        self.drive_type
+    }
+
+    /// The serial number of the volume.
+    #[must_use]
+    pub fn serial_number(&self) -> u32 {
+        self.serial_number
+    }
+
+    /// The label of the volume.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// The network share a link target was stored on, from the
+/// `CommonNetworkRelativeLink` structure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkLink {
+    net_name: String,
+    device_name: Option<String>,
+}
+
+impl NetworkLink {
+    /// The UNC path of the share, like `\\server\share`.
+    #[must_use]
+    pub fn net_name(&self) -> &str {
+        &self.net_name
+    }
+
+    /// The drive letter the share was mapped to, like `Z:`.
+    #[must_use]
+    pub fn device_name(&self) -> Option<&str> {
+        self.device_name.as_deref()
+    }
+}
+
+/// The `LinkInfo` structure of a shell link, which records the path of the
+/// target at the time the link was created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkInfo {
+    volume_id: Option<VolumeId>,
+    local_base_path: Option<String>,
+    network_link: Option<NetworkLink>,
+    common_path_suffix: String,
+}
+
+impl LinkInfo {
+    fn parse(data: &[u8], base: usize) -> Result<Self, ParseError> {
+        let truncated = |offset: usize| ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset: base + offset,
+        };
+        let header_size = u32_at(data, 4)
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| truncated(4))?;
+        let flags = u32_at(data, 8).ok_or_else(|| truncated(8))?;
+        let field = |offset: usize| u32_at(data, offset).and_then(|n| usize::try_from(n).ok());
+        // Header sizes of 0x24 and up carry offsets of Unicode strings.
+        let unicode_field = |offset: usize| {
+            if header_size >= 0x24 {
+                field(offset).filter(|&n| n != 0)
+            } else {
+                None
+            }
+        };
+        let string_at = |ansi: Option<usize>, unicode: Option<usize>| {
+            if let Some(unicode) = unicode {
+                data.get(unicode..).map(text::decode_utf16le_nul)
+            } else {
+                data.get(ansi?..).map(text::decode_ansi_nul)
+            }
+        };
+
+        let mut volume_id = None;
+        let mut local_base_path = None;
+        if flags & VOLUME_ID_AND_LOCAL_BASE_PATH != 0 {
+            let offset = field(12).ok_or_else(|| truncated(12))?;
+            let volume = data.get(offset..).ok_or_else(|| truncated(offset))?;
+            volume_id = Some(VolumeId::parse(volume).ok_or_else(|| truncated(offset))?);
+            local_base_path = string_at(field(16), unicode_field(28));
+        }
+        let mut network_link = None;
+        if flags & COMMON_NETWORK_RELATIVE_LINK_AND_PATH_SUFFIX != 0 {
+            let offset = field(20).ok_or_else(|| truncated(20))?;
+            let link = data.get(offset..).ok_or_else(|| truncated(offset))?;
+            network_link = Some(NetworkLink::parse(link).ok_or_else(|| truncated(offset))?);
+        }
+        let common_path_suffix = string_at(field(24), unicode_field(32)).unwrap_or_default();
+        Ok(Self {
+            volume_id,
+            local_base_path,
+            network_link,
+            common_path_suffix,
+        })
+    }
+
+    /// The volume the target was stored on.
+    #[must_use]
+    pub fn volume_id(&self) -> Option<&VolumeId> {
+        self.volume_id.as_ref()
+    }
+
+    /// The local path of the target, or of the folder that
+    /// [`common_path_suffix`](Self::common_path_suffix) is relative to.
+    #[must_use]
+    pub fn local_base_path(&self) -> Option<&str> {
+        self.local_base_path.as_deref()
+    }
+
+    /// The network share the target was stored on.
+    #[must_use]
+    pub fn network_link(&self) -> Option<&NetworkLink> {
+        self.network_link.as_ref()
+    }
+
+    /// The path of the target relative to the local base path or the
+    /// network share.
+    #[must_use]
+    pub fn common_path_suffix(&self) -> &str {
+        &self.common_path_suffix
+    }
+
+    /// Return the full path of the target, preferring the local path over
+    /// the network path.
+    #[must_use]
+    pub fn path(&self) -> Option<String> {
+        let base = self
+            .local_base_path
+            .as_deref()
+            .or_else(|| self.network_link.as_ref().map(NetworkLink::net_name))?;
+        if self.common_path_suffix.is_empty() {
+            Some(base.to_owned())
+        } else if base.ends_with('\\') {
+            Some(format!("{base}{}", self.common_path_suffix))
+        } else {
+            Some(format!(r"{base}\{}", self.common_path_suffix))
+        }
+    }
+}
+
+impl VolumeId {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let drive_type = u32_at(data, 4)?;
+        let serial_number = u32_at(data, 8)?;
+        let label_offset = usize::try_from(u32_at(data, 12)?).ok()?;
+        let label = if label_offset == 0x14 {
+            let offset = usize::try_from(u32_at(data, 16)?).ok()?;
+            text::decode_utf16le_nul(data.get(offset..)?)
+        } else {
+            text::decode_ansi_nul(data.get(label_offset..)?)
+        };
+        Some(Self {
+            drive_type,
+            serial_number,
+            label,
+        })
+    }
+}
+
+impl NetworkLink {
+    fn parse(data: &[u8]) -> Option<Self> {
+        let flags = u32_at(data, 4)?;
+        let net_name_offset = usize::try_from(u32_at(data, 8)?).ok()?;
+        let device_name_offset = usize::try_from(u32_at(data, 12)?).ok()?;
+        // Offsets past 0x14 indicate the presence of Unicode strings.
+        let unicode = net_name_offset > 0x14;
+        let string_at = |ansi: usize, unicode_field: usize| {
+            if unicode {
+                let offset = usize::try_from(u32_at(data, unicode_field)?).ok()?;
+                data.get(offset..).map(text::decode_utf16le_nul)
+            } else {
+                data.get(ansi..).map(text::decode_ansi_nul)
+            }
+        };
+        let net_name = string_at(net_name_offset, 20)?;
+        // The `ValidDevice` flag.
+        let device_name = if flags & 0x1 == 0 {
+            None
+        } else {
+            string_at(device_name_offset, 24)
+        };
+        Some(Self {
+            net_name,
+            device_name,
+        })
+    }
+}
+
+/// An extra data block of a shell link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtraDataBlock {
+    signature: u32,
+    data: Vec<u8>,
+}
+
+impl ExtraDataBlock {
+    /// The block signature, like `0xA000000B` for a `KnownFolderDataBlock`.
+    #[must_use]
+    pub fn signature(&self) -> u32 {
+        self.signature
+    }
+
+    /// The raw bytes of the block, including its size and signature.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+/// A parsed shell link (`.lnk`) file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use known_folders::shell_link::ShellLink;
+/// use known_folders::Snapshot;
+///
+/// let link = ShellLink::open(r"C:\Users\jsmith\Desktop\Notepad.lnk")?;
+/// println!("{:?} {:?}", link.target_path(), link.arguments());
+/// if let Some((folder, relative)) = link.target_known_folder() {
+///     println!("{relative} in {folder:?}");
+/// }
+/// # Ok::<(), known_folders::shell_link::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellLink {
+    flags: u32,
+    file_attributes: u32,
+    creation_time: u64,
+    access_time: u64,
+    write_time: u64,
+    file_size: u32,
+    icon_index: i32,
+    show_command: u32,
+    hot_key: u16,
+    id_list: Option<IdList>,
+    link_info: Option<LinkInfo>,
+    name: Option<String>,
+    relative_path: Option<String>,
+    working_dir: Option<String>,
+    arguments: Option<String>,
+    icon_location: Option<String>,
+    environment_target: Option<String>,
+    icon_environment: Option<String>,
+    known_folder: Option<(FolderId, u32)>,
+    properties: Option<PropertyStore>,
+    extra_data: Vec<ExtraDataBlock>,
+}
+
+impl ShellLink {
+    /// Parse the contents of a shell link file.
+    ///
+    /// # Errors
+    ///
+    /// If the data does not start with a shell link header or a structure
+    /// extends past the end of the data, an error is returned. Extra data
+    /// blocks that cannot be decoded are kept as raw
+    /// [`ExtraDataBlock`]s.
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        Self::parse_prefix(bytes).map(|(link, _)| link)
+    }
+
+    /// Parse a shell link at the start of `bytes`, which may be followed by
+    /// other data, and return it with the number of bytes it spans.
+    ///
+    /// The link ends after its `TerminalBlock`, or at the first extra data
+    /// block that is truncated or past the extra data size limit.
+    pub(crate) fn parse_prefix(bytes: &[u8]) -> Result<(Self, usize), ParseError> {
+        let invalid_header = ParseError {
+            kind: ParseErrorKind::InvalidHeader,
+            offset: 0,
+        };
+        let header = slice_at(bytes, 0, HEADER_SIZE).ok_or(invalid_header)?;
+        let clsid = slice_at(header, 4, 16).and_then(Guid::from_slice_le);
+        if u32_at(header, 0).and_then(|n| usize::try_from(n).ok()) != Some(HEADER_SIZE)
+            || clsid != Some(LINK_CLSID)
+        {
+            return Err(invalid_header);
+        }
+        let header_field = |offset| u32_at(header, offset).unwrap_or_default();
+        let header_time = |offset| u64_at(header, offset).unwrap_or_default();
+        let mut link = Self {
+            flags: header_field(20),
+            file_attributes: header_field(24),
+            creation_time: header_time(28),
+            access_time: header_time(36),
+            write_time: header_time(44),
+            file_size: header_field(52),
+            icon_index: i32_at(header, 56).unwrap_or_default(),
+            show_command: header_field(60),
+            hot_key: u16_at(header, 64).unwrap_or_default(),
+            id_list: None,
+            link_info: None,
+            name: None,
+            relative_path: None,
+            working_dir: None,
+            arguments: None,
+            icon_location: None,
+            environment_target: None,
+            icon_environment: None,
+            known_folder: None,
+            properties: None,
+            extra_data: Vec::new(),
+        };
+        let mut offset = HEADER_SIZE;
+        if link.flags & HAS_LINK_TARGET_ID_LIST != 0 {
+            offset = link.parse_id_list(bytes, offset)?;
+        }
+        if link.flags & HAS_LINK_INFO != 0 {
+            offset = link.parse_link_info(bytes, offset)?;
+        }
+        offset = link.parse_string_data(bytes, offset)?;
+        let len = link.parse_extra_data(bytes, offset);
+        Ok((link, len))
+    }
+
+    /// Read and parse the shell link file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read or is not a valid shell link, an error is
+    /// returned.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|error| Error::Io {
+            path: path.to_owned(),
+            error,
+        })?;
+        Self::parse(&bytes).map_err(|error| Error::Parse {
+            path: path.to_owned(),
+            error,
+        })
+    }
+
+    fn parse_id_list(&mut self, bytes: &[u8], offset: usize) -> Result<usize, ParseError> {
+        let size = u16_at(bytes, offset).ok_or(ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset,
+        })?;
+        let data = slice_at(bytes, offset + 2, usize::from(size)).ok_or(ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset,
+        })?;
+        let id_list = IdList::parse(data).map_err(|err: pidl::ParseError| ParseError {
+            kind: ParseErrorKind::InvalidIdList,
+            offset: offset + 2 + err.offset(),
+        })?;
+        self.id_list = Some(id_list);
+        Ok(offset + 2 + data.len())
+    }
+
+    fn parse_link_info(&mut self, bytes: &[u8], offset: usize) -> Result<usize, ParseError> {
+        let truncated = ParseError {
+            kind: ParseErrorKind::Truncated,
+            offset,
+        };
+        let size = u32_at(bytes, offset)
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or(truncated)?;
+        let data = slice_at(bytes, offset, size).ok_or(truncated)?;
+        if self.flags & FORCE_NO_LINK_INFO == 0 {
+            self.link_info = Some(LinkInfo::parse(data, offset)?);
+        }
+        Ok(offset + size)
+    }
+
+    fn parse_string_data(&mut self, bytes: &[u8], mut offset: usize) -> Result<usize, ParseError> {
+        let unicode = self.flags & IS_UNICODE != 0;
+        let mut next = |flag: u32| -> Result<Option<String>, ParseError> {
+            if self.flags & flag == 0 {
+                return Ok(None);
+            }
+            let truncated = ParseError {
+                kind: ParseErrorKind::Truncated,
+                offset,
+            };
+            let count = usize::from(u16_at(bytes, offset).ok_or(truncated)?);
+            let len = if unicode { count * 2 } else { count };
+            let data = slice_at(bytes, offset + 2, len).ok_or(truncated)?;
+            offset += 2 + len;
+            if unicode {
+                Ok(Some(text::decode_utf16le(data)))
+            } else {
+                Ok(Some(text::decode_ansi(data)))
+            }
+        };
+        let name = next(HAS_NAME)?;
+        let relative_path = next(HAS_RELATIVE_PATH)?;
+        let working_dir = next(HAS_WORKING_DIR)?;
+        let arguments = next(HAS_ARGUMENTS)?;
+        let icon_location = next(HAS_ICON_LOCATION)?;
+        self.name = name;
+        self.relative_path = relative_path;
+        self.working_dir = working_dir;
+        self.arguments = arguments;
+        self.icon_location = icon_location;
+        Ok(offset)
+    }
+
+    /// Parse the extra data blocks starting at `offset` and return the
+    /// offset of the end of the extra data.
+    fn parse_extra_data(&mut self, bytes: &[u8], mut offset: usize) -> usize {
+        let start = offset;
+        // The list is terminated by a block with a size less than 4.
+        while let Some(size) = u32_at(bytes, offset).and_then(|n| usize::try_from(n).ok()) {
+            if size < TERMINAL_BLOCK_SIZE {
+                return offset + TERMINAL_BLOCK_SIZE;
+            }
+            if offset - start + size > MAX_EXTRA_DATA_SIZE {
+                break;
+            }
+            let block = match slice_at(bytes, offset, size) {
+                Some(block) if size >= 8 => block,
+                _ => break,
+            };
+            let signature = u32_at(block, 4).unwrap_or_default();
+            let target = || {
+                let unicode = block.get(268..788).map(text::decode_utf16le_nul);
+                unicode
+                    .filter(|target| !target.is_empty())
+                    .or_else(|| block.get(8..268).map(text::decode_ansi_nul))
+            };
+            match signature {
+                ENVIRONMENT_VARIABLE_DATA_BLOCK => self.environment_target = target(),
+                ICON_ENVIRONMENT_DATA_BLOCK => self.icon_environment = target(),
+                PROPERTY_STORE_DATA_BLOCK => {
+                    self.properties = block
+                        .get(8..)
+                        .and_then(|store| PropertyStore::parse(store).ok());
+                }
+                KNOWN_FOLDER_DATA_BLOCK => {
+                    let id = slice_at(block, 8, 16).and_then(Guid::from_slice_le);
+                    if let (Some(id), Some(item_offset)) = (id, u32_at(block, 24)) {
+                        self.known_folder = Some((FolderId::from_guid(id), item_offset));
+                    }
+                }
+                _ => {}
+            }
+            self.extra_data.push(ExtraDataBlock {
+                signature,
+                data: block.to_vec(),
+            });
+            offset += size;
+        }
+        offset
+    }
+
+    /// The `LinkFlags` field of the header.
+    #[must_use]
+    pub fn link_flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// The `FILE_ATTRIBUTE_*` flags of the target.
+    #[must_use]
+    pub fn file_attributes(&self) -> u32 {
+        self.file_attributes
+    }
+
+    /// The creation time of the target as a `FILETIME`.
+    #[must_use]
+    pub fn creation_time(&self) -> u64 {
+        self.creation_time
+    }
+
+    /// The last access time of the target as a `FILETIME`.
+    #[must_use]
+    pub fn access_time(&self) -> u64 {
+        self.access_time
+    }
+
+    /// The last write time of the target as a `FILETIME`.
+    #[must_use]
+    pub fn write_time(&self) -> u64 {
+        self.write_time
+    }
+
+    /// The size of the target in bytes, truncated to 32 bits.
+    #[must_use]
+    pub fn file_size(&self) -> u32 {
+        self.file_size
+    }
+
+    /// The index of the icon within the icon location.
+    #[must_use]
+    pub fn icon_index(&self) -> i32 {
+        self.icon_index
+    }
+
+    /// The `SW_*` show command for the window of the launched application.
+    #[must_use]
+    pub fn show_command(&self) -> u32 {
+        self.show_command
+    }
+
+    /// The keyboard shortcut that launches the link.
+    #[must_use]
+    pub fn hot_key(&self) -> u16 {
+        self.hot_key
+    }
+
+    /// The item ID list of the target.
+    #[must_use]
+    pub fn id_list(&self) -> Option<&IdList> {
+        self.id_list.as_ref()
+    }
+
+    /// The `LinkInfo` structure of the link.
+    #[must_use]
+    pub fn link_info(&self) -> Option<&LinkInfo> {
+        self.link_info.as_ref()
+    }
+
+    /// The description of the link.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// The path of the target relative to the link file.
+    #[must_use]
+    pub fn relative_path(&self) -> Option<&str> {
+        self.relative_path.as_deref()
+    }
+
+    /// The working directory to launch the target in.
+    #[must_use]
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref()
+    }
+
+    /// The command line arguments passed to the target.
+    #[must_use]
+    pub fn arguments(&self) -> Option<&str> {
+        self.arguments.as_deref()
+    }
+
+    /// The path of the file that holds the icon of the link.
+    ///
+    /// Prefers the unexpanded path of the `IconEnvironmentDataBlock`, which
+    /// may contain environment variables, over the `StringData` entry.
+    #[must_use]
+    pub fn icon_location(&self) -> Option<&str> {
+        self.icon_environment
+            .as_deref()
+            .or(self.icon_location.as_deref())
+    }
+
+    /// The target path of the `EnvironmentVariableDataBlock`, which may
+    /// contain environment variables like `%windir%`.
+    #[must_use]
+    pub fn environment_target(&self) -> Option<&str> {
+        self.environment_target.as_deref()
+    }
+
+    /// The properties of the `PropertyStoreDataBlock`, which carry the
+    /// `AppUserModelID` of the target among others.
+    #[must_use]
+    pub fn properties(&self) -> Option<&PropertyStore> {
+        self.properties.as_ref()
+    }
+
+    /// The extra data blocks of the link, including those decoded into
+    /// other accessors.
+    #[must_use]
+    pub fn extra_data(&self) -> &[ExtraDataBlock] {
+        &self.extra_data
+    }
+
+    /// Return the path of the target as recorded in the link.
+    ///
+    /// The path is taken from the `LinkInfo` structure, falling back to the
+    /// item ID list and to the `EnvironmentVariableDataBlock`, whose path is
+    /// returned unexpanded.
+    #[must_use]
+    pub fn target_path(&self) -> Option<String> {
+        self.link_info
+            .as_ref()
+            .and_then(LinkInfo::path)
+            .or_else(|| self.id_list.as_ref().and_then(IdList::path))
+            .or_else(|| self.environment_target.clone())
+    }
+
+    /// Return the known folder the target sits under and the `\`-separated
+    /// path of the target relative to it.
+    ///
+    /// The folder is taken from the `KnownFolderDataBlock`, whose offset
+    /// locates the first item of the item ID list below the folder. If there
+    /// is no such block or its offset does not locate an item, the innermost
+    /// known folder of the item ID list is used.
+    #[must_use]
+    pub fn target_known_folder(&self) -> Option<(FolderId, String)> {
+        let id_list = self.id_list.as_ref();
+        if let Some((known_folder, offset)) = self.known_folder {
+            let items =
+                id_list.and_then(|list| list.items_from_offset(usize::try_from(offset).ok()?));
+            if let Some(items) = items {
+                let names = items.iter().filter_map(ShellItem::file_name);
+                return Some((known_folder, names.collect::<Vec<_>>().join("\\")));
+            }
+        }
+        id_list.and_then(IdList::known_folder)
+    }
+
+    /// Resolve the file system path of the target.
+    ///
+    /// Targets under a known folder are resolved relative to the folder's
+    /// path as located by `resolver`, which keeps links working across
+    /// profiles and relocated folders. Otherwise the recorded
+    /// [`target_path`](Self::target_path) is returned.
+    #[must_use]
+    pub fn resolve_target<R>(&self, resolver: &R) -> Option<PathBuf>
+    where
+        R: Resolver + ?Sized,
+    {
+        if let Some((known_folder, relative)) = self.target_known_folder() {
+            if let Some(folder) = resolver.resolve_id(known_folder) {
+                return Some(path::join(&folder, &relative));
+            }
+        }
+        self.target_path().map(PathBuf::from)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::pidl::tests::{file_entry, item, root};
+    use crate::property_store::tests::storage;
+    use crate::{KnownFolder, Snapshot};
+
+    /// Serialize a Unicode shell link.
+    pub(crate) fn link(
+        id_list: Option<&[u8]>,
+        local_path: Option<&str>,
+        strings: &[(u32, &str)],
+        extra: &[Vec<u8>],
+    ) -> Vec<u8> {
+        let mut flags = IS_UNICODE;
+        flags |= id_list.map_or(0, |_| HAS_LINK_TARGET_ID_LIST);
+        flags |= local_path.map_or(0, |_| HAS_LINK_INFO);
+        for &(flag, _) in strings {
+            flags |= flag;
+        }
+        let mut out = vec![0; HEADER_SIZE];
+        out[..4].copy_from_slice(&u32::try_from(HEADER_SIZE).unwrap().to_le_bytes());
+        out[4..20].copy_from_slice(&LINK_CLSID.to_bytes_le());
+        out[20..24].copy_from_slice(&flags.to_le_bytes());
+        out[60..64].copy_from_slice(&1_u32.to_le_bytes());
+        if let Some(id_list) = id_list {
+            out.extend_from_slice(&u16::try_from(id_list.len()).unwrap().to_le_bytes());
+            out.extend_from_slice(id_list);
+        }
+        if let Some(local_path) = local_path {
+            let volume = b"\x11\0\0\0\x03\0\0\0\x78\x56\x34\x12\x10\0\0\0\0";
+            let header_size = 0x1C_u32;
+            let volume_offset = header_size;
+            let path_offset = volume_offset + u32::try_from(volume.len()).unwrap();
+            let suffix_offset = path_offset + u32::try_from(local_path.len()).unwrap() + 1;
+            let size = suffix_offset + 1;
+            for field in &[
+                size,
+                header_size,
+                VOLUME_ID_AND_LOCAL_BASE_PATH,
+                volume_offset,
+                path_offset,
+                0,
+                suffix_offset,
+            ] {
+                out.extend_from_slice(&field.to_le_bytes());
+            }
+            out.extend_from_slice(volume);
+            out.extend_from_slice(local_path.as_bytes());
+            out.extend_from_slice(&[0, 0]);
+        }
+        let mut strings = strings.to_vec();
+        strings.sort_by_key(|&(flag, _)| flag);
+        for (_, string) in strings {
+            let units = string.encode_utf16().collect::<Vec<_>>();
+            out.extend_from_slice(&u16::try_from(units.len()).unwrap().to_le_bytes());
+            out.extend(units.into_iter().flat_map(u16::to_le_bytes));
+        }
+        for block in extra {
+            out.extend_from_slice(block);
+        }
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        out
+    }
+
+    /// Serialize a link to a local program with arguments and a working
+    /// directory.
+    pub(crate) fn program_link(target: &str, arguments: &str, working_dir: &str) -> Vec<u8> {
+        link(
+            None,
+            Some(target),
+            &[(HAS_WORKING_DIR, working_dir), (HAS_ARGUMENTS, arguments)],
+            &[],
+        )
+    }
+
+    /// Serialize a link to a local program whose property store records the
+    /// `AppUserModelID` of the program.
+    pub(crate) fn app_link(target: &str, app_user_model_id: &str) -> Vec<u8> {
+        let name = app_user_model_id
+            .encode_utf16()
+            .chain(Some(0))
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let mut value = vec![0x1F, 0, 0, 0];
+        value.extend_from_slice(&u32::try_from(name.len() / 2).unwrap().to_le_bytes());
+        value.extend_from_slice(&name);
+        let mut store = storage(
+            Guid::from_u128(0x9f4c2855_9f79_4b39_a8d0_e1d42de1d5f3),
+            &[(&5_u32.to_le_bytes(), &value)],
+        );
+        store.extend_from_slice(&0_u32.to_le_bytes());
+        let mut block = u32::try_from(8 + store.len())
+            .unwrap()
+            .to_le_bytes()
+            .to_vec();
+        block.extend_from_slice(&PROPERTY_STORE_DATA_BLOCK.to_le_bytes());
+        block.extend_from_slice(&store);
+        link(None, Some(target), &[], &[block])
+    }
+
+    pub(crate) fn known_folder_block(known_folder: KnownFolder, offset: u32) -> Vec<u8> {
+        let mut block = Vec::new();
+        block.extend_from_slice(&0x1C_u32.to_le_bytes());
+        block.extend_from_slice(&KNOWN_FOLDER_DATA_BLOCK.to_le_bytes());
+        block.extend_from_slice(&known_folder.guid().to_bytes_le());
+        block.extend_from_slice(&offset.to_le_bytes());
+        block
+    }
+
+    pub(crate) fn environment_block(target: &str) -> Vec<u8> {
+        let mut block = Vec::new();
+        block.extend_from_slice(&0x314_u32.to_le_bytes());
+        block.extend_from_slice(&ENVIRONMENT_VARIABLE_DATA_BLOCK.to_le_bytes());
+        let mut ansi = target.as_bytes().to_vec();
+        ansi.resize(260, 0);
+        block.extend_from_slice(&ansi);
+        let mut unicode = target
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        unicode.resize(520, 0);
+        block.extend_from_slice(&unicode);
+        block
+    }
+
+    #[test]
+    fn parse_local_link() {
+        let bytes = link(
+            None,
+            Some(r"C:\Program Files\Acme\acme.exe"),
+            &[
+                (HAS_NAME, "Acme Editor"),
+                (HAS_WORKING_DIR, r"C:\Program Files\Acme"),
+                (HAS_ARGUMENTS, "--new-window"),
+                (HAS_ICON_LOCATION, r"C:\Program Files\Acme\acme.ico"),
+            ],
+            &[],
+        );
+        let link = ShellLink::parse(&bytes).unwrap();
+        let mut trailing = bytes.clone();
+        trailing.extend_from_slice(b"trailing data");
+        assert_eq!(
+            ShellLink::parse_prefix(&trailing).unwrap(),
+            (link.clone(), bytes.len())
+        );
+        assert_eq!(link.name(), Some("Acme Editor"));
+        assert_eq!(link.working_dir(), Some(r"C:\Program Files\Acme"));
+        assert_eq!(link.arguments(), Some("--new-window"));
+        assert_eq!(
+            link.icon_location(),
+            Some(r"C:\Program Files\Acme\acme.ico")
+        );
+        assert_eq!(link.relative_path(), None);
+        assert_eq!(link.show_command(), 1);
+        let info = link.link_info().unwrap();
+        let volume = info.volume_id().unwrap();
+        assert_eq!(volume.drive_type(), 3);
+        assert_eq!(volume.serial_number(), 0x1234_5678);
+        assert_eq!(
+            link.target_path().as_deref(),
+            Some(r"C:\Program Files\Acme\acme.exe")
+        );
+        assert_eq!(link.target_known_folder(), None);
+        assert_eq!(
+            link.resolve_target(&Snapshot::new()),
+            Some(PathBuf::from(r"C:\Program Files\Acme\acme.exe"))
+        );
+    }
+
+    #[test]
+    fn resolve_known_folder_target() {
+        let mut id_list = root(Guid::from_u128(0x20d04fe0_3aea_1069_a2d8_08002b30309d));
+        id_list.extend(item(b"\x2FC:\\\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"));
+        id_list.extend(file_entry("Users", "Users", true));
+        id_list.extend(file_entry("jsmith", "jsmith", true));
+        id_list.extend(file_entry("DOCUME~1", "Documents", true));
+        id_list.extend(file_entry("REPORT~1.DOC", "report.docx", false));
+        id_list.extend_from_slice(&[0, 0]);
+
+        let list = IdList::parse(&id_list).unwrap();
+        // The block points at the first item below the folder.
+        let report = list
+            .items()
+            .iter()
+            .position(|item| item.file_name() == Some("report.docx"))
+            .unwrap();
+        let mut item_offset = 0;
+        for _ in 0..report {
+            item_offset += usize::from(u16_at(&id_list, item_offset).unwrap());
+        }
+        let link_with_offset = |offset: usize| {
+            link(
+                Some(&id_list),
+                None,
+                &[],
+                &[
+                    environment_block(r"%USERPROFILE%\Documents\report.docx"),
+                    known_folder_block(KnownFolder::Documents, u32::try_from(offset).unwrap()),
+                ],
+            )
+        };
+        let link = ShellLink::parse(&link_with_offset(item_offset)).unwrap();
+        assert_eq!(link.extra_data().len(), 2);
+        assert_eq!(
+            link.environment_target(),
+            Some(r"%USERPROFILE%\Documents\report.docx")
+        );
+        assert_eq!(
+            link.target_path().as_deref(),
+            Some(r"C:\Users\jsmith\Documents\report.docx")
+        );
+        assert_eq!(
+            link.target_known_folder(),
+            Some((
+                FolderId::Known(KnownFolder::Documents),
+                "report.docx".to_owned()
+            ))
+        );
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::Documents, r"D:\Docs");
+        assert_eq!(
+            link.resolve_target(&snapshot),
+            Some(PathBuf::from(r"D:\Docs\report.docx"))
+        );
+
+        // An offset that does not locate an item falls back to the ID list,
+        // which is anchored at a drive.
+        let link = ShellLink::parse(&link_with_offset(item_offset + 1)).unwrap();
+        assert_eq!(link.target_known_folder(), None);
+        assert_eq!(
+            link.resolve_target(&snapshot),
+            Some(PathBuf::from(r"C:\Users\jsmith\Documents\report.docx"))
+        );
+    }
+
+    #[test]
+    fn drive_target_is_not_under_a_known_folder() {
+        let mut id_list = root(Guid::from_u128(0x20d04fe0_3aea_1069_a2d8_08002b30309d));
+        id_list.extend(item(b"\x2FC:\\\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0"));
+        id_list.extend(file_entry("PROGRA~1", "Program Files", true));
+        id_list.extend(file_entry("Acme", "Acme", true));
+        id_list.extend_from_slice(&[0, 0]);
+
+        let bytes = link(Some(&id_list), None, &[], &[]);
+        let link = ShellLink::parse(&bytes).unwrap();
+        assert_eq!(link.target_known_folder(), None);
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::ComputerFolder, r"D:\Elsewhere");
+        assert_eq!(
+            link.resolve_target(&snapshot),
+            Some(PathBuf::from(r"C:\Program Files\Acme"))
+        );
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert_eq!(
+            ShellLink::parse(b"not a link").unwrap_err().kind(),
+            ParseErrorKind::InvalidHeader
+        );
+        let mut bytes = link(None, None, &[(HAS_NAME, "name")], &[]);
+        bytes.truncate(HEADER_SIZE + 4);
+        assert_eq!(
+            ShellLink::parse(&bytes).unwrap_err().kind(),
+            ParseErrorKind::Truncated
+        );
+    }
+}
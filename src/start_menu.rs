@@ -0,0 +1,330 @@
+// src/start_menu.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Inventory of the applications listed in the Start Menu.
+//!
+//! The Start Menu merges the shortcuts of the per-user [`StartMenu`] and
+//! [`Programs`] folders with those of their all-users counterparts
+//! [`CommonStartMenu`] and [`CommonPrograms`]. [`apps`] walks all four
+//! folders as located by any [`Resolver`], parses each shortcut, and returns
+//! one entry per application the way the Start Menu presents them.
+//!
+//! [`StartMenu`]: KnownFolder::StartMenu
+//! [`Programs`]: KnownFolder::Programs
+//! [`CommonStartMenu`]: KnownFolder::CommonStartMenu
+//! [`CommonPrograms`]: KnownFolder::CommonPrograms
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::desktop_ini::IconLocation;
+use crate::shell_link::ShellLink;
+use crate::{path, KnownFolder, Resolver, Scope};
+
+// Folders walked for shortcuts. Programs folders come first so their
+// shortcuts are grouped relative to them and not to the Start Menu folder
+// that usually contains them.
+const ROOTS: &[(KnownFolder, Scope)] = &[
+    (KnownFolder::Programs, Scope::PerUser),
+    (KnownFolder::StartMenu, Scope::PerUser),
+    (KnownFolder::CommonPrograms, Scope::AllUsers),
+    (KnownFolder::CommonStartMenu, Scope::AllUsers),
+];
+
+/// An application listed in the Start Menu.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct App {
+    name: String,
+    group: String,
+    scope: Scope,
+    link_path: PathBuf,
+    target: Option<PathBuf>,
+    arguments: Option<String>,
+    working_dir: Option<String>,
+    icon: Option<IconLocation>,
+    description: Option<String>,
+}
+
+impl App {
+    /// The display name of the application, the file name of its shortcut
+    /// without the `.lnk` extension.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The `\`-separated folder the shortcut is grouped in, relative to the
+    /// Programs or Start Menu folder, like `Accessories`. Empty for
+    /// shortcuts at the top level.
+    #[must_use]
+    pub fn group(&self) -> &str {
+        &self.group
+    }
+
+    /// Whether the shortcut was installed for the current user or for all
+    /// users.
+    #[must_use]
+    pub fn scope(&self) -> Scope {
+        self.scope
+    }
+
+    /// The path of the shortcut file.
+    #[must_use]
+    pub fn link_path(&self) -> &Path {
+        &self.link_path
+    }
+
+    /// The resolved path of the shortcut target.
+    ///
+    /// Returns [`None`] for shortcuts to virtual items, like packaged apps.
+    #[must_use]
+    pub fn target(&self) -> Option<&Path> {
+        self.target.as_deref()
+    }
+
+    /// The command line arguments passed to the target.
+    #[must_use]
+    pub fn arguments(&self) -> Option<&str> {
+        self.arguments.as_deref()
+    }
+
+    /// The working directory the target is launched in.
+    #[must_use]
+    pub fn working_dir(&self) -> Option<&str> {
+        self.working_dir.as_deref()
+    }
+
+    /// The icon of the shortcut, defaulting to the first icon of the target.
+    #[must_use]
+    pub fn icon(&self) -> Option<&IconLocation> {
+        self.icon.as_ref()
+    }
+
+    /// The description of the shortcut, shown as its tooltip.
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn from_link<R>(link_path: PathBuf, group: String, scope: Scope, resolver: &R) -> Option<Self>
+    where
+        R: Resolver + ?Sized,
+    {
+        let name = link_path.file_stem()?.to_str()?.to_owned();
+        let link = ShellLink::open(&link_path).ok()?;
+        let target = link.resolve_target(resolver);
+        let icon = match link.icon_location() {
+            Some(icon) => Some(IconLocation::new(icon.to_owned(), link.icon_index())),
+            None => target
+                .as_deref()
+                .and_then(Path::to_str)
+                .map(|target| IconLocation::new(target.to_owned(), 0)),
+        };
+        Some(Self {
+            name,
+            group,
+            scope,
+            link_path,
+            target,
+            arguments: link.arguments().map(str::to_owned),
+            working_dir: link.working_dir().map(str::to_owned),
+            icon,
+            description: link.name().map(str::to_owned),
+        })
+    }
+}
+
+/// List the applications of the Start Menu.
+///
+/// Shortcuts are collected from the per-user and all-users Programs and
+/// Start Menu folders as located by `resolver`. A shortcut with the same
+/// group and name in both scopes is listed once, as the per-user entry,
+/// which matches how the Start Menu merges the two. Apps are ordered by
+/// group and name.
+///
+/// Folders that cannot be resolved or read and shortcuts that cannot be
+/// parsed are skipped.
+///
+/// # Examples
+///
+#[cfg_attr(windows, doc = "```no_run")]
+#[cfg_attr(not(windows), doc = "```compile_fail")]
+/// use known_folders::start_menu;
+/// use known_folders::SystemResolver;
+///
+/// for app in start_menu::apps(&SystemResolver) {
+///     println!("{}\\{} -> {:?}", app.group(), app.name(), app.target());
+/// }
+/// ```
+#[must_use]
+pub fn apps<R>(resolver: &R) -> Vec<App>
+where
+    R: Resolver + ?Sized,
+{
+    let programs = ROOTS
+        .iter()
+        .filter(|&&(known_folder, _)| {
+            matches!(
+                known_folder,
+                KnownFolder::Programs | KnownFolder::CommonPrograms
+            )
+        })
+        .filter_map(|&(known_folder, _)| resolver.resolve(known_folder))
+        .collect::<Vec<_>>();
+    let mut visited = Vec::new();
+    let mut seen = HashSet::new();
+    let mut apps = Vec::new();
+    for &(known_folder, scope) in ROOTS {
+        let root = match resolver.resolve(known_folder) {
+            Some(root) => root,
+            None => continue,
+        };
+        if visited
+            .iter()
+            .any(|folder: &PathBuf| path::eq_ignore_case(folder, &root))
+        {
+            continue;
+        }
+        let mut links = Vec::new();
+        walk(&root, "", &programs, &mut links);
+        visited.push(root);
+        for (group, link_path) in links {
+            let app = match App::from_link(link_path, group, scope, resolver) {
+                Some(app) => app,
+                None => continue,
+            };
+            if seen.insert(sort_key(&app)) {
+                apps.push(app);
+            }
+        }
+    }
+    apps.sort_by_cached_key(sort_key);
+    apps
+}
+
+fn sort_key(app: &App) -> (String, String) {
+    (app.group.to_lowercase(), app.name.to_lowercase())
+}
+
+/// Collect the `.lnk` files below `dir` with their group, skipping the
+/// directories in `skip`, which are walked as roots of their own.
+fn walk(dir: &Path, group: &str, skip: &[PathBuf], links: &mut Vec<(String, PathBuf)>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let file_type = match entry.file_type() {
+            Ok(file_type) => file_type,
+            Err(_) => continue,
+        };
+        let name = entry.file_name();
+        let name = match name.to_str() {
+            Some(name) => name,
+            None => continue,
+        };
+        if file_type.is_dir() {
+            if skip.iter().any(|skip| path::eq_ignore_case(skip, &path)) {
+                continue;
+            }
+            let group = if group.is_empty() {
+                name.to_owned()
+            } else {
+                format!(r"{group}\{name}")
+            };
+            walk(&path, &group, skip, links);
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or(false, |extension| extension.eq_ignore_ascii_case("lnk"))
+        {
+            links.push((group.to_owned(), path));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shell_link::tests::program_link;
+    use crate::temp_dir::TempDir;
+    use crate::Snapshot;
+
+    #[test]
+    fn merge_user_and_common_shortcuts() {
+        let temp_dir = TempDir::new("start-menu");
+        let root = temp_dir.path();
+        let user = root.join("User").join("Start Menu");
+        let common = root.join("Common").join("Start Menu");
+        let shortcuts = [
+            (
+                user.join("Programs").join("Acme.lnk"),
+                r"C:\Users\jsmith\AppData\Local\Acme\acme.exe",
+            ),
+            (
+                user.join("Programs").join("Tools").join("Hex Editor.lnk"),
+                r"C:\Tools\hex.exe",
+            ),
+            (user.join("Welcome.lnk"), r"C:\Windows\welcome.exe"),
+            (
+                common.join("Programs").join("acme.lnk"),
+                r"C:\Program Files\Acme\acme.exe",
+            ),
+            (
+                common
+                    .join("Programs")
+                    .join("Accessories")
+                    .join("Notepad.lnk"),
+                r"C:\Windows\notepad.exe",
+            ),
+        ];
+        for (link, target) in &shortcuts {
+            fs::create_dir_all(link.parent().unwrap()).unwrap();
+            fs::write(link, program_link(target, "--start", r"C:\Windows")).unwrap();
+        }
+        fs::write(user.join("Programs").join("desktop.ini"), "").unwrap();
+        fs::write(user.join("Programs").join("Broken.lnk"), "not a link").unwrap();
+
+        let mut snapshot = Snapshot::new();
+        snapshot.insert(KnownFolder::StartMenu, &user);
+        snapshot.insert(KnownFolder::Programs, user.join("Programs"));
+        snapshot.insert(KnownFolder::CommonStartMenu, &common);
+        snapshot.insert(KnownFolder::CommonPrograms, common.join("Programs"));
+
+        let apps = apps(&snapshot);
+        let names = apps
+            .iter()
+            .map(|app| (app.group(), app.name(), app.scope()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("", "Acme", Scope::PerUser),
+                ("", "Welcome", Scope::PerUser),
+                ("Accessories", "Notepad", Scope::AllUsers),
+                ("Tools", "Hex Editor", Scope::PerUser),
+            ]
+        );
+        let acme = &apps[0];
+        assert_eq!(
+            acme.target(),
+            Some(Path::new(r"C:\Users\jsmith\AppData\Local\Acme\acme.exe"))
+        );
+        assert_eq!(acme.arguments(), Some("--start"));
+        assert_eq!(acme.working_dir(), Some(r"C:\Windows"));
+        assert_eq!(
+            acme.icon().map(IconLocation::path),
+            Some(r"C:\Users\jsmith\AppData\Local\Acme\acme.exe")
+        );
+        assert!(acme.link_path().ends_with("Acme.lnk"));
+    }
+}
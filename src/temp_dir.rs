@@ -0,0 +1,45 @@
+// src/temp_dir.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Scratch directories for tests that read fixtures from the file system.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A directory under the system temporary directory that is removed when
+/// dropped, including when the test that owns it panics.
+#[derive(Debug)]
+pub(crate) struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// Reserve a directory named after `name` and the current process.
+    ///
+    /// Leftovers of an earlier run are removed. The directory itself is not
+    /// created, so tests can check how missing directories are handled.
+    pub(crate) fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("known-folders-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        Self { path }
+    }
+
+    /// The path of the directory.
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
@@ -0,0 +1,134 @@
+// src/text.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Text decoding helpers for the file formats Windows writes.
+//!
+//! Windows text files are either UTF-16LE with a byte order mark or encoded
+//! in the system ANSI code page. This crate approximates the ANSI code page
+//! with Windows-1252, which is the code page used by Western European and
+//! US English installations.
+
+use core::char;
+
+/// Windows-1252 code points for bytes `0x80..=0x9F`.
+///
+/// Unassigned bytes map to the C1 control character with the same value,
+/// matching the behavior of `MultiByteToWideChar`.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Decode UTF-16LE code units from `bytes`, replacing unpaired surrogates.
+///
+/// A trailing odd byte is ignored.
+pub(crate) fn decode_utf16le(bytes: &[u8]) -> String {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Decode a NUL-terminated UTF-16LE string from `bytes`.
+///
+/// Decoding stops at the first NUL code unit or at the end of the buffer.
+pub(crate) fn decode_utf16le_nul(bytes: &[u8]) -> String {
+    let len = bytes
+        .chunks_exact(2)
+        .position(|pair| pair == [0, 0])
+        .map_or(bytes.len(), |units| units * 2);
+    decode_utf16le(&bytes[..len])
+}
+
+/// Decode `bytes` encoded in the ANSI code page.
+pub(crate) fn decode_ansi(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|&byte| match byte {
+            0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+            _ => char::from(byte),
+        })
+        .collect()
+}
+
+/// Decode a NUL-terminated ANSI string from `bytes`.
+pub(crate) fn decode_ansi_nul(bytes: &[u8]) -> String {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    decode_ansi(&bytes[..len])
+}
+
+/// Decode the contents of a text file written by Windows tooling.
+///
+/// The encoding is sniffed from the byte order mark. Files without a byte
+/// order mark are decoded as UTF-8 if they are valid UTF-8 and as ANSI
+/// otherwise. The byte order mark is not included in the returned string.
+pub(crate) fn decode_text_file(bytes: &[u8]) -> String {
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return decode_utf16le(rest);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units = rest
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]));
+        return char::decode_utf16(units)
+            .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+    }
+    let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s.to_owned(),
+        Err(_) => decode_ansi(bytes),
+    }
+}
+
+/// Compare two strings for equality ignoring case, the way the registry and
+/// NTFS compare names.
+pub(crate) fn eq_ignore_case(left: &str, right: &str) -> bool {
+    if left.is_ascii() && right.is_ascii() {
+        return left.eq_ignore_ascii_case(right);
+    }
+    left.chars()
+        .flat_map(char::to_uppercase)
+        .eq(right.chars().flat_map(char::to_uppercase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf16le_stops_at_nul() {
+        let bytes = b"a\0b\0\0\0c\0";
+        assert_eq!(decode_utf16le_nul(bytes), "ab");
+        assert_eq!(decode_utf16le_nul(b"a\0b\0"), "ab");
+    }
+
+    #[test]
+    fn text_file_sniffs_bom() {
+        assert_eq!(decode_text_file(b"\xFF\xFEh\0i\0"), "hi");
+        assert_eq!(decode_text_file(b"\xFE\xFF\0h\0i"), "hi");
+        assert_eq!(decode_text_file(b"\xEF\xBB\xBFh\xC3\xA9"), "h\u{e9}");
+        assert_eq!(decode_text_file(b"h\xE9\x80"), "h\u{e9}\u{20ac}");
+    }
+
+    #[test]
+    fn case_insensitive_comparison() {
+        assert!(eq_ignore_case("Personal", "PERSONAL"));
+        assert!(eq_ignore_case(
+            "T\u{e9}l\u{e9}chargements",
+            "T\u{c9}L\u{c9}CHARGEMENTS"
+        ));
+        assert!(!eq_ignore_case("Personal", "Persona"));
+    }
+}
@@ -0,0 +1,341 @@
+// src/version.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Windows versions and the known folders available on each of them.
+
+use core::fmt;
+use core::str::FromStr;
+use std::error::Error;
+
+use crate::KnownFolder;
+
+/// A Windows release identified by its `major.minor.build` version number.
+///
+/// Versions are ordered by major version, then minor version, then build
+/// number. Constants are provided for the releases that introduced or
+/// removed known folders.
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::WindowsVersion;
+///
+/// let version = "10.0.19045".parse::<WindowsVersion>()?;
+/// assert!(version >= WindowsVersion::WINDOWS_10);
+/// assert!(version < WindowsVersion::WINDOWS_11);
+/// assert_eq!(version.to_string(), "10.0.19045");
+/// # Ok::<(), known_folders::ParseWindowsVersionError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WindowsVersion {
+    major: u32,
+    minor: u32,
+    build: u32,
+}
+
+impl WindowsVersion {
+    /// Windows Vista, version 6.0.6000.
+    pub const VISTA: Self = Self::new(6, 0, 6000);
+    /// Windows 7, version 6.1.7600.
+    pub const WINDOWS_7: Self = Self::new(6, 1, 7600);
+    /// Windows 8, version 6.2.9200.
+    pub const WINDOWS_8: Self = Self::new(6, 2, 9200);
+    /// Windows 8.1, version 6.3.9600.
+    pub const WINDOWS_8_1: Self = Self::new(6, 3, 9600);
+    /// Windows 10, version 10.0.10240.
+    pub const WINDOWS_10: Self = Self::new(10, 0, 10240);
+    /// Windows 10 Fall Creators Update (1709), version 10.0.16299.
+    pub const WINDOWS_10_1709: Self = Self::new(10, 0, 16299);
+    /// Windows 10 April 2018 Update (1803), version 10.0.17134.
+    pub const WINDOWS_10_1803: Self = Self::new(10, 0, 17134);
+    /// Windows 11, version 10.0.22000.
+    pub const WINDOWS_11: Self = Self::new(10, 0, 22000);
+
+    /// Construct a version from its major, minor, and build numbers.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, build: u32) -> Self {
+        Self {
+            major,
+            minor,
+            build,
+        }
+    }
+
+    /// The major version number, e.g. `10` for Windows 10 and 11.
+    #[must_use]
+    pub const fn major(self) -> u32 {
+        self.major
+    }
+
+    /// The minor version number, e.g. `1` for Windows 7.
+    #[must_use]
+    pub const fn minor(self) -> u32 {
+        self.minor
+    }
+
+    /// The build number, e.g. `22000` for Windows 11.
+    #[must_use]
+    pub const fn build(self) -> u32 {
+        self.build
+    }
+}
+
+impl fmt::Display for WindowsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.build)
+    }
+}
+
+impl FromStr for WindowsVersion {
+    type Err = ParseWindowsVersionError;
+
+    /// Parse a version of the form `major.minor` or `major.minor.build`.
+    ///
+    /// A missing build number is parsed as `0`. Additional components, like
+    /// the update build revision in `10.0.19045.3570`, are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut components = s.trim().split('.').map(str::parse::<u32>);
+        let mut next = |required: bool| match components.next() {
+            Some(Ok(number)) => Ok(number),
+            None if !required => Ok(0),
+            _ => Err(ParseWindowsVersionError { _private: () }),
+        };
+        let major = next(true)?;
+        let minor = next(true)?;
+        let build = next(false)?;
+        Ok(Self::new(major, minor, build))
+    }
+}
+
+/// Error returned when parsing a [`WindowsVersion`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseWindowsVersionError {
+    _private: (),
+}
+
+impl fmt::Display for ParseWindowsVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid Windows version syntax")
+    }
+}
+
+impl Error for ParseWindowsVersionError {}
+
+impl KnownFolder {
+    /// Return the first Windows release that defines this known folder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{KnownFolder, WindowsVersion};
+    ///
+    /// assert_eq!(KnownFolder::Downloads.introduced_in(), WindowsVersion::VISTA);
+    /// assert_eq!(KnownFolder::CameraRoll.introduced_in(), WindowsVersion::WINDOWS_8_1);
+    /// ```
+    #[must_use]
+    pub const fn introduced_in(self) -> WindowsVersion {
+        match self {
+            Self::DeviceMetadataStore
+            | Self::DocumentsLibrary
+            | Self::HomeGroup
+            | Self::ImplicitAppShortcuts
+            | Self::Libraries
+            | Self::MusicLibrary
+            | Self::PicturesLibrary
+            | Self::PublicLibraries
+            | Self::PublicRingtones
+            | Self::RecordedTVLibrary
+            | Self::Ringtones
+            | Self::UserPinned
+            | Self::UserProgramFiles
+            | Self::UserProgramFilesCommon
+            | Self::UsersLibraries
+            | Self::VideosLibrary => WindowsVersion::WINDOWS_7,
+            Self::AccountPictures
+            | Self::ApplicationShortcuts
+            | Self::AppsFolder
+            | Self::HomeGroupCurrentUser
+            | Self::PublicUserTiles
+            | Self::RoamedTileImages
+            | Self::RoamingTiles
+            | Self::Screenshots => WindowsVersion::WINDOWS_8,
+            Self::CameraRoll
+            | Self::OneDrive
+            | Self::RetailDemo
+            | Self::SearchHistory
+            | Self::SearchTemplates
+            | Self::SkyDrive
+            | Self::SkyDriveCameraRoll
+            | Self::SkyDriveDocuments
+            | Self::SkyDriveMusic
+            | Self::SkyDrivePictures => WindowsVersion::WINDOWS_8_1,
+            Self::AllAppMods
+            | Self::AppCaptures
+            | Self::AppDataDesktop
+            | Self::AppDataDocuments
+            | Self::AppDataFavorites
+            | Self::AppDataProgramData
+            | Self::CameraRollLibrary
+            | Self::CurrentAppMods
+            | Self::DevelopmentFiles
+            | Self::Device
+            | Self::LocalDocuments
+            | Self::LocalDownloads
+            | Self::LocalMusic
+            | Self::LocalPictures
+            | Self::LocalStorage
+            | Self::LocalVideos
+            | Self::RecordedCalls
+            | Self::SavedPictures
+            | Self::SavedPicturesLibrary => WindowsVersion::WINDOWS_10,
+            Self::Objects3D => WindowsVersion::WINDOWS_10_1709,
+            _ => WindowsVersion::VISTA,
+        }
+    }
+
+    /// Return the first Windows release that no longer defines this known
+    /// folder, if it has been removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{KnownFolder, WindowsVersion};
+    ///
+    /// assert_eq!(KnownFolder::HomeGroup.removed_in(), Some(WindowsVersion::WINDOWS_10_1803));
+    /// assert_eq!(KnownFolder::Downloads.removed_in(), None);
+    /// ```
+    #[must_use]
+    pub const fn removed_in(self) -> Option<WindowsVersion> {
+        match self {
+            Self::SidebarDefaultParts | Self::SidebarParts => Some(WindowsVersion::WINDOWS_8),
+            Self::Games | Self::HomeGroup | Self::HomeGroupCurrentUser => {
+                Some(WindowsVersion::WINDOWS_10_1803)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the first Windows release that deprecates this known folder, if
+    /// any.
+    ///
+    /// Deprecated folders may still be defined but should not be used by new
+    /// code. Removed folders are deprecated no later than the release that
+    /// removes them.
+    #[must_use]
+    pub const fn deprecated_in(self) -> Option<WindowsVersion> {
+        match self {
+            Self::QuickLaunch => Some(WindowsVersion::WINDOWS_7),
+            Self::SkyDrive => Some(WindowsVersion::WINDOWS_8_1),
+            Self::SkyDriveCameraRoll
+            | Self::SkyDriveDocuments
+            | Self::SkyDriveMusic
+            | Self::SkyDrivePictures => Some(WindowsVersion::WINDOWS_10),
+            Self::GameTasks | Self::PublicGameTasks => Some(WindowsVersion::WINDOWS_10_1803),
+            _ => self.removed_in(),
+        }
+    }
+
+    /// Return `true` if this known folder is deprecated as of the latest
+    /// Windows release.
+    #[must_use]
+    pub const fn is_deprecated(self) -> bool {
+        self.deprecated_in().is_some()
+    }
+
+    /// Return the known folder that replaces this deprecated known folder,
+    /// if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::KnownFolder;
+    ///
+    /// assert_eq!(KnownFolder::SkyDrive.successor(), Some(KnownFolder::OneDrive));
+    /// assert_eq!(KnownFolder::HomeGroup.successor(), None);
+    /// ```
+    #[must_use]
+    pub const fn successor(self) -> Option<KnownFolder> {
+        match self {
+            Self::SkyDrive
+            | Self::SkyDriveCameraRoll
+            | Self::SkyDriveDocuments
+            | Self::SkyDriveMusic
+            | Self::SkyDrivePictures => Some(Self::OneDrive),
+            Self::QuickLaunch => Some(Self::UserPinned),
+            _ => None,
+        }
+    }
+
+    /// Return `true` if this known folder is defined on the given Windows
+    /// release.
+    ///
+    /// A folder is supported from the release that introduced it up to, but
+    /// not including, the release that removed it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use known_folders::{KnownFolder, WindowsVersion};
+    ///
+    /// assert!(KnownFolder::SidebarParts.is_supported_on(WindowsVersion::WINDOWS_7));
+    /// assert!(!KnownFolder::SidebarParts.is_supported_on(WindowsVersion::WINDOWS_10));
+    /// assert!(!KnownFolder::Objects3D.is_supported_on(WindowsVersion::WINDOWS_8_1));
+    /// assert!(KnownFolder::Objects3D.is_supported_on(WindowsVersion::WINDOWS_11));
+    /// ```
+    #[must_use]
+    pub fn is_supported_on(self, version: WindowsVersion) -> bool {
+        self.introduced_in() <= version
+            && self.removed_in().map_or(true, |removed| version < removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_versions() {
+        assert_eq!("6.1".parse(), Ok(WindowsVersion::new(6, 1, 0)));
+        assert_eq!(
+            "10.0.19045.3570".parse(),
+            Ok(WindowsVersion::new(10, 0, 19045))
+        );
+        assert!("10".parse::<WindowsVersion>().is_err());
+        assert!("10.x".parse::<WindowsVersion>().is_err());
+        assert!("".parse::<WindowsVersion>().is_err());
+    }
+
+    #[test]
+    fn lifecycle_is_consistent() {
+        for &folder in KnownFolder::ALL {
+            if let Some(removed) = folder.removed_in() {
+                assert!(folder.introduced_in() < removed, "{folder:?}");
+                assert!(folder.deprecated_in() <= Some(removed), "{folder:?}");
+            }
+            if let Some(successor) = folder.successor() {
+                assert!(folder.is_deprecated(), "{folder:?}");
+                assert!(!successor.is_deprecated(), "{folder:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn supported_folders_per_release() {
+        let vista = KnownFolder::ALL
+            .iter()
+            .filter(|folder| folder.is_supported_on(WindowsVersion::VISTA))
+            .count();
+        assert!(vista < KnownFolder::ALL.len());
+        assert!(KnownFolder::HomeGroup.is_supported_on(WindowsVersion::WINDOWS_10));
+        assert!(!KnownFolder::HomeGroup.is_supported_on(WindowsVersion::WINDOWS_10_1803));
+        assert!(!KnownFolder::HomeGroup.is_supported_on(WindowsVersion::VISTA));
+        assert!(KnownFolder::OneDrive.is_supported_on(WindowsVersion::WINDOWS_11));
+    }
+}
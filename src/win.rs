@@ -14,17 +14,18 @@ use std::ffi::OsString;
 use std::os::windows::ffi::OsStringExt;
 use std::path::PathBuf;
 
+use windows_sys::core::GUID;
 use windows_sys::Win32::{
     Foundation::{E_FAIL, E_INVALIDARG, HANDLE, S_OK},
     Globalization::lstrlenW,
     UI::Shell::{SHGetKnownFolderPath, KF_FLAG_DEFAULT},
 };
 
+use crate::{FolderId, KnownFolder, Resolver};
+
 mod ffi;
 mod known_folder;
 
-pub use known_folder::KnownFolder;
-
 /// Retrieve the full path of a known folder identified by the folder's
 /// [`KNOWNFOLDERID`].
 ///
@@ -51,9 +52,15 @@ pub use known_folder::KnownFolder;
 /// [`KNOWNFOLDERID`]: KnownFolder
 /// [`SHGetKnownFolderPath`]: https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath
 #[must_use]
+pub fn get_known_folder_path(known_folder: KnownFolder) -> Option<PathBuf> {
+    get_folder_path(known_folder.to_guid())
+}
+
+/// Retrieve the full path of the known folder identified by `rfid`, which
+/// may be a known folder registered by an application.
 #[allow(clippy::match_same_arms)]
 #[allow(clippy::cast_possible_wrap)]
-pub fn get_known_folder_path(known_folder: KnownFolder) -> Option<PathBuf> {
+fn get_folder_path(rfid: &GUID) -> Option<PathBuf> {
     // This guard ensures `CoTaskMemFree` is always called after invoking
     // `SHGetKnownFolderPath`, which is required regardless of the return
     // value.
@@ -73,7 +80,7 @@ pub fn get_known_folder_path(known_folder: KnownFolder) -> Option<PathBuf> {
     // SAFETY: this invocation meets the preconditions defined in the API
     // documentation:
     //
-    // - `rfid` is a reference to a known folder ID, provided by `windows-sys`.
+    // - `rfid` is a reference to a known folder ID.
     // - `dwFlags` can be `0` per the documentation, we have no special retrieval
     //   requirements, so use the default defined in `windows-sys`.
     //   The `KNOWN_FOLDER_FLAG` enum is documented here:
@@ -87,7 +94,7 @@ pub fn get_known_folder_path(known_folder: KnownFolder) -> Option<PathBuf> {
     // https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath#parameters
     match unsafe {
         SHGetKnownFolderPath(
-            known_folder.to_guid(),
+            rfid,
             KF_FLAG_DEFAULT as _,
             HANDLE::default(),
             guard.as_out_ppszPath(),
@@ -136,3 +143,29 @@ pub fn get_known_folder_path(known_folder: KnownFolder) -> Option<PathBuf> {
         _ => None,
     }
 }
+
+/// A [`Resolver`] that retrieves known folder locations for the current user
+/// with the Known Folders API.
+///
+/// This resolver delegates to [`get_known_folder_path`].
+///
+/// # Examples
+///
+/// ```
+/// use known_folders::{KnownFolder, Resolver, SystemResolver};
+///
+/// let profile_dir = SystemResolver.resolve(KnownFolder::Profile);
+/// ```
+#[derive(Default, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct SystemResolver;
+
+impl Resolver for SystemResolver {
+    fn resolve(&self, known_folder: KnownFolder) -> Option<PathBuf> {
+        get_known_folder_path(known_folder)
+    }
+
+    fn resolve_id(&self, id: FolderId) -> Option<PathBuf> {
+        let guid = GUID::from_u128(id.guid().to_u128());
+        get_folder_path(&guid)
+    }
+}
@@ -0,0 +1,302 @@
+// src/xml.rs
+//
+// Copyright (c) 2023 Ryan Lopopolo <rjl@hyperbo.la>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE> or
+// <http://www.apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT>
+// or <http://opensource.org/licenses/MIT>, at your option. All files in the
+// project carrying such notice may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A small, non-validating XML reader for shell description files.
+//!
+//! Files like `.library-ms` and `.search-ms` are simple XML documents. This
+//! reader builds an element tree that keeps local names, attributes, and
+//! text content, and ignores namespaces, comments, processing instructions,
+//! and document type declarations.
+
+use core::char;
+
+/// An XML element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Element {
+    /// The local name of the element, without a namespace prefix.
+    pub(crate) name: String,
+    /// Attributes by local name, in document order.
+    pub(crate) attributes: Vec<(String, String)>,
+    /// Child elements in document order.
+    pub(crate) children: Vec<Element>,
+    /// The concatenated text content of this element, excluding the text of
+    /// child elements.
+    pub(crate) text: String,
+}
+
+impl Element {
+    /// Return the first child element named `name`.
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Iterate over child elements named `name`.
+    pub(crate) fn children_named<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Return the value of the attribute named `name`.
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Return the trimmed text content of the first child element named
+    /// `name`.
+    pub(crate) fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|child| child.text.trim())
+    }
+}
+
+/// Strip a namespace prefix from a qualified name.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Replace entity and character references in `text`.
+fn unescape(text: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find(';')? + start;
+        let entity = &rest[start + 1..end];
+        let ch = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()?
+                } else {
+                    entity.strip_prefix('#')?.parse().ok()?
+                };
+                char::from_u32(code)?
+            }
+        };
+        out.push(ch);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+// Elements nested deeper than this are rejected, which bounds the recursion
+// of the parser on untrusted documents.
+const MAX_DEPTH: usize = 256;
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skip past the next occurrence of `terminator`.
+    fn skip_past(&mut self, terminator: &str) -> Result<(), usize> {
+        let end = self.rest().find(terminator).ok_or(self.pos)?;
+        self.pos += end + terminator.len();
+        Ok(())
+    }
+
+    /// Skip comments, processing instructions, and declarations.
+    fn skip_misc(&mut self) -> Result<(), usize> {
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if rest.starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, usize> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '=' | '<'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.pos);
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    fn element(&mut self) -> Result<Element, usize> {
+        let start = self.pos;
+        if !self.rest().starts_with('<') {
+            return Err(start);
+        }
+        self.pos += 1;
+        let qualified_name = self.name()?;
+        let mut element = Element {
+            name: local_name(qualified_name).to_owned(),
+            attributes: Vec::new(),
+            children: Vec::new(),
+            text: String::new(),
+        };
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok(element);
+            }
+            if rest.starts_with('>') {
+                self.pos += 1;
+                break;
+            }
+            let name = self.name()?;
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.pos);
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+            let quote = self.rest().chars().next().ok_or(self.pos)?;
+            if quote != '"' && quote != '\'' {
+                return Err(self.pos);
+            }
+            self.pos += 1;
+            let len = self.rest().find(quote).ok_or(self.pos)?;
+            let value = unescape(&self.rest()[..len]).ok_or(self.pos)?;
+            self.pos += len + 1;
+            element
+                .attributes
+                .push((local_name(name).to_owned(), value));
+        }
+        loop {
+            let rest = self.rest();
+            let len = rest.find('<').ok_or(self.pos)?;
+            let text = unescape(&rest[..len]).ok_or(self.pos)?;
+            element.text.push_str(&text);
+            self.pos += len;
+            let rest = self.rest();
+            if let Some(close) = rest.strip_prefix("</") {
+                let len = close.find('>').ok_or(self.pos)?;
+                if close[..len].trim_end() != qualified_name {
+                    return Err(self.pos);
+                }
+                self.pos += len + 3;
+                return Ok(element);
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let len = cdata.find("]]>").ok_or(self.pos)?;
+                element.text.push_str(&cdata[..len]);
+                self.pos += "<![CDATA[".len() + len + 3;
+            } else if rest.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if rest.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else {
+                if self.depth == MAX_DEPTH {
+                    return Err(self.pos);
+                }
+                self.depth += 1;
+                let child = self.element()?;
+                self.depth -= 1;
+                element.children.push(child);
+            }
+        }
+    }
+}
+
+/// Parse an XML document and return its root element.
+///
+/// On failure, the byte offset in `input` where the error was detected is
+/// returned.
+pub(crate) fn parse(input: &str) -> Result<Element, usize> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+    };
+    parser.skip_misc()?;
+    let root = parser.element()?;
+    parser.skip_misc()?;
+    if parser.pos == input.len() {
+        Ok(root)
+    } else {
+        Err(parser.pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_document() {
+        let doc = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- comment -->
+<a:root xmlns:a="urn:x" kind='x &amp; y'>
+  <item>one &lt;1&gt; &#x41;&#66;</item>
+  <item/>
+  <other><![CDATA[<raw>]]></other>
+</a:root>
+"#;
+        let root = parse(doc).unwrap();
+        assert_eq!(root.name, "root");
+        assert_eq!(
+            root.attributes,
+            [
+                ("a".to_owned(), "urn:x".to_owned()),
+                ("kind".to_owned(), "x & y".to_owned())
+            ]
+        );
+        assert_eq!(root.children_named("item").count(), 2);
+        assert_eq!(root.child_text("item"), Some("one <1> AB"));
+        assert_eq!(root.child_text("other"), Some("<raw>"));
+    }
+
+    #[test]
+    fn reject_malformed() {
+        for doc in [
+            "",
+            "<a>",
+            "<a></b>",
+            "<a x=1/>",
+            "<a/><b/>",
+            "<a>&bogus;</a>",
+        ] {
+            assert!(parse(doc).is_err(), "{doc}");
+        }
+    }
+
+    #[test]
+    fn reject_deep_nesting() {
+        let nested = |depth: usize| format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        assert!(parse(&nested(MAX_DEPTH + 1)).is_ok());
+        let doc = nested(200_000);
+        assert_eq!(parse(&doc), Err((MAX_DEPTH + 1) * 3));
+    }
+}